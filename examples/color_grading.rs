@@ -0,0 +1,138 @@
+use bevy::{
+    prelude::*,
+    render::{
+        camera::ScalingMode,
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::ImageSampler,
+    },
+};
+use bevy_parallaxation2d::prelude::*;
+
+// This example demonstrates a color grading LUT applied to every parallax layer.
+// A warm LUT is generated at startup, and pressing space toggles it on and off.
+
+const CAMERA_MOVE_SPEED: f32 = 5.0;
+const CAMERA_HEIGHT: f32 = 180.0;
+/// Number of slices of the LUT, and the size of each slice in pixels.
+const LUT_SIZE: u32 = 16;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::hex("29ADFF").unwrap()))
+        .add_plugins((
+            DefaultPlugins.set(ImagePlugin::default_nearest()),
+            ParallaxPlugin::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_camera, toggle_color_grading))
+        .run();
+}
+
+#[derive(Resource)]
+struct WarmLut(Handle<Image>);
+
+fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands
+        .spawn(Camera2dBundle {
+            projection: OrthographicProjection {
+                near: -1000.0,
+                far: 1000.0,
+                scaling_mode: ScalingMode::FixedVertical(CAMERA_HEIGHT),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(ParallaxCamera::default());
+
+    commands.spawn_batch(vec![
+        ParallaxLayer {
+            image: "mountains_background.png".into(),
+            depth: 84.0.into(),
+            ..default()
+        },
+        ParallaxLayer {
+            image: "back_trees_background.png".into(),
+            depth: 70.0.into(),
+            ..default()
+        },
+        ParallaxLayer {
+            image: "trees_background.png".into(),
+            depth: 55.0.into(),
+            ..default()
+        },
+        ParallaxLayer {
+            image: "bushes_background.png".into(),
+            depth: 40.0.into(),
+            ..default()
+        },
+    ]);
+
+    let lut = images.add(warm_lut());
+    commands.insert_resource(ParallaxColorGrading { lut: lut.clone() });
+    commands.insert_resource(WarmLut(lut));
+}
+
+/// Creates a LUT warming up the colors, by lifting the reds and lowering the blues.
+fn warm_lut() -> Image {
+    let max = (LUT_SIZE - 1) as f32;
+    let data = (0..LUT_SIZE)
+        .flat_map(|green| {
+            (0..LUT_SIZE).flat_map(move |blue| (0..LUT_SIZE).map(move |red| (red, green, blue)))
+        })
+        .flat_map(|(red, green, blue)| {
+            let color = Vec3::new(red as f32, green as f32, blue as f32) / max;
+            let warm = color * Vec3::new(1.1, 1.0, 0.8) + Vec3::new(0.04, 0.02, 0.0);
+            let warm = (warm.clamp(Vec3::ZERO, Vec3::ONE) * 255.0).round();
+            [warm.x as u8, warm.y as u8, warm.z as u8, 255]
+        })
+        .collect();
+
+    let mut image = Image::new(
+        Extent3d {
+            width: LUT_SIZE * LUT_SIZE,
+            height: LUT_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8Unorm,
+        RenderAssetUsages::default(),
+    );
+    // Interpolate between the colors of the LUT
+    image.sampler = ImageSampler::linear();
+    image
+}
+
+fn toggle_color_grading(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    color_grading: Option<Res<ParallaxColorGrading>>,
+    warm_lut: Res<WarmLut>,
+) {
+    if input.just_pressed(KeyCode::Space) {
+        match color_grading {
+            Some(_) => commands.remove_resource::<ParallaxColorGrading>(),
+            None => commands.insert_resource(ParallaxColorGrading {
+                lut: warm_lut.0.clone(),
+            }),
+        }
+    }
+}
+
+fn move_camera(
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let mut camera_transform = camera_query
+        .get_single_mut()
+        .expect("There should only be one Camera2d");
+
+    let left = input.pressed(KeyCode::ArrowLeft) as u32 as f32;
+    let right = input.pressed(KeyCode::ArrowRight) as u32 as f32;
+    let up = input.pressed(KeyCode::ArrowUp) as u32 as f32;
+    let down = input.pressed(KeyCode::ArrowDown) as u32 as f32;
+    let move_direction = Vec2::new(right - left, up - down) * CAMERA_MOVE_SPEED;
+
+    camera_transform.translation += move_direction.extend(0.0);
+}
@@ -0,0 +1,94 @@
+use bevy::{prelude::*, render::camera::ScalingMode};
+use bevy_parallaxation2d::prelude::*;
+
+// This example demonstrates how to break up the repetition of repeating parallax layers.
+// The trees start out tiled, and pressing space switches them between tiled and de-tiled,
+// where some of the tiles are flipped.
+
+const CAMERA_MOVE_SPEED: f32 = 5.0;
+const CAMERA_HEIGHT: f32 = 180.0;
+const DETILE_STRENGTH: f32 = 1.0;
+
+/// Marker for the layers switched between tiled and de-tiled by the example.
+#[derive(Component)]
+struct DetiledLayer;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::hex("29ADFF").unwrap()))
+        .add_plugins((
+            DefaultPlugins.set(ImagePlugin::default_nearest()),
+            ParallaxPlugin::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_camera, toggle_detile))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands
+        .spawn(Camera2dBundle {
+            projection: OrthographicProjection {
+                near: -1000.0,
+                far: 1000.0,
+                scaling_mode: ScalingMode::FixedVertical(CAMERA_HEIGHT),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(ParallaxCamera::default());
+
+    commands.spawn(ParallaxLayer {
+        image: "mountains_background.png".into(),
+        depth: 84.0.into(),
+        flags: ParallaxFlags::REPEAT_X_AXIS,
+        ..default()
+    });
+
+    for (image, depth) in [
+        ("back_trees_background.png", 70.0),
+        ("trees_background.png", 55.0),
+    ] {
+        commands.spawn((
+            ParallaxLayer {
+                image: image.into(),
+                depth: depth.into(),
+                flags: ParallaxFlags::REPEAT_X_AXIS,
+                ..default()
+            },
+            DetiledLayer,
+        ));
+    }
+}
+
+fn move_camera(
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let mut camera_transform = camera_query
+        .get_single_mut()
+        .expect("There should only be one Camera2d");
+
+    let left = input.pressed(KeyCode::ArrowLeft) as u32 as f32;
+    let right = input.pressed(KeyCode::ArrowRight) as u32 as f32;
+    let up = input.pressed(KeyCode::ArrowUp) as u32 as f32;
+    let down = input.pressed(KeyCode::ArrowDown) as u32 as f32;
+    let move_direction = Vec2::new(right - left, up - down) * CAMERA_MOVE_SPEED;
+
+    camera_transform.translation += move_direction.extend(0.0);
+}
+
+fn toggle_detile(
+    mut layer_query: Query<&mut ParallaxLayerData, With<DetiledLayer>>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    if input.just_pressed(KeyCode::Space) {
+        for mut parallax in layer_query.iter_mut() {
+            let detile_strength = match parallax.detile_strength() > 0.0 {
+                true => 0.0,
+                false => DETILE_STRENGTH,
+            };
+            parallax.set_detile_strength(detile_strength);
+        }
+    }
+}
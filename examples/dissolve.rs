@@ -0,0 +1,98 @@
+use bevy::{prelude::*, render::camera::ScalingMode};
+use bevy_parallaxation2d::prelude::*;
+
+// This example demonstrates how to transition parallax layers in and out with a dissolve.
+// The trees get revealed at startup, and pressing space hides or reveals them again.
+
+const CAMERA_MOVE_SPEED: f32 = 5.0;
+const CAMERA_HEIGHT: f32 = 180.0;
+const DISSOLVE_DURATION: f32 = 1.5;
+
+/// Marker for the layers transitioned by the example.
+#[derive(Component)]
+struct DissolvingLayer;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::hex("29ADFF").unwrap()))
+        .add_plugins((
+            DefaultPlugins.set(ImagePlugin::default_nearest()),
+            ParallaxPlugin::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_camera, toggle_dissolve))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands
+        .spawn(Camera2dBundle {
+            projection: OrthographicProjection {
+                near: -1000.0,
+                far: 1000.0,
+                scaling_mode: ScalingMode::FixedVertical(CAMERA_HEIGHT),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(ParallaxCamera::default());
+
+    commands.spawn(ParallaxLayer {
+        image: "mountains_background.png".into(),
+        depth: 84.0.into(),
+        ..default()
+    });
+
+    for (image, depth) in [
+        ("back_trees_background.png", 70.0),
+        ("trees_background.png", 55.0),
+    ] {
+        commands.spawn((
+            ParallaxLayer {
+                image: image.into(),
+                depth: depth.into(),
+                dissolve_image: Some("dissolve_noise.png".into()),
+                ..default()
+            },
+            ParallaxDissolve::reveal(DISSOLVE_DURATION),
+            DissolvingLayer,
+        ));
+    }
+}
+
+fn move_camera(
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let mut camera_transform = camera_query
+        .get_single_mut()
+        .expect("There should only be one Camera2d");
+
+    let left = input.pressed(KeyCode::ArrowLeft) as u32 as f32;
+    let right = input.pressed(KeyCode::ArrowRight) as u32 as f32;
+    let up = input.pressed(KeyCode::ArrowUp) as u32 as f32;
+    let down = input.pressed(KeyCode::ArrowDown) as u32 as f32;
+    let move_direction = Vec2::new(right - left, up - down) * CAMERA_MOVE_SPEED;
+
+    camera_transform.translation += move_direction.extend(0.0);
+}
+
+fn toggle_dissolve(
+    mut commands: Commands,
+    layer_query: Query<Entity, With<DissolvingLayer>>,
+    input: Res<ButtonInput<KeyCode>>,
+    mut is_hidden: Local<bool>,
+) {
+    if input.just_pressed(KeyCode::Space) {
+        let dissolve = match *is_hidden {
+            true => ParallaxDissolve::reveal(DISSOLVE_DURATION),
+            false => ParallaxDissolve::hide(DISSOLVE_DURATION),
+        };
+
+        for entity in layer_query.iter() {
+            commands.entity(entity).insert(dissolve);
+        }
+
+        *is_hidden = !*is_hidden;
+    }
+}
@@ -4,7 +4,7 @@ use bevy_parallaxation2d::prelude::*;
 
 // This example demonstrates how to set up and use the `bevy_parallaxation2d`
 // crate in a Bevy application, including camera movement and parallax layers.
-// There is also a simple demonstration of how to despawn a parallax layer.
+// There is also a simple demonstration of how to hide and show a parallax layer.
 
 const CAMERA_MOVE_SPEED: f32 = 5.0;
 const CAMERA_HEIGHT: f32 = 180.0;
@@ -33,33 +33,34 @@ fn setup(mut commands: Commands) {
             },
             ..default()
         })
-        .insert(ParallaxCamera);
+        .insert(ParallaxCamera::default());
 
     commands.spawn_batch(vec![
         ParallaxLayer {
-            image: "mountains_background.png",
+            image: "mountains_background.png".into(),
             color: Color::DARK_GRAY,
             depth: 90.0.into(),
             offset: Vec2::Y * 10.0,
             flags: ParallaxFlags::OFFSET_CAMERA_TOP,
+            ..default()
         },
         ParallaxLayer {
-            image: "mountains_background.png",
+            image: "mountains_background.png".into(),
             depth: 84.0.into(),
             ..default()
         },
         ParallaxLayer {
-            image: "back_trees_background.png",
+            image: "back_trees_background.png".into(),
             depth: 70.0.into(),
             ..default()
         },
         ParallaxLayer {
-            image: "trees_background.png",
+            image: "trees_background.png".into(),
             depth: 55.0.into(),
             ..default()
         },
         ParallaxLayer {
-            image: "bushes_background.png",
+            image: "bushes_background.png".into(),
             depth: 40.0.into(),
             ..default()
         },
@@ -86,21 +87,15 @@ fn move_camera(
 fn toggle_back_layer(
     mut commands: Commands,
     input: Res<ButtonInput<KeyCode>>,
-    mut is_despawned: Local<bool>,
+    mut is_hidden: Local<bool>,
 ) {
     if input.just_pressed(KeyCode::Space) {
-        if *is_despawned {
-            commands.spawn(ParallaxLayer {
-                image: "mountains_background.png",
-                color: Color::DARK_GRAY,
-                depth: 90.0.into(),
-                offset: Vec2::Y * 10.0,
-                flags: ParallaxFlags::OFFSET_CAMERA_TOP,
-            });
+        if *is_hidden {
+            commands.show_all_layers();
         } else {
-            commands.despawn_back_layer();
+            commands.hide_back_layer();
         }
 
-        *is_despawned = !*is_despawned;
+        *is_hidden = !*is_hidden;
     }
 }
@@ -0,0 +1,174 @@
+use bevy::{prelude::*, render::camera::ScalingMode};
+use bevy_inspector_egui::bevy_egui::{egui, EguiContexts, EguiPlugin};
+use bevy_parallaxation2d::prelude::*;
+
+// This example demonstrates how the parallax configuration and the parallax layers
+// can be modified at runtime. The controls in the window are wired directly to the
+// `ParallaxContext` resource and the `ParallaxLayerData` components.
+
+const CAMERA_MOVE_SPEED: f32 = 5.0;
+const CAMERA_HEIGHT: f32 = 180.0;
+
+/// Editable values of a single parallax layer.
+struct LayerSettings {
+    entity: Entity,
+    name: &'static str,
+    depth: f32,
+    offset: Vec2,
+    flags: ParallaxFlags,
+}
+
+/// Editable values shown in the preview window.
+#[derive(Resource, Default)]
+struct PreviewSettings {
+    scale: f32,
+    neutral_depth: f32,
+    selected: usize,
+    layers: Vec<LayerSettings>,
+}
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::hex("29ADFF").unwrap()))
+        .add_plugins((
+            DefaultPlugins.set(ImagePlugin::default_nearest()),
+            EguiPlugin,
+            ParallaxPlugin::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_camera, preview_ui))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands
+        .spawn(Camera2dBundle {
+            projection: OrthographicProjection {
+                near: -1000.0,
+                far: 1000.0,
+                scaling_mode: ScalingMode::FixedVertical(CAMERA_HEIGHT),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(ParallaxCamera::default());
+
+    let layers = [
+        ("Mountains", "mountains_background.png", 84.0),
+        ("Back trees", "back_trees_background.png", 70.0),
+        ("Trees", "trees_background.png", 55.0),
+        ("Bushes", "bushes_background.png", 40.0),
+    ];
+
+    let mut settings = PreviewSettings {
+        scale: 1.0,
+        ..default()
+    };
+    for (name, image, depth) in layers {
+        let entity = commands
+            .spawn(ParallaxLayer {
+                image: image.into(),
+                depth: depth.into(),
+                ..default()
+            })
+            .id();
+
+        settings.layers.push(LayerSettings {
+            entity,
+            name,
+            depth,
+            offset: Vec2::ZERO,
+            flags: ParallaxFlags::default(),
+        });
+    }
+
+    commands.insert_resource(settings);
+}
+
+fn move_camera(
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let mut camera_transform = camera_query
+        .get_single_mut()
+        .expect("There should only be one Camera2d");
+
+    let left = input.pressed(KeyCode::ArrowLeft) as u32 as f32;
+    let right = input.pressed(KeyCode::ArrowRight) as u32 as f32;
+    let up = input.pressed(KeyCode::ArrowUp) as u32 as f32;
+    let down = input.pressed(KeyCode::ArrowDown) as u32 as f32;
+    let move_direction = Vec2::new(right - left, up - down) * CAMERA_MOVE_SPEED;
+
+    camera_transform.translation += move_direction.extend(0.0);
+}
+
+fn preview_ui(
+    mut contexts: EguiContexts,
+    mut settings: ResMut<PreviewSettings>,
+    mut parallax_context: ResMut<ParallaxContext>,
+    mut layer_query: Query<&mut ParallaxLayerData>,
+) {
+    let settings = settings.as_mut();
+    let mut layer_changed = false;
+
+    egui::Window::new("Parallax Preview").show(contexts.ctx_mut(), |ui| {
+        ui.heading("Plugin");
+        ui.add(egui::Slider::new(&mut settings.scale, -2.0..=4.0).text("Scale"));
+        ui.add(egui::Slider::new(&mut settings.neutral_depth, -10.0..=100.0).text("Neutral depth"));
+
+        ui.separator();
+        ui.heading("Layer");
+        egui::ComboBox::from_label("Selected")
+            .selected_text(settings.layers[settings.selected].name)
+            .show_ui(ui, |ui| {
+                for (index, layer) in settings.layers.iter().enumerate() {
+                    ui.selectable_value(&mut settings.selected, index, layer.name);
+                }
+            });
+
+        let layer = &mut settings.layers[settings.selected];
+        layer_changed |= ui
+            .add(egui::Slider::new(&mut layer.depth, -10.0..=100.0).text("Depth"))
+            .changed();
+        layer_changed |= ui
+            .add(egui::Slider::new(&mut layer.offset.x, -200.0..=200.0).text("Offset X"))
+            .changed();
+        layer_changed |= ui
+            .add(egui::Slider::new(&mut layer.offset.y, -200.0..=200.0).text("Offset Y"))
+            .changed();
+
+        for (flag, label) in [
+            (ParallaxFlags::REPEAT_X_AXIS, "Repeat X"),
+            (ParallaxFlags::REPEAT_Y_AXIS, "Repeat Y"),
+            (ParallaxFlags::LOCKED_X_AXIS, "Lock X"),
+            (ParallaxFlags::LOCKED_Y_AXIS, "Lock Y"),
+            (ParallaxFlags::OFFSET_TO_CAMERA, "Offset to camera"),
+            (ParallaxFlags::HORIZONTAL_OFFSET, "Horizontal offset"),
+            (ParallaxFlags::POSITIVE_OFFSET, "Positive offset"),
+        ] {
+            let mut is_set = layer.flags.contains(flag);
+            if ui.checkbox(&mut is_set, label).changed() {
+                layer.flags.set(flag, is_set);
+                layer_changed = true;
+            }
+        }
+    });
+
+    // Only mutate on change, otherwise every layer gets processed each frame
+    if parallax_context.scale() != settings.scale {
+        parallax_context.set_scale(settings.scale);
+    }
+    if parallax_context.neutral_depth() != settings.neutral_depth {
+        parallax_context.set_neutral_depth(settings.neutral_depth);
+    }
+
+    if layer_changed {
+        let layer = &settings.layers[settings.selected];
+        if let Ok(mut parallax) = layer_query.get_mut(layer.entity) {
+            parallax
+                .set_depth(layer.depth)
+                .set_offset(layer.offset)
+                .set_flags(layer.flags);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::{
+    prelude::*,
+    render::{
+        camera::ScalingMode,
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+};
+use bevy_parallaxation2d::prelude::*;
+
+// This example demonstrates a parallax layer rendered from a signed distance field.
+// A small distance field of a sign, magnified several times by the camera, keeps a crisp
+// edge at the near and far depth, while the mountains behind render as usual.
+
+const CAMERA_MOVE_SPEED: f32 = 5.0;
+const CAMERA_HEIGHT: f32 = 180.0;
+const LABEL_SIZE: UVec2 = UVec2::new(64, 32);
+/// Distance in texels over which the distance field goes from the edge to fully inside.
+const LABEL_SPREAD: f32 = 4.0;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::hex("29ADFF").unwrap()))
+        .add_plugins((
+            DefaultPlugins.set(ImagePlugin::default_nearest()),
+            ParallaxPlugin::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, move_camera)
+        .run();
+}
+
+fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands
+        .spawn(Camera2dBundle {
+            projection: OrthographicProjection {
+                near: -1000.0,
+                far: 1000.0,
+                scaling_mode: ScalingMode::FixedVertical(CAMERA_HEIGHT),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(ParallaxCamera::default());
+
+    commands.spawn(ParallaxLayer {
+        image: "mountains_background.png".into(),
+        depth: 84.0.into(),
+        flags: ParallaxFlags::REPEAT_X_AXIS,
+        ..default()
+    });
+
+    let label = images.add(sign_distance_field());
+    for (depth, offset, color) in [
+        (60.0, Vec2::new(-60.0, 40.0), Color::hex("FFEC27").unwrap()),
+        (-4.0, Vec2::new(40.0, 10.0), Color::hex("FF004D").unwrap()),
+    ] {
+        commands.spawn(
+            ParallaxLayer::new(label.clone())
+                .depth(depth)
+                .offset(offset)
+                .color(color)
+                .sdf()
+                .build(),
+        );
+    }
+}
+
+/// Creates a distance field of a rounded sign with a hole for hanging it, stored as linear data
+/// in the red channel, with `0.5` on the edge.
+fn sign_distance_field() -> Image {
+    let size = LABEL_SIZE.as_vec2();
+    let half_size = size / 2.0 - Vec2::splat(LABEL_SPREAD);
+    let corner_radius = 6.0;
+
+    let data = (0..LABEL_SIZE.y)
+        .flat_map(|y| (0..LABEL_SIZE.x).map(move |x| Vec2::new(x as f32, y as f32)))
+        .map(|texel| {
+            let position = texel + 0.5 - size / 2.0;
+
+            // Signed distance to the rounded rectangle, negative inside
+            let corner = position.abs() - half_size + corner_radius;
+            let rectangle =
+                corner.max(Vec2::ZERO).length() + corner.max_element().min(0.0) - corner_radius;
+            // Cut out the hole near the top, the rows of the image go from top to bottom
+            let hole = 3.0 - position.distance(Vec2::new(0.0, 6.0 - half_size.y));
+            let distance = rectangle.max(hole);
+
+            ((0.5 - distance / LABEL_SPREAD / 2.0).clamp(0.0, 1.0) * 255.0) as u8
+        })
+        .collect();
+
+    Image::new(
+        Extent3d {
+            width: LABEL_SIZE.x,
+            height: LABEL_SIZE.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::R8Unorm,
+        RenderAssetUsages::default(),
+    )
+}
+
+fn move_camera(
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let mut camera_transform = camera_query
+        .get_single_mut()
+        .expect("There should only be one Camera2d");
+
+    let left = input.pressed(KeyCode::ArrowLeft) as u32 as f32;
+    let right = input.pressed(KeyCode::ArrowRight) as u32 as f32;
+    let up = input.pressed(KeyCode::ArrowUp) as u32 as f32;
+    let down = input.pressed(KeyCode::ArrowDown) as u32 as f32;
+    let move_direction = Vec2::new(right - left, up - down) * CAMERA_MOVE_SPEED;
+
+    camera_transform.translation += move_direction.extend(0.0);
+}
@@ -0,0 +1,69 @@
+use bevy::{prelude::*, render::camera::ScalingMode};
+use bevy_parallaxation2d::prelude::*;
+
+// This example demonstrates how to use a solid color parallax layer as a backdrop.
+// A dark plane fills the view behind the mountains, without needing a texture for it.
+
+const CAMERA_MOVE_SPEED: f32 = 5.0;
+const CAMERA_HEIGHT: f32 = 180.0;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins.set(ImagePlugin::default_nearest()),
+            ParallaxPlugin::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, move_camera)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands
+        .spawn(Camera2dBundle {
+            projection: OrthographicProjection {
+                near: -1000.0,
+                far: 1000.0,
+                scaling_mode: ScalingMode::FixedVertical(CAMERA_HEIGHT),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(ParallaxCamera::default());
+
+    commands.spawn(ParallaxSolidLayer {
+        color: Color::hex("1D2B53").unwrap(),
+        depth: 95.0.into(),
+    });
+
+    commands.spawn(ParallaxLayer {
+        image: "mountains_background.png".into(),
+        depth: 84.0.into(),
+        flags: ParallaxFlags::REPEAT_X_AXIS,
+        ..default()
+    });
+
+    commands.spawn(ParallaxLayer {
+        image: "trees_background.png".into(),
+        depth: 55.0.into(),
+        flags: ParallaxFlags::REPEAT_X_AXIS,
+        ..default()
+    });
+}
+
+fn move_camera(
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let mut camera_transform = camera_query
+        .get_single_mut()
+        .expect("There should only be one Camera2d");
+
+    let left = input.pressed(KeyCode::ArrowLeft) as u32 as f32;
+    let right = input.pressed(KeyCode::ArrowRight) as u32 as f32;
+    let up = input.pressed(KeyCode::ArrowUp) as u32 as f32;
+    let down = input.pressed(KeyCode::ArrowDown) as u32 as f32;
+    let move_direction = Vec2::new(right - left, up - down) * CAMERA_MOVE_SPEED;
+
+    camera_transform.translation += move_direction.extend(0.0);
+}
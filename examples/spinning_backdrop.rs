@@ -0,0 +1,126 @@
+use bevy::{
+    prelude::*,
+    render::{
+        camera::ScalingMode,
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+};
+use bevy_parallaxation2d::prelude::*;
+use std::f32::consts::TAU;
+
+// This example demonstrates a spinning backdrop behind the mountains.
+// The texture of a distant galaxy rotates around the center of its layer, while the layer
+// itself moves with the parallax effect like any other layer.
+
+const CAMERA_MOVE_SPEED: f32 = 5.0;
+const CAMERA_HEIGHT: f32 = 180.0;
+const GALAXY_SIZE: u32 = 128;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::hex("1D2B53").unwrap()))
+        .add_plugins((
+            DefaultPlugins.set(ImagePlugin::default_nearest()),
+            ParallaxPlugin::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, move_camera)
+        .run();
+}
+
+fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands
+        .spawn(Camera2dBundle {
+            projection: OrthographicProjection {
+                near: -1000.0,
+                far: 1000.0,
+                scaling_mode: ScalingMode::FixedVertical(CAMERA_HEIGHT),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(ParallaxCamera::default());
+
+    // The galaxy has a transparent border, so its corners are never cut off while rotating
+    commands.spawn((
+        ParallaxLayer::new(images.add(galaxy()))
+            .depth(95.0)
+            .offset(Vec2::new(40.0, 40.0))
+            .build(),
+        ParallaxUvRotation {
+            radians_per_sec: TAU / 30.0,
+        },
+    ));
+
+    commands.spawn_batch(vec![
+        ParallaxLayer {
+            image: "mountains_background.png".into(),
+            depth: 84.0.into(),
+            ..default()
+        },
+        ParallaxLayer {
+            image: "back_trees_background.png".into(),
+            depth: 70.0.into(),
+            ..default()
+        },
+    ]);
+}
+
+/// Creates a spiral galaxy with two arms, fading out towards the edge of the image.
+fn galaxy() -> Image {
+    let center = Vec2::splat(GALAXY_SIZE as f32 / 2.0);
+
+    let data = (0..GALAXY_SIZE)
+        .flat_map(|y| (0..GALAXY_SIZE).map(move |x| Vec2::new(x as f32, y as f32)))
+        .flat_map(|texel| {
+            let position = (texel + 0.5 - center) / center;
+            let radius = position.length();
+            let angle = position.y.atan2(position.x);
+
+            // Two arms winding outwards, on top of a bright core
+            let arms = (2.0 * angle - 8.0 * radius).cos() * 0.5 + 0.5;
+            let core = (1.0 - radius * 4.0).max(0.0);
+            // Fade out well within the inscribed circle, leaving the corners transparent
+            let falloff = (1.0 - radius / 0.7).clamp(0.0, 1.0);
+            let brightness = ((arms.powi(3) * 0.8 + core) * falloff).min(1.0);
+
+            let color = Vec3::new(1.0, 0.9, 1.0) * brightness;
+            [
+                (color.x * 255.0) as u8,
+                (color.y * 255.0) as u8,
+                (color.z * 255.0) as u8,
+                (brightness * 255.0) as u8,
+            ]
+        })
+        .collect();
+
+    Image::new(
+        Extent3d {
+            width: GALAXY_SIZE,
+            height: GALAXY_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+fn move_camera(
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let mut camera_transform = camera_query
+        .get_single_mut()
+        .expect("There should only be one Camera2d");
+
+    let left = input.pressed(KeyCode::ArrowLeft) as u32 as f32;
+    let right = input.pressed(KeyCode::ArrowRight) as u32 as f32;
+    let up = input.pressed(KeyCode::ArrowUp) as u32 as f32;
+    let down = input.pressed(KeyCode::ArrowDown) as u32 as f32;
+    let move_direction = Vec2::new(right - left, up - down) * CAMERA_MOVE_SPEED;
+
+    camera_transform.translation += move_direction.extend(0.0);
+}
@@ -0,0 +1,67 @@
+use bevy::{prelude::*, render::camera::ScalingMode};
+use bevy_parallaxation2d::prelude::*;
+
+// This example demonstrates how to only show a parallax layer through a shaped hole with a mask.
+// The trees are only visible through the window in the middle of the screen, while the
+// mountains behind them are always visible. Since the mask is in screen space, the window stays
+// in place while the trees scroll past it.
+
+const CAMERA_MOVE_SPEED: f32 = 5.0;
+const CAMERA_HEIGHT: f32 = 180.0;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::hex("29ADFF").unwrap()))
+        .add_plugins((
+            DefaultPlugins.set(ImagePlugin::default_nearest()),
+            ParallaxPlugin::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, move_camera)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands
+        .spawn(Camera2dBundle {
+            projection: OrthographicProjection {
+                near: -1000.0,
+                far: 1000.0,
+                scaling_mode: ScalingMode::FixedVertical(CAMERA_HEIGHT),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(ParallaxCamera::default());
+
+    commands.spawn(ParallaxLayer {
+        image: "mountains_background.png".into(),
+        depth: 84.0.into(),
+        ..default()
+    });
+
+    commands.spawn(ParallaxLayer {
+        image: "trees_background.png".into(),
+        depth: 55.0.into(),
+        mask_image: Some("window_mask.png".into()),
+        mask_space: MaskSpace::Screen,
+        ..default()
+    });
+}
+
+fn move_camera(
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    let mut camera_transform = camera_query
+        .get_single_mut()
+        .expect("There should only be one Camera2d");
+
+    let left = input.pressed(KeyCode::ArrowLeft) as u32 as f32;
+    let right = input.pressed(KeyCode::ArrowRight) as u32 as f32;
+    let up = input.pressed(KeyCode::ArrowUp) as u32 as f32;
+    let down = input.pressed(KeyCode::ArrowDown) as u32 as f32;
+    let move_direction = Vec2::new(right - left, up - down) * CAMERA_MOVE_SPEED;
+
+    camera_transform.translation += move_direction.extend(0.0);
+}
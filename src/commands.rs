@@ -1,48 +1,387 @@
-use crate::{components::ParallaxLayerData, depth::Depth};
-use bevy::prelude::{Commands, Entity, World};
+use crate::{
+    components::{ParallaxCamera, ParallaxLayer, ParallaxLayerData, ParallaxTargetCamera},
+    depth::Depth,
+    queries::{back_layer_of, front_layer_of},
+    systems::{primary_camera, reprocess_all_parallax_layers},
+};
+use bevy::{
+    ecs::system::{Command, RunSystemOnce},
+    log::warn,
+    prelude::{Commands, Entity, Or, Visibility, With, World},
+    utils::HashSet,
+};
 
+/// Commands for despawning parallax layers.
+///
+/// The layer to despawn is chosen when the command is applied, after the commands queued
+/// before it. Calling a command several times in one frame thereby despawns as many layers.
 pub trait ParallaxDespawnCommands {
     /// Despawn the front most parallax layer
     fn despawn_front_layer(&mut self);
 
     /// Despawn the back most parallax layer
     fn despawn_back_layer(&mut self);
+
+    /// Despawn the front most parallax layer of the given camera
+    ///
+    /// Only layers bound to the camera with a [`ParallaxTargetCamera`], and layers not bound
+    /// to any camera, are considered. Without any bound layers this is the same as
+    /// [`despawn_front_layer`](ParallaxDespawnCommands::despawn_front_layer).
+    fn despawn_front_layer_for(&mut self, camera: Entity);
+
+    /// Despawn the back most parallax layer of the given camera
+    ///
+    /// Only layers bound to the camera with a [`ParallaxTargetCamera`], and layers not bound
+    /// to any camera, are considered. Without any bound layers this is the same as
+    /// [`despawn_back_layer`](ParallaxDespawnCommands::despawn_back_layer).
+    fn despawn_back_layer_for(&mut self, camera: Entity);
+
+    /// Despawn every parallax layer
+    ///
+    /// Includes the layers spawned as a [`ParallaxLayer`] that are not yet loaded. Useful when
+    /// tearing down a level.
+    fn despawn_all_layers(&mut self);
+
+    /// Despawn every parallax layer at the given depth
+    ///
+    /// A layer matches if either the depth it was created with, or its resolved world depth,
+    /// equals the given depth. Parallax depths are thereby compared with the created depths,
+    /// and world depths also with the resolved depths of layers created with a parallax depth.
+    fn despawn_layer_at_depth(&mut self, depth: Depth);
+
+    /// Despawn every parallax layer with a depth from `min` to `max`, both inclusive
+    ///
+    /// Layers match like in [`despawn_layer_at_depth`](ParallaxDespawnCommands::despawn_layer_at_depth).
+    /// Note that a greater parallax depth is further back, while a greater world depth is
+    /// further in front.
+    fn despawn_layers_in_range(&mut self, min: Depth, max: Depth);
 }
 
 impl<'w, 's> ParallaxDespawnCommands for Commands<'w, 's> {
     fn despawn_front_layer(&mut self) {
-        self.add(|world: &mut World| {
-            let mut parallax_layers_query = world.query::<(Entity, &ParallaxLayerData)>();
-            let (mut front_layer_entity, mut min_depth) = (None, Depth::from_world(f32::MIN, 1.0));
-            for (entity, parallax) in parallax_layers_query.iter(world) {
-                if parallax.depth > min_depth {
-                    (front_layer_entity, min_depth) = (Some(entity), parallax.depth);
-                }
-            }
-
-            if let Some(front_entity) = front_layer_entity {
-                world.despawn(front_entity);
-            }
-        });
+        self.add(|world: &mut World| despawn_front_layer(world, None));
     }
 
     fn despawn_back_layer(&mut self) {
-        self.add(|world: &mut World| {
-            let mut parallax_layers_query = world.query::<(Entity, &ParallaxLayerData)>();
-            let (mut back_layer_entity, mut max_depth) = (None, Depth::from_world(f32::MAX, 1.0));
-            for (entity, parallax) in parallax_layers_query.iter(world) {
-                if parallax.depth < max_depth {
-                    (back_layer_entity, max_depth) = (Some(entity), parallax.depth);
-                }
-            }
-
-            if let Some(back_entity) = back_layer_entity {
-                world.despawn(back_entity);
-            }
+        self.add(|world: &mut World| despawn_back_layer(world, None));
+    }
+
+    fn despawn_front_layer_for(&mut self, camera: Entity) {
+        self.add(move |world: &mut World| despawn_front_layer(world, Some(camera)));
+    }
+
+    fn despawn_back_layer_for(&mut self, camera: Entity) {
+        self.add(move |world: &mut World| despawn_back_layer(world, Some(camera)));
+    }
+
+    fn despawn_all_layers(&mut self) {
+        self.add(despawn_all_layers);
+    }
+
+    fn despawn_layer_at_depth(&mut self, depth: Depth) {
+        self.add(move |world: &mut World| {
+            despawn_layers_by_depth(world, |layer_depth| layer_depth == depth);
+        });
+    }
+
+    fn despawn_layers_in_range(&mut self, min: Depth, max: Depth) {
+        self.add(move |world: &mut World| {
+            despawn_layers_by_depth(world, |layer_depth| {
+                min <= layer_depth && layer_depth <= max
+            });
         });
     }
 }
 
+pub trait ParallaxCameraCommands {
+    /// Rebind every parallax layer to the current parallax camera
+    ///
+    /// Every [`ParallaxTargetCamera`] pointing at an entity which is no longer a
+    /// [`ParallaxCamera`], such as a despawned or replaced camera, is pointed at the primary
+    /// [`ParallaxCamera`]. Layers bound to another parallax camera keep their camera. Every
+    /// layer is then processed again against the projection of its camera, see
+    /// [`ParallaxReprocessAll`]. Useful after moving the [`ParallaxCamera`] to another camera,
+    /// for example during a scene transition.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use bevy::prelude::{Commands, Entity};
+    /// use bevy_parallaxation2d::prelude::*;
+    ///
+    /// fn switch_camera(mut commands: Commands, old_camera: Entity, new_camera: Entity) {
+    ///     commands.entity(old_camera).remove::<ParallaxCamera>();
+    ///     commands.entity(new_camera).insert(ParallaxCamera::default());
+    ///     commands.rebind_layers_to_camera();
+    /// }
+    /// ```
+    fn rebind_layers_to_camera(&mut self);
+}
+
+impl<'w, 's> ParallaxCameraCommands for Commands<'w, 's> {
+    fn rebind_layers_to_camera(&mut self) {
+        self.add(rebind_layers_to_camera);
+    }
+}
+
+pub trait ParallaxLayerCommands {
+    /// Swap the depths of two parallax layers
+    ///
+    /// The layers exchange the depths they were created with, and are processed again,
+    /// swapping their world depth, depth factor and thereby their ordering and speed. Does
+    /// nothing, with a warning, if either entity is not a parallax layer.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use bevy::prelude::{Commands, Entity};
+    /// use bevy_parallaxation2d::prelude::*;
+    ///
+    /// fn solve_puzzle(mut commands: Commands, front: Entity, back: Entity) {
+    ///     commands.swap_layer_depths(front, back);
+    /// }
+    /// ```
+    fn swap_layer_depths(&mut self, a: Entity, b: Entity);
+}
+
+impl<'w, 's> ParallaxLayerCommands for Commands<'w, 's> {
+    fn swap_layer_depths(&mut self, a: Entity, b: Entity) {
+        self.add(move |world: &mut World| swap_layer_depths(world, a, b));
+    }
+}
+
+/// Commands for hiding and showing parallax layers.
+///
+/// Hiding a layer keeps it spawned along with its [`ParallaxLayerData`] and material, so
+/// showing it again is cheap, unlike despawning and spawning it again. A hidden layer keeps
+/// following the camera. Only loaded layers can be hidden.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Commands, Entity};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn toggle_clouds(mut commands: Commands, clouds: Entity, visible: bool) {
+///     commands.set_layer_visible(clouds, visible);
+/// }
+/// ```
+pub trait ParallaxVisibilityCommands {
+    /// Show or hide a parallax layer
+    ///
+    /// Does nothing, with a warning, if the entity is not a loaded parallax layer.
+    fn set_layer_visible(&mut self, entity: Entity, visible: bool);
+
+    /// Hide the front most visible parallax layer
+    fn hide_front_layer(&mut self);
+
+    /// Hide the back most visible parallax layer
+    fn hide_back_layer(&mut self);
+
+    /// Show every hidden parallax layer
+    fn show_all_layers(&mut self);
+}
+
+impl<'w, 's> ParallaxVisibilityCommands for Commands<'w, 's> {
+    fn set_layer_visible(&mut self, entity: Entity, visible: bool) {
+        self.add(move |world: &mut World| set_layer_visible(world, entity, visible));
+    }
+
+    fn hide_front_layer(&mut self) {
+        self.add(hide_front_layer);
+    }
+
+    fn hide_back_layer(&mut self) {
+        self.add(hide_back_layer);
+    }
+
+    fn show_all_layers(&mut self) {
+        self.add(show_all_layers);
+    }
+}
+
+/// Command processing every parallax layer again.
+///
+/// Re-reads the image sizes, sets up the samplers, and resolves the scale, offset and depth of
+/// every layer, like when a layer is first loaded. Useful after changing something the
+/// parallax system does not track, such as the projection of the parallax camera.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::Commands;
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn refresh_layers(mut commands: Commands) {
+///     commands.add(ParallaxReprocessAll);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ParallaxReprocessAll;
+
+impl Command for ParallaxReprocessAll {
+    fn apply(self, world: &mut World) {
+        world.run_system_once(reprocess_all_parallax_layers);
+    }
+}
+
+/// Point the layers bound to a camera which is no longer a parallax camera at the primary
+/// parallax camera, and process every layer again
+fn rebind_layers_to_camera(world: &mut World) {
+    let mut camera_query = world.query::<(Entity, &ParallaxCamera)>();
+    let camera = primary_camera(camera_query.iter(world));
+    let cameras: HashSet<_> = camera_query.iter(world).map(|(entity, _)| entity).collect();
+
+    let mut target_camera_query = world.query::<&mut ParallaxTargetCamera>();
+    for mut target_camera in target_camera_query.iter_mut(world) {
+        if !cameras.contains(&target_camera.0) {
+            target_camera.0 = camera;
+        }
+    }
+
+    world.run_system_once(reprocess_all_parallax_layers);
+}
+
+/// Exchange the authored depths of two parallax layers, triggering their processing
+fn swap_layer_depths(world: &mut World, a: Entity, b: Entity) {
+    let depths = [a, b].map(|entity| {
+        world
+            .get::<ParallaxLayerData>(entity)
+            .map(|parallax| parallax.authored_depth)
+    });
+
+    let [Some(depth_a), Some(depth_b)] = depths else {
+        warn!("Cannot swap the depths of {a:?} and {b:?} as both must be parallax layers");
+        return;
+    };
+
+    for (entity, depth) in [(a, depth_b), (b, depth_a)] {
+        if let Some(mut parallax) = world.get_mut::<ParallaxLayerData>(entity) {
+            parallax.set_depth(depth);
+        }
+    }
+}
+
+/// Set the visibility of a loaded parallax layer
+fn set_layer_visible(world: &mut World, entity: Entity, visible: bool) {
+    let is_layer = world.get::<ParallaxLayerData>(entity).is_some();
+    let Some(mut visibility) = world.get_mut::<Visibility>(entity).filter(|_| is_layer) else {
+        warn!("Cannot set the visibility of {entity:?} as it is not a loaded parallax layer");
+        return;
+    };
+
+    *visibility = match visible {
+        true => Visibility::Inherited,
+        false => Visibility::Hidden,
+    };
+}
+
+/// Hide the front most visible parallax layer
+fn hide_front_layer(world: &mut World) {
+    let mut parallax_layers_query = world.query::<(Entity, &ParallaxLayerData, &Visibility)>();
+    let front_layer = front_layer_of(
+        parallax_layers_query
+            .iter(world)
+            .filter(|(_, _, visibility)| **visibility != Visibility::Hidden)
+            .map(|(entity, parallax, _)| (entity, parallax)),
+    );
+
+    if let Some((front_entity, _)) = front_layer {
+        set_layer_visible(world, front_entity, false);
+    }
+}
+
+/// Hide the back most visible parallax layer
+fn hide_back_layer(world: &mut World) {
+    let mut parallax_layers_query = world.query::<(Entity, &ParallaxLayerData, &Visibility)>();
+    let back_layer = back_layer_of(
+        parallax_layers_query
+            .iter(world)
+            .filter(|(_, _, visibility)| **visibility != Visibility::Hidden)
+            .map(|(entity, parallax, _)| (entity, parallax)),
+    );
+
+    if let Some((back_entity, _)) = back_layer {
+        set_layer_visible(world, back_entity, false);
+    }
+}
+
+/// Show every hidden parallax layer
+fn show_all_layers(world: &mut World) {
+    let mut visibility_query = world.query_filtered::<&mut Visibility, With<ParallaxLayerData>>();
+    for mut visibility in visibility_query.iter_mut(world) {
+        if *visibility == Visibility::Hidden {
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+/// Despawn the parallax layers whose created or resolved depth matches
+///
+/// Depths of different kinds are not comparable, which the predicate sees as no match.
+fn despawn_layers_by_depth(world: &mut World, matches: impl Fn(Depth) -> bool) {
+    let mut parallax_layers_query = world.query::<(Entity, &ParallaxLayerData)>();
+    let layers = parallax_layers_query
+        .iter(world)
+        .filter(|(_, parallax)| matches(parallax.authored_depth) || matches(parallax.depth))
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+
+    for layer in layers {
+        world.despawn(layer);
+    }
+}
+
+/// Despawn the front most parallax layer, of the given camera if any
+fn despawn_front_layer(world: &mut World, camera: Option<Entity>) {
+    let mut parallax_layers_query =
+        world.query::<(Entity, &ParallaxLayerData, Option<&ParallaxTargetCamera>)>();
+    let front_layer = front_layer_of(
+        parallax_layers_query
+            .iter(world)
+            .filter(|(_, _, target_camera)| is_layer_of_camera(*target_camera, camera))
+            .map(|(entity, parallax, _)| (entity, parallax)),
+    );
+
+    if let Some((front_entity, _)) = front_layer {
+        world.despawn(front_entity);
+    }
+}
+
+/// Despawn the back most parallax layer, of the given camera if any
+fn despawn_back_layer(world: &mut World, camera: Option<Entity>) {
+    let mut parallax_layers_query =
+        world.query::<(Entity, &ParallaxLayerData, Option<&ParallaxTargetCamera>)>();
+    let back_layer = back_layer_of(
+        parallax_layers_query
+            .iter(world)
+            .filter(|(_, _, target_camera)| is_layer_of_camera(*target_camera, camera))
+            .map(|(entity, parallax, _)| (entity, parallax)),
+    );
+
+    if let Some((back_entity, _)) = back_layer {
+        world.despawn(back_entity);
+    }
+}
+
+/// Despawn every parallax layer, loaded or not
+fn despawn_all_layers(world: &mut World) {
+    let mut parallax_layers_query =
+        world.query_filtered::<Entity, Or<(With<ParallaxLayerData>, With<ParallaxLayer>)>>();
+    let layers = parallax_layers_query.iter(world).collect::<Vec<_>>();
+    for layer in layers {
+        world.despawn(layer);
+    }
+}
+
+/// Layers not bound to a camera belong to every camera
+#[inline]
+fn is_layer_of_camera(
+    target_camera: Option<&ParallaxTargetCamera>,
+    camera: Option<Entity>,
+) -> bool {
+    match (target_camera, camera) {
+        (Some(target_camera), Some(camera)) => target_camera.0 == camera,
+        _ => true,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,16 +395,16 @@ mod tests {
 
         // Spawn
         Commands::new(&mut command_queue, &world).spawn_batch(vec![
-            ParallaxLayerData {
-                depth: Depth::from_world(10.0, 1.0),
-                offset: Vec2::ZERO,
-                flags: ParallaxFlags::NONE,
-            },
-            ParallaxLayerData {
-                depth: Depth::from_world(-12.0, 1.0),
-                offset: Vec2::ZERO,
-                flags: ParallaxFlags::NONE,
-            },
+            ParallaxLayerData::new(
+                Depth::from_world(10.0, 1.0),
+                Vec2::ZERO,
+                ParallaxFlags::NONE,
+            ),
+            ParallaxLayerData::new(
+                Depth::from_world(-12.0, 1.0),
+                Vec2::ZERO,
+                ParallaxFlags::NONE,
+            ),
         ]);
         command_queue.apply(&mut world);
         assert_eq!(world.entities().len(), 2);
@@ -89,16 +428,16 @@ mod tests {
 
         // Spawn
         Commands::new(&mut command_queue, &world).spawn_batch(vec![
-            ParallaxLayerData {
-                depth: Depth::from_world(10.0, 1.0),
-                offset: Vec2::ZERO,
-                flags: ParallaxFlags::NONE,
-            },
-            ParallaxLayerData {
-                depth: Depth::from_world(-12.0, 1.0),
-                offset: Vec2::ZERO,
-                flags: ParallaxFlags::NONE,
-            },
+            ParallaxLayerData::new(
+                Depth::from_world(10.0, 1.0),
+                Vec2::ZERO,
+                ParallaxFlags::NONE,
+            ),
+            ParallaxLayerData::new(
+                Depth::from_world(-12.0, 1.0),
+                Vec2::ZERO,
+                ParallaxFlags::NONE,
+            ),
         ]);
         command_queue.apply(&mut world);
         assert_eq!(world.entities().len(), 2);
@@ -122,26 +461,18 @@ mod tests {
 
         // Spawn
         Commands::new(&mut command_queue, &world).spawn_batch(vec![
-            ParallaxLayerData {
-                depth: Depth::from_world(10.0, 1.0),
-                offset: Vec2::ZERO,
-                flags: ParallaxFlags::NONE,
-            },
-            ParallaxLayerData {
-                depth: Depth::from_world(-12.0, 1.0),
-                offset: Vec2::ZERO,
-                flags: ParallaxFlags::NONE,
-            },
-            ParallaxLayerData {
-                depth: Depth::from_world(0.0, 1.0),
-                offset: Vec2::ZERO,
-                flags: ParallaxFlags::NONE,
-            },
-            ParallaxLayerData {
-                depth: Depth::from_world(4.0, 1.0),
-                offset: Vec2::ZERO,
-                flags: ParallaxFlags::NONE,
-            },
+            ParallaxLayerData::new(
+                Depth::from_world(10.0, 1.0),
+                Vec2::ZERO,
+                ParallaxFlags::NONE,
+            ),
+            ParallaxLayerData::new(
+                Depth::from_world(-12.0, 1.0),
+                Vec2::ZERO,
+                ParallaxFlags::NONE,
+            ),
+            ParallaxLayerData::new(Depth::from_world(0.0, 1.0), Vec2::ZERO, ParallaxFlags::NONE),
+            ParallaxLayerData::new(Depth::from_world(4.0, 1.0), Vec2::ZERO, ParallaxFlags::NONE),
         ]);
         command_queue.apply(&mut world);
         assert_eq!(world.entities().len(), 4);
@@ -158,7 +489,7 @@ mod tests {
         assert_eq!(result.len(), 3);
         assert_eq!(world.entities().len(), 3);
         for depth in result.iter() {
-            assert_eq!([0.0, -12.0, 4.0].contains(depth), true);
+            assert!([0.0, -12.0, 4.0].contains(depth));
         }
 
         // Despawn back
@@ -173,7 +504,7 @@ mod tests {
         assert_eq!(result.len(), 2);
         assert_eq!(world.entities().len(), 2);
         for depth in result.iter() {
-            assert_eq!([0.0, 4.0].contains(depth), true);
+            assert!([0.0, 4.0].contains(depth));
         }
 
         // Despawn back
@@ -188,7 +519,7 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert_eq!(world.entities().len(), 1);
         for depth in result.iter() {
-            assert_eq!([4.0].contains(depth), true);
+            assert!([4.0].contains(depth));
         }
 
         // Despawn front
@@ -215,4 +546,229 @@ mod tests {
         assert_eq!(result.len(), 0);
         assert_eq!(world.entities().len(), 0);
     }
+
+    #[test]
+    fn commands_despawn_layer_for_camera() {
+        let mut world = World::default();
+        let mut command_queue = CommandQueue::default();
+        let [camera_a, camera_b] = [(), ()].map(|_| world.spawn_empty().id());
+
+        // Spawn
+        let layer = |depth: f32| {
+            ParallaxLayerData::new(
+                Depth::from_world(depth, 1.0),
+                Vec2::ZERO,
+                ParallaxFlags::NONE,
+            )
+        };
+        let mut commands = Commands::new(&mut command_queue, &world);
+        commands.spawn((layer(10.0), ParallaxTargetCamera(camera_a)));
+        commands.spawn((layer(-12.0), ParallaxTargetCamera(camera_a)));
+        commands.spawn((layer(20.0), ParallaxTargetCamera(camera_b)));
+        commands.spawn((layer(-20.0), ParallaxTargetCamera(camera_b)));
+        commands.spawn(layer(0.0));
+        command_queue.apply(&mut world);
+
+        let depths = |world: &mut World| {
+            let mut result = world
+                .query::<&ParallaxLayerData>()
+                .iter(world)
+                .map(|e| e.depth.depth())
+                .collect::<Vec<_>>();
+            result.sort_by(f32::total_cmp);
+            result
+        };
+
+        // Despawn within the layers of camera A
+        let mut commands = Commands::new(&mut command_queue, &world);
+        commands.despawn_front_layer_for(camera_a);
+        commands.despawn_back_layer_for(camera_a);
+        command_queue.apply(&mut world);
+        assert_eq!(depths(&mut world), vec![-20.0, 0.0, 20.0]);
+
+        // Unbound layers belong to every camera
+        Commands::new(&mut command_queue, &world).despawn_back_layer_for(camera_a);
+        command_queue.apply(&mut world);
+        assert_eq!(depths(&mut world), vec![-20.0, 20.0]);
+
+        // Camera A has no layers left
+        Commands::new(&mut command_queue, &world).despawn_front_layer_for(camera_a);
+        command_queue.apply(&mut world);
+        assert_eq!(depths(&mut world), vec![-20.0, 20.0]);
+
+        // Global despawn considers every layer
+        Commands::new(&mut command_queue, &world).despawn_front_layer();
+        command_queue.apply(&mut world);
+        assert_eq!(depths(&mut world), vec![-20.0]);
+    }
+
+    #[test]
+    fn commands_despawn_all_layers() {
+        let mut world = World::default();
+        let mut command_queue = CommandQueue::default();
+
+        // Spawn
+        let mut commands = Commands::new(&mut command_queue, &world);
+        commands.spawn_batch([10.0, -12.0, 0.0, 4.0].map(|depth| {
+            ParallaxLayerData::new(
+                Depth::from_world(depth, 1.0),
+                Vec2::ZERO,
+                ParallaxFlags::NONE,
+            )
+        }));
+        commands.spawn(ParallaxLayer::default());
+        command_queue.apply(&mut world);
+        assert_eq!(world.entities().len(), 5);
+
+        // Despawn all
+        Commands::new(&mut command_queue, &world).despawn_all_layers();
+        command_queue.apply(&mut world);
+        assert_eq!(world.entities().len(), 0);
+    }
+
+    #[test]
+    fn commands_despawn_layers_in_one_frame() {
+        let mut world = World::default();
+        let mut command_queue = CommandQueue::default();
+
+        // Spawn
+        Commands::new(&mut command_queue, &world).spawn_batch([10.0, -12.0, 0.0, 4.0, -2.0].map(
+            |depth| {
+                ParallaxLayerData::new(
+                    Depth::from_world(depth, 1.0),
+                    Vec2::ZERO,
+                    ParallaxFlags::NONE,
+                )
+            },
+        ));
+        command_queue.apply(&mut world);
+
+        // Every call despawns another layer, even before the queue is applied
+        let mut commands = Commands::new(&mut command_queue, &world);
+        commands.despawn_front_layer();
+        commands.despawn_front_layer();
+        commands.despawn_back_layer();
+        command_queue.apply(&mut world);
+
+        let mut result = world
+            .query::<&ParallaxLayerData>()
+            .iter(&world)
+            .map(|e| e.depth.depth())
+            .collect::<Vec<_>>();
+        result.sort_by(f32::total_cmp);
+        assert_eq!(result, vec![-2.0, 0.0]);
+    }
+
+    #[test]
+    fn commands_despawn_layers_by_depth() {
+        let mut world = World::default();
+        let mut command_queue = CommandQueue::default();
+
+        // Despawn nothing from an empty world
+        let mut commands = Commands::new(&mut command_queue, &world);
+        commands.despawn_layer_at_depth(10.0.into());
+        commands.despawn_layers_in_range(0.0.into(), 100.0.into());
+        command_queue.apply(&mut world);
+        assert_eq!(world.entities().len(), 0);
+
+        // Spawn
+        let layer = |depth: Depth| ParallaxLayerData::new(depth, Vec2::ZERO, ParallaxFlags::NONE);
+        Commands::new(&mut command_queue, &world).spawn_batch(vec![
+            layer(10.0.into()),
+            layer(10.0.into()),
+            layer(40.0.into()),
+            layer(80.0.into()),
+            layer(Depth::from_world(-20.0, 0.5)),
+            layer(Depth::from_world(5.0, 1.0)),
+        ]);
+        command_queue.apply(&mut world);
+
+        let depths = |world: &mut World| {
+            let mut result = world
+                .query::<&ParallaxLayerData>()
+                .iter(world)
+                .map(|e| e.depth.depth())
+                .collect::<Vec<_>>();
+            result.sort_by(f32::total_cmp);
+            result
+        };
+
+        // No match
+        let mut commands = Commands::new(&mut command_queue, &world);
+        commands.despawn_layer_at_depth(20.0.into());
+        commands
+            .despawn_layers_in_range(Depth::from_world(-10.0, 1.0), Depth::from_world(0.0, 1.0));
+        command_queue.apply(&mut world);
+        assert_eq!(depths(&mut world), vec![-20.0, 5.0, 10.0, 10.0, 40.0, 80.0]);
+
+        // Multiple matches at one depth, skipping the world depths
+        Commands::new(&mut command_queue, &world).despawn_layer_at_depth(10.0.into());
+        command_queue.apply(&mut world);
+        assert_eq!(depths(&mut world), vec![-20.0, 5.0, 40.0, 80.0]);
+
+        // Multiple matches in a range
+        Commands::new(&mut command_queue, &world)
+            .despawn_layers_in_range(30.0.into(), 100.0.into());
+        command_queue.apply(&mut world);
+        assert_eq!(depths(&mut world), vec![-20.0, 5.0]);
+
+        Commands::new(&mut command_queue, &world)
+            .despawn_layers_in_range(Depth::from_world(-50.0, 0.0), Depth::from_world(5.0, 0.0));
+        command_queue.apply(&mut world);
+        assert_eq!(depths(&mut world), Vec::<f32>::new());
+    }
+
+    #[test]
+    fn commands_layer_visibility() {
+        let mut world = World::default();
+        let layers = [10.0, 0.0, -12.0].map(|depth| {
+            world
+                .spawn((
+                    ParallaxLayerData::new(
+                        Depth::from_world(depth, 1.0),
+                        Vec2::ZERO,
+                        ParallaxFlags::NONE,
+                    ),
+                    Visibility::default(),
+                ))
+                .id()
+        });
+        let not_loaded = world.spawn(Visibility::default()).id();
+        let mut command_queue = CommandQueue::default();
+        let hidden = |world: &World| {
+            layers.map(|layer| *world.get::<Visibility>(layer).unwrap() == Visibility::Hidden)
+        };
+
+        // Hiding skips the layers already hidden
+        let mut commands = Commands::new(&mut command_queue, &world);
+        commands.hide_front_layer();
+        commands.hide_front_layer();
+        command_queue.apply(&mut world);
+        assert_eq!(hidden(&world), [true, true, false]);
+
+        let mut commands = Commands::new(&mut command_queue, &world);
+        commands.show_all_layers();
+        commands.hide_back_layer();
+        commands.set_layer_visible(layers[0], false);
+        commands.set_layer_visible(not_loaded, false);
+        command_queue.apply(&mut world);
+        assert_eq!(hidden(&world), [true, false, true]);
+        assert_eq!(
+            *world.get::<Visibility>(not_loaded).unwrap(),
+            Visibility::Inherited
+        );
+
+        // The layers keep their data while hidden
+        Commands::new(&mut command_queue, &world).set_layer_visible(layers[0], true);
+        command_queue.apply(&mut world);
+        assert_eq!(hidden(&world), [false, false, true]);
+        assert_eq!(
+            world
+                .get::<ParallaxLayerData>(layers[0])
+                .unwrap()
+                .depth
+                .depth(),
+            10.0
+        );
+    }
 }
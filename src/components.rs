@@ -1,12 +1,24 @@
-use crate::{depth::Depth, flags::ParallaxFlags};
-use bevy::prelude::{Color, Component, Vec2};
+use crate::{
+    depth::Depth,
+    flags::{FlagConflictError, ParallaxFlags},
+};
+use bevy::prelude::{
+    AssetServer, BVec2, Color, Component, Entity, Handle, Image, OrthographicProjection, Rect,
+    Reflect, ReflectComponent, ReflectDefault, Transform, UVec2, Vec2, Window,
+};
+use std::{borrow::Cow, fmt};
 
-/// Marker component for the parallax camera.
+/// Component for marking the parallax camera.
 ///
-/// **Only one camera can use parallax layers**.
+/// **The primary camera positions the parallax layers** not bound to a camera. Further cameras
+/// can be marked as not primary, such as an effects camera rendering the same layers differently,
+/// or the second view of a split-screen game. A layer bound to a camera with a
+/// [`ParallaxTargetCamera`] is positioned for that camera instead, while the other cameras render
+/// it as positioned for its camera.
 ///
 /// ## Panics
-/// The application will panic if multiple (or none) `ParallaxCamera` components are detected.
+/// The application will panic if multiple (or none) primary `ParallaxCamera` components are
+/// detected.
 ///
 /// ## Examples
 /// ```
@@ -15,11 +27,108 @@ use bevy::prelude::{Color, Component, Vec2};
 ///
 /// fn setup(mut commands: Commands) {
 ///     // Add the ParallaxCamera component to your camera entity to enable parallax effects.
-///     commands.spawn(Camera2dBundle::default()).insert(ParallaxCamera);
+///     commands
+///         .spawn(Camera2dBundle::default())
+///         .insert(ParallaxCamera::default());
+///
+///     // Further cameras render the layers without positioning them
+///     commands
+///         .spawn(Camera2dBundle::default())
+///         .insert(ParallaxCamera { primary: false });
 /// }
 /// ```
-#[derive(Default, Component, Debug)]
-pub struct ParallaxCamera;
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParallaxCamera {
+    /// Whether the layers not bound to a camera are positioned for this camera. Exactly one
+    /// camera should be primary.
+    pub primary: bool,
+}
+
+impl Default for ParallaxCamera {
+    /// The default camera is primary.
+    #[inline]
+    fn default() -> Self {
+        Self { primary: true }
+    }
+}
+
+/// Image of a [`ParallaxLayer`], either an asset path or an already loaded image.
+///
+/// Converts from a `&'static str` or a `String` path, and from a `Handle<Image>`, such that the
+/// image of a layer can be chosen at runtime.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, AssetServer, Commands, Res};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     let level = 3;
+///     commands.spawn_batch([
+///         ParallaxLayer {
+///             image: format!("level_{level}/background.png").into(),
+///             ..default()
+///         },
+///         ParallaxLayer {
+///             image: asset_server.load("clouds.png").into(),
+///             ..default()
+///         },
+///     ]);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum ParallaxImageSource {
+    /// Path of the image asset, loaded when the layer is loaded.
+    Path(Cow<'static, str>),
+    /// Handle of an image asset.
+    Handle(Handle<Image>),
+}
+
+impl ParallaxImageSource {
+    /// Returns whether the source is an empty path.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Path(path) if path.is_empty())
+    }
+
+    /// Returns the handle of the image, loading it if the source is a path.
+    #[inline]
+    pub(crate) fn load(&self, asset_server: &AssetServer) -> Handle<Image> {
+        match self {
+            Self::Path(path) => asset_server.load(path.to_string()),
+            Self::Handle(handle) => handle.clone(),
+        }
+    }
+}
+
+impl Default for ParallaxImageSource {
+    #[inline]
+    fn default() -> Self {
+        Self::Path(Cow::Borrowed(""))
+    }
+}
+
+impl From<&'static str> for ParallaxImageSource {
+    #[inline]
+    fn from(path: &'static str) -> Self {
+        Self::Path(Cow::Borrowed(path))
+    }
+}
+
+impl From<String> for ParallaxImageSource {
+    #[inline]
+    fn from(path: String) -> Self {
+        Self::Path(Cow::Owned(path))
+    }
+}
+
+impl From<Handle<Image>> for ParallaxImageSource {
+    #[inline]
+    fn from(handle: Handle<Image>) -> Self {
+        Self::Handle(handle)
+    }
+}
 
 /// Inserting this component initiates a layer in the parallax scrolling system.
 ///
@@ -30,7 +139,7 @@ pub struct ParallaxCamera;
 ///
 /// fn setup(mut commands: Commands) {
 ///     let layer = ParallaxLayer {
-///         image: "background.png",
+///         image: "background.png".into(),
 ///         depth: 1.0.into(),
 ///         ..default()
 ///     };
@@ -41,12 +150,12 @@ pub struct ParallaxCamera;
 /// fn setup_extra(mut commands: Commands) {
 ///     let layers = vec![
 ///         ParallaxLayer {
-///             image: "background.png",
+///             image: "background.png".into(),
 ///             depth: 5.0.into(),
 ///             ..default()
 ///         },
 ///         ParallaxLayer {
-///             image: "foreground.png",
+///             image: "foreground.png".into(),
 ///             depth: (-2.0).into(),
 ///             flags: ParallaxFlags::REPEAT_Y_AXIS | ParallaxFlags::OFFSET_CAMERA_RIGHT,
 ///             ..default()
@@ -61,9 +170,19 @@ pub struct ParallaxCamera;
 /// It is not necessary to provide a `TransformBundle` to the parallax layer but if you do,
 /// the initialisation process only takes into account the z-value as a depth offset without
 /// affecting the depth factor of the parallax effect.
-#[derive(Default, Component, Debug)]
+///
+/// ## Reflection
+/// The layer is registered for reflection, such as for saving it in a scene. Serializing the
+/// layer in a scene requires the `serialize` feature.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
 pub struct ParallaxLayer {
-    pub image: &'static str,
+    /// Image of the parallax layer, see [`ParallaxImageSource`].
+    pub image: ParallaxImageSource,
+    /// Name of the parallax layer, inserted as a [`Name`](bevy::prelude::Name) component.
+    ///
+    /// Layers can be looked up by name with [`find_layer_by_name`](crate::queries::find_layer_by_name).
+    pub name: Option<Cow<'static, str>>,
     /// Color tint of the parallax layer.
     pub color: Color,
     /// The depth of the parallax layer, affecting its scroll speed.
@@ -72,15 +191,2027 @@ pub struct ParallaxLayer {
     ///
     /// The offset is used such that the layer will be centered in the camera view
     /// when the camera is at this position. The offset can be further tuned using
-    /// `OFFSET_TO_CAMERA` in the [`ParallaxFlags`].
+    /// `OFFSET_TO_CAMERA` in the [`ParallaxFlags`]. With [`OffsetSpace::CameraLocal`] the
+    /// offset is a distance in screen pixels from the camera instead.
     pub offset: Vec2,
     pub flags: ParallaxFlags,
+    /// World position where a tile boundary of the repeating axes falls.
+    ///
+    /// When the camera is centered at this position, a tile boundary lies exactly at it. Layers
+    /// at the neutral depth are stationary in world space, meaning their tiles stay aligned to
+    /// the world, for example to match a tilemap. If `None`, the tiling phase follows from the
+    /// offset of the layer.
+    pub tile_origin: Option<Vec2>,
+    /// Size of the tiles of the repeating axes, relative to the size of the image.
+    ///
+    /// Each repeating axis chooses its tile size independently. A positive component tiles
+    /// the axis at that multiple of the native size of the image, while a component of `0.0`
+    /// stretches a single tile over the view of the camera. Axes which do not repeat keep the
+    /// native size of the image. If `None`, the tiles have the native size of the image.
+    pub tile_scale: Option<Vec2>,
+    /// Image whose red channel defines the order in which the layer gets revealed by a
+    /// [`ParallaxDissolve`], such as a noise texture.
+    ///
+    /// If `None`, the layer appears at once when the dissolve finishes revealing.
+    pub dissolve_image: Option<Cow<'static, str>>,
+    /// Point within the layer placed at the position of the layer, from `(0.0, 0.0)` at the
+    /// bottom left to `(1.0, 1.0)` at the top right. Defaults to the center.
+    ///
+    /// Only applies to the non-repeating axes, as the layer covers the view on repeating axes.
+    pub pivot: Vec2,
+    /// Lower resolution variants of the image, used while the layer is far away.
+    ///
+    /// See [`ParallaxLod`] for how a variant gets selected.
+    pub lods: Vec<ParallaxLod>,
+    /// How the offset of the layer is interpreted, see [`OffsetSpace`].
+    pub offset_space: OffsetSpace,
+    /// Image whose alpha channel multiplies the alpha of the layer, such as to only show the
+    /// layer through a shaped hole.
+    ///
+    /// The mask is stretched over the space given by `mask_space`. If `None`, the layer is not
+    /// masked.
+    pub mask_image: Option<Cow<'static, str>>,
+    /// Space over which the `mask_image` is stretched, see [`MaskSpace`].
+    pub mask_space: MaskSpace,
+    /// Duration in seconds over which the layer fades in once processed, see [`ParallaxFadeIn`].
+    ///
+    /// If `None`, the layer appears at once.
+    pub fade_in: Option<f32>,
+    /// Strength, from `0.0` to `1.0`, of the variation breaking up the repetition of the
+    /// repeating axes, by flipping the texture of some tiles.
+    ///
+    /// Whether a tile is flipped horizontally depends only on its column, and vertically only
+    /// on its row, such that the edges of neighboring tiles still line up. At `0.0` every tile
+    /// is the same, at `1.0` half of the columns and rows are flipped.
+    pub detile_strength: f32,
+    /// Camera the layer is positioned for, inserted as a [`ParallaxTargetCamera`] component.
+    ///
+    /// If `None`, the layer is positioned for the primary camera, see [`ParallaxCamera`].
+    pub target_camera: Option<Entity>,
+    /// How the layer is blended with the layers behind it, see [`BlendMode`].
+    pub blend_mode: BlendMode,
+    /// Order of the layer among the layers at the same depth, where greater orders render in
+    /// front.
+    ///
+    /// The z-position of the layer is moved forward by `order_in_tier` times the tier epsilon,
+    /// see [`ParallaxPlugin::set_tier_epsilon`](crate::plugin::ParallaxPlugin::set_tier_epsilon).
+    /// The depth factor of the layer is unaffected.
+    pub order_in_tier: i32,
+    /// Distance by which the z-position of the layer is moved forward, such as to draw one of
+    /// two overlapping layers at the same depth factor in front of the other.
+    ///
+    /// Unlike the depth, the bias only affects the draw order of the layer and not its depth
+    /// factor, meaning the layer keeps scrolling at the speed of its depth.
+    pub z_bias: f32,
+    /// Maximum level of anisotropic filtering of the texture, improving the quality of a layer
+    /// viewed at a steep angle, such as on a skewed mesh.
+    ///
+    /// Anisotropic filtering only takes effect when the image has mipmaps. A level above `1`
+    /// samples the texture with linear filtering, as anisotropic filtering requires it, and is
+    /// ignored while rendering pixel-perfect. Common levels are `1`, `2`, `4`, `8` and `16`,
+    /// where `1` disables anisotropic filtering.
+    pub anisotropy: u16,
+    /// Whether the depth factor of the layer ignores the scale of the parallax effect, such as
+    /// for a frame which should keep its movement when the scale changes.
+    ///
+    /// The depth factor gets resolved as if the scale were `1.0`, see
+    /// [`ParallaxPlugin::set_scale`](crate::plugin::ParallaxPlugin::set_scale). A depth created
+    /// with [`Depth::from_world`] is never scaled.
+    pub ignore_global_scale: bool,
+    /// Whether the image is a signed distance field, such as for text or signage which should
+    /// stay crisp at any scale.
+    ///
+    /// The red channel of the image holds the distance to the edge of the shape, where `0.5`
+    /// lies on the edge, and greater values lie inside. The shape is filled with the `color` of
+    /// the layer, with the edge smoothed over about a screen pixel. The image should be loaded
+    /// as linear, not sRGB, data, such as an `R8Unorm` texture or with `is_srgb` disabled in the
+    /// `ImageLoaderSettings`, and gets sampled with linear filtering unless rendering
+    /// pixel-perfect.
+    pub sdf: bool,
+    /// Sprite sheet layout of the image, animating the layer through the frames of the sheet,
+    /// see [`ParallaxAtlas`].
+    pub atlas: Option<ParallaxAtlas>,
+}
+
+impl ParallaxLayer {
+    /// Starts building a layer of the given image, see [`ParallaxLayerBuilder`].
+    ///
+    /// ## Examples
+    /// ```
+    /// # use bevy::prelude::{Commands, Vec2};
+    /// use bevy_parallaxation2d::prelude::*;
+    ///
+    /// fn setup(mut commands: Commands) {
+    ///     let layer = ParallaxLayer::new("background.png")
+    ///         .depth(40.0)
+    ///         .offset(Vec2::Y * 10.0)
+    ///         .repeat_x()
+    ///         .offset_camera_top()
+    ///         .build();
+    ///
+    ///     commands.spawn(layer);
+    /// }
+    /// ```
+    #[inline]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(image: impl Into<ParallaxImageSource>) -> ParallaxLayerBuilder {
+        ParallaxLayerBuilder(Self {
+            image: image.into(),
+            flags: ParallaxFlags::NONE,
+            ..Default::default()
+        })
+    }
+
+    /// Checks the layer for values that would panic or render wrong once spawned:
+    /// * The image path cannot be empty.
+    /// * The depth cannot be infinite or NaN, and the depth factor cannot be NaN.
+    /// * The offset, the tile origin and the pivot cannot be infinite or NaN.
+    /// * The tile scale cannot be negative, infinite or NaN.
+    /// * The atlas cannot be empty, cannot have more frames than fit its grid, and cannot play
+    ///   at a negative, infinite or NaN frame rate.
+    /// * The flags cannot contradict themselves, see [`ParallaxFlags::validate`].
+    ///
+    /// See [`ParallaxPlugin::set_strict`](crate::plugin::ParallaxPlugin::set_strict) to
+    /// validate every layer when loaded.
+    ///
+    /// ## Examples
+    /// ```
+    /// use bevy_parallaxation2d::prelude::*;
+    ///
+    /// let layer = ParallaxLayer {
+    ///     image: "mountains.png".into(),
+    ///     flags: ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::LOCKED_X_AXIS,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     layer.validate(),
+    ///     Err(ParallaxLayerError::Flags(FlagConflictError::RepeatAndLock(Axis2::X)))
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), ParallaxLayerError> {
+        if self.image.is_empty() {
+            return Err(ParallaxLayerError::EmptyImage);
+        }
+
+        let depth = self.depth.depth();
+        if !depth.is_finite()
+            || self.depth.depth_factor_xy().is_some_and(Vec2::is_nan)
+            || !self.z_bias.is_finite()
+        {
+            return Err(ParallaxLayerError::InvalidDepth);
+        }
+
+        if !self.offset.is_finite() || self.tile_origin.is_some_and(|origin| !origin.is_finite()) {
+            return Err(ParallaxLayerError::InvalidOffset);
+        }
+
+        if !self.pivot.is_finite() {
+            return Err(ParallaxLayerError::InvalidPivot);
+        }
+
+        if let Some(tile_scale) = self.tile_scale {
+            if !tile_scale.is_finite() || tile_scale.cmplt(Vec2::ZERO).any() {
+                return Err(ParallaxLayerError::InvalidTileScale);
+            }
+        }
+
+        if self.atlas.is_some_and(|atlas| !atlas.is_valid()) {
+            return Err(ParallaxLayerError::InvalidAtlas);
+        }
+
+        self.flags.validate()?;
+        Ok(())
+    }
+}
+
+/// Problem found when validating a [`ParallaxLayer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParallaxLayerError {
+    /// The image path is empty.
+    EmptyImage,
+    /// The depth or the z bias is infinite or NaN, or the depth factor is NaN.
+    InvalidDepth,
+    /// The offset or the tile origin is infinite or NaN.
+    InvalidOffset,
+    /// The pivot is infinite or NaN.
+    InvalidPivot,
+    /// The tile scale is negative, infinite or NaN.
+    InvalidTileScale,
+    /// The atlas has no frames, more frames than fit its grid, or an invalid frame rate.
+    InvalidAtlas,
+    /// The flags contradict themselves.
+    Flags(FlagConflictError),
+}
+
+impl From<FlagConflictError> for ParallaxLayerError {
+    #[inline]
+    fn from(error: FlagConflictError) -> Self {
+        Self::Flags(error)
+    }
+}
+
+impl fmt::Display for ParallaxLayerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyImage => write!(f, "Parallax layer has no image."),
+            Self::InvalidDepth => write!(f, "Parallax layer depth is not a finite value."),
+            Self::InvalidOffset => write!(f, "Parallax layer offset is not a finite value."),
+            Self::InvalidPivot => write!(f, "Parallax layer pivot is not a finite value."),
+            Self::InvalidTileScale => {
+                write!(
+                    f,
+                    "Parallax layer tile scale is not a finite, non-negative value."
+                )
+            }
+            Self::InvalidAtlas => write!(f, "Parallax layer atlas has no valid frames."),
+            Self::Flags(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ParallaxLayerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Flags(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ParallaxLayer {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            image: ParallaxImageSource::default(),
+            name: None,
+            color: Color::default(),
+            depth: Depth::default(),
+            offset: Vec2::ZERO,
+            flags: ParallaxFlags::default(),
+            tile_origin: None,
+            tile_scale: None,
+            dissolve_image: None,
+            pivot: Vec2::splat(0.5),
+            lods: Vec::new(),
+            offset_space: OffsetSpace::World,
+            mask_image: None,
+            mask_space: MaskSpace::Screen,
+            fade_in: None,
+            detile_strength: 0.0,
+            target_camera: None,
+            blend_mode: BlendMode::Alpha,
+            order_in_tier: 0,
+            z_bias: 0.0,
+            anisotropy: 1,
+            ignore_global_scale: false,
+            sdf: false,
+            atlas: None,
+        }
+    }
+}
+
+/// Builder of a [`ParallaxLayer`], created by [`ParallaxLayer::new`].
+///
+/// The fields not set through the builder keep their default value, except for the flags,
+/// which start out empty such that the layer only has the flags set through the builder. The
+/// flag methods each insert the bits noted on them, where the offset methods first clear the
+/// bits of any previous camera offset.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct ParallaxLayerBuilder(ParallaxLayer);
+
+impl ParallaxLayerBuilder {
+    /// Sets the name of the layer, see [`ParallaxLayer::name`].
+    #[inline]
+    pub fn name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.0.name = Some(name.into());
+        self
+    }
+
+    /// Sets the color tint of the layer, see [`ParallaxLayer::color`].
+    #[inline]
+    pub fn color(mut self, color: Color) -> Self {
+        self.0.color = color;
+        self
+    }
+
+    /// Sets the depth of the layer, see [`ParallaxLayer::depth`].
+    #[inline]
+    pub fn depth(mut self, depth: impl Into<Depth>) -> Self {
+        self.0.depth = depth.into();
+        self
+    }
+
+    /// Sets the offset of the layer, see [`ParallaxLayer::offset`].
+    #[inline]
+    pub fn offset(mut self, offset: Vec2) -> Self {
+        self.0.offset = offset;
+        self
+    }
+
+    /// Replaces all flags of the layer.
+    #[inline]
+    pub fn flags(mut self, flags: ParallaxFlags) -> Self {
+        self.0.flags = flags;
+        self
+    }
+
+    /// Repeats the layer along the X axis, inserting [`ParallaxFlags::REPEAT_X_AXIS`] (`1`).
+    #[inline]
+    pub fn repeat_x(self) -> Self {
+        self.insert_flags(ParallaxFlags::REPEAT_X_AXIS)
+    }
+
+    /// Repeats the layer along the Y axis, inserting [`ParallaxFlags::REPEAT_Y_AXIS`] (`2`).
+    #[inline]
+    pub fn repeat_y(self) -> Self {
+        self.insert_flags(ParallaxFlags::REPEAT_Y_AXIS)
+    }
+
+    /// Locks the layer along the X axis, inserting [`ParallaxFlags::LOCKED_X_AXIS`] (`4`).
+    #[inline]
+    pub fn lock_x(self) -> Self {
+        self.insert_flags(ParallaxFlags::LOCKED_X_AXIS)
+    }
+
+    /// Locks the layer along the Y axis, inserting [`ParallaxFlags::LOCKED_Y_AXIS`] (`8`).
+    #[inline]
+    pub fn lock_y(self) -> Self {
+        self.insert_flags(ParallaxFlags::LOCKED_Y_AXIS)
+    }
+
+    /// Offsets the layer to the left of the camera, setting
+    /// [`ParallaxFlags::OFFSET_CAMERA_LEFT`], that is `OFFSET_TO_CAMERA | HORIZONTAL_OFFSET`
+    /// (`16 | 32`).
+    #[inline]
+    pub fn offset_camera_left(self) -> Self {
+        self.set_camera_offset(ParallaxFlags::OFFSET_CAMERA_LEFT)
+    }
+
+    /// Offsets the layer to the right of the camera, setting
+    /// [`ParallaxFlags::OFFSET_CAMERA_RIGHT`], that is
+    /// `OFFSET_TO_CAMERA | HORIZONTAL_OFFSET | POSITIVE_OFFSET` (`16 | 32 | 64`).
+    #[inline]
+    pub fn offset_camera_right(self) -> Self {
+        self.set_camera_offset(ParallaxFlags::OFFSET_CAMERA_RIGHT)
+    }
+
+    /// Offsets the layer to the bottom of the camera, setting
+    /// [`ParallaxFlags::OFFSET_CAMERA_BOTTOM`], that is `OFFSET_TO_CAMERA` (`16`).
+    #[inline]
+    pub fn offset_camera_bottom(self) -> Self {
+        self.set_camera_offset(ParallaxFlags::OFFSET_CAMERA_BOTTOM)
+    }
+
+    /// Offsets the layer to the top of the camera, setting
+    /// [`ParallaxFlags::OFFSET_CAMERA_TOP`], that is `OFFSET_TO_CAMERA | POSITIVE_OFFSET`
+    /// (`16 | 64`).
+    #[inline]
+    pub fn offset_camera_top(self) -> Self {
+        self.set_camera_offset(ParallaxFlags::OFFSET_CAMERA_TOP)
+    }
+
+    /// Sets the camera the layer is positioned for, see [`ParallaxLayer::target_camera`].
+    #[inline]
+    pub fn target_camera(mut self, camera: Entity) -> Self {
+        self.0.target_camera = Some(camera);
+        self
+    }
+
+    /// Sets how the layer is blended, see [`ParallaxLayer::blend_mode`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.0.blend_mode = blend_mode;
+        self
+    }
+
+    /// Sets the order of the layer among the layers at the same depth, see
+    /// [`ParallaxLayer::order_in_tier`].
+    #[inline]
+    pub fn order_in_tier(mut self, order_in_tier: i32) -> Self {
+        self.0.order_in_tier = order_in_tier;
+        self
+    }
+
+    /// Sets the distance by which the z-position of the layer is moved forward, see
+    /// [`ParallaxLayer::z_bias`].
+    #[inline]
+    pub fn z_bias(mut self, z_bias: f32) -> Self {
+        self.0.z_bias = z_bias;
+        self
+    }
+
+    /// Makes the depth factor of the layer ignore the scale of the parallax effect, see
+    /// [`ParallaxLayer::ignore_global_scale`].
+    #[inline]
+    pub fn ignore_global_scale(mut self) -> Self {
+        self.0.ignore_global_scale = true;
+        self
+    }
+
+    /// Marks the image of the layer as a signed distance field, see [`ParallaxLayer::sdf`].
+    #[inline]
+    pub fn sdf(mut self) -> Self {
+        self.0.sdf = true;
+        self
+    }
+
+    /// Sets the sprite sheet layout of the image, see [`ParallaxLayer::atlas`].
+    #[inline]
+    pub fn atlas(mut self, atlas: ParallaxAtlas) -> Self {
+        self.0.atlas = Some(atlas);
+        self
+    }
+
+    /// Returns the built layer.
+    #[inline]
+    #[must_use]
+    pub fn build(self) -> ParallaxLayer {
+        self.0
+    }
+
+    #[inline]
+    fn insert_flags(mut self, flags: ParallaxFlags) -> Self {
+        self.0.flags.insert(flags);
+        self
+    }
+
+    #[inline]
+    fn set_camera_offset(mut self, flags: ParallaxFlags) -> Self {
+        self.0
+            .flags
+            .remove(ParallaxFlags::OFFSET_CAMERA_RIGHT | ParallaxFlags::OFFSET_CAMERA_TOP);
+        self.insert_flags(flags)
+    }
+}
+
+/// How a [`ParallaxLayer`] is blended with the layers behind it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum BlendMode {
+    /// The layer is drawn over the layers behind it, weighted by its alpha.
+    #[default]
+    Alpha,
+    /// The color of the layer, weighted by its alpha, is added to the layers behind it. Useful
+    /// for glow and light shafts, as the layer only ever brightens the scene.
+    Additive,
+    /// The layer replaces the layers behind it, ignoring its alpha. Pixels not yet revealed by
+    /// a [`ParallaxDissolve`] are still left out.
+    Opaque,
+}
+
+/// Space over which the mask of a [`ParallaxLayer`] is stretched.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum MaskSpace {
+    /// The mask covers the viewport of the camera, from the top left to the bottom right. It
+    /// stays in place on screen while the layer moves or scrolls.
+    #[default]
+    Screen,
+    /// The mask covers the layer, and moves along with it. On repeating and UV scrolling axes
+    /// the mask does not scroll with the texture.
+    Layer,
+}
+
+/// Space in which the offset of a [`ParallaxLayer`] is interpreted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum OffsetSpace {
+    /// The offset is a world position, at which the layer is centered in the camera view when
+    /// the camera is at this position.
+    #[default]
+    World,
+    /// The offset is a distance in screen pixels from the center of the camera, which stays
+    /// constant on screen as the camera moves and zooms. A screen pixel spans the
+    /// [`scale`](bevy::prelude::OrthographicProjection::scale) of the camera projection in world
+    /// units, as with the default scaling mode.
+    ///
+    /// Only applies to the axes that are neither locked nor follow the camera to scroll the
+    /// texture. The depth still defines the z-position and the render order of the layer.
+    CameraLocal,
+}
+
+/// Lower resolution variant of the image of a [`ParallaxLayer`], a level of detail.
+///
+/// The variant is used while the resolved depth factor of the layer is at most
+/// `max_depth_factor`, meaning the layer is far enough away. Of all the variants whose
+/// threshold is met, the one with the lowest threshold gets used. The variant is stretched to
+/// the size of the full resolution image, and is swapped whenever the depth factor crosses a
+/// threshold, such as when the depth or the [`ParallaxContext`](crate::resources::ParallaxContext)
+/// changes.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(ParallaxLayer {
+///         image: "mountains.png".into(),
+///         depth: 80.0.into(),
+///         lods: vec![
+///             ParallaxLod {
+///                 image: "mountains_half.png".into(),
+///                 max_depth_factor: 0.5,
+///             },
+///             ParallaxLod {
+///                 image: "mountains_quarter.png".into(),
+///                 max_depth_factor: 0.2,
+///             },
+///         ],
+///         ..default()
+///     });
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct ParallaxLod {
+    pub image: Cow<'static, str>,
+    /// Largest depth factor at which the variant is used.
+    pub max_depth_factor: f32,
+}
+
+/// Sprite sheet layout of the image of a parallax layer, such as for an animated waterfall.
+///
+/// The image is divided into a grid of `columns` by `rows` frames of equal size, numbered row by
+/// row from the top left. The layer is sized for a single frame, which repeats along the
+/// repeating axes of the layer like a whole image would. The first `frame_count` frames play in
+/// order at `fps` frames per second of the [`ParallaxClock`](crate::resources::ParallaxClock),
+/// looping, such that the animation pauses with the clock. A frame rate of `0.0` shows the first
+/// frame only.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::Commands;
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // A sheet of 4 by 2 frames, of which the last one is empty
+///     commands.spawn(
+///         ParallaxLayer::new("waterfall.png")
+///             .depth(20.0)
+///             .repeat_y()
+///             .atlas(ParallaxAtlas {
+///                 frame_count: 7,
+///                 ..ParallaxAtlas::new(4, 2, 12.0)
+///             })
+///             .build(),
+///     );
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct ParallaxAtlas {
+    /// Number of frames along the width of the image.
+    pub columns: u32,
+    /// Number of frames along the height of the image.
+    pub rows: u32,
+    /// Number of frames played, at most `columns * rows`.
+    pub frame_count: u32,
+    /// Frames played per second.
+    pub fps: f32,
+}
+
+impl ParallaxAtlas {
+    /// Creates an atlas of `columns` by `rows` frames, playing every frame at `fps` frames per
+    /// second.
+    #[inline]
+    #[must_use]
+    pub fn new(columns: u32, rows: u32, fps: f32) -> Self {
+        Self {
+            columns,
+            rows,
+            frame_count: columns * rows,
+            fps,
+        }
+    }
+
+    /// Returns the frame shown after `elapsed` seconds.
+    #[inline]
+    #[must_use]
+    pub fn frame_at(&self, elapsed: f32) -> u32 {
+        if self.frame_count == 0 {
+            return 0;
+        }
+
+        (elapsed * self.fps).max(0.0) as u32 % self.frame_count
+    }
+
+    /// Returns the number of frames along each axis of the image.
+    #[inline]
+    #[must_use]
+    pub(crate) fn grid(&self) -> UVec2 {
+        UVec2::new(self.columns, self.rows)
+    }
+
+    #[inline]
+    #[must_use]
+    fn is_valid(&self) -> bool {
+        self.columns > 0
+            && self.rows > 0
+            && (1..=self.columns * self.rows).contains(&self.frame_count)
+            && self.fps.is_finite()
+            && self.fps >= 0.0
+    }
+}
+
+/// Images of the levels of detail of a parallax layer, see [`ParallaxLod`].
+#[derive(Component, Debug)]
+pub(crate) struct ParallaxLodImages {
+    /// The full resolution image, which also defines the size of the layer.
+    pub base: Handle<Image>,
+    pub levels: Vec<(f32, Handle<Image>)>,
+    /// Index of the level in use, `None` for the full resolution image.
+    pub current: Option<usize>,
+}
+
+impl ParallaxLodImages {
+    /// Returns the index of the level to use at the given depth factor.
+    #[inline]
+    #[must_use]
+    pub fn level(&self, depth_factor: f32) -> Option<usize> {
+        self.levels
+            .iter()
+            .enumerate()
+            .filter(|(_, (max_depth_factor, _))| depth_factor <= *max_depth_factor)
+            .min_by(|(_, (lhs, _)), (_, (rhs, _))| lhs.total_cmp(rhs))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the image of the level.
+    #[inline]
+    #[must_use]
+    pub fn image(&self, level: Option<usize>) -> Handle<Image> {
+        match level {
+            Some(index) => self.levels[index].1.clone(),
+            None => self.base.clone(),
+        }
+    }
+}
+
+/// Scrolls the texture of a parallax layer at a fixed rate of texture pixels per world unit of
+/// camera movement, instead of the rate given by the depth factor of the layer.
+///
+/// The rate is independent of the viewport size and the parallax configuration. Only the
+/// repeating axes of the layer are affected, as the texture of a non-repeating axis does not
+/// scroll. The component is read when the layer is processed.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands, Vec2};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         ParallaxLayer {
+///             image: "clouds.png".into(),
+///             ..default()
+///         },
+///         ParallaxPixelScroll {
+///             pixels_per_unit: Vec2::new(0.25, 0.0),
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ParallaxPixelScroll {
+    /// Texture pixels scrolled per world unit of camera movement.
+    pub pixels_per_unit: Vec2,
+}
+
+/// Reveals or hides a parallax layer over time with a dissolve wipe.
+///
+/// A fragment of the layer is shown when the red channel of the `dissolve_image` of the
+/// [`ParallaxLayer`] lies below the dissolve threshold. The threshold moves from `0.0` to `1.0`
+/// while revealing, and from `1.0` to `0.0` while hiding, driven by the
+/// [`ParallaxClock`](crate::resources::ParallaxClock). The component can be replaced to start
+/// another transition.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         ParallaxLayer {
+///             image: "background.png".into(),
+///             dissolve_image: Some("noise.png".into()),
+///             ..default()
+///         },
+///         ParallaxDissolve::reveal(1.5),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ParallaxDissolve {
+    elapsed: f32,
+    duration: f32,
+    reveal: bool,
+}
+
+impl ParallaxDissolve {
+    /// Creates a dissolve revealing the layer over `duration` seconds.
+    #[inline]
+    #[must_use]
+    pub fn reveal(duration: f32) -> Self {
+        Self {
+            elapsed: 0.0,
+            duration,
+            reveal: true,
+        }
+    }
+
+    /// Creates a dissolve hiding the layer over `duration` seconds.
+    #[inline]
+    #[must_use]
+    pub fn hide(duration: f32) -> Self {
+        Self {
+            elapsed: 0.0,
+            duration,
+            reveal: false,
+        }
+    }
+
+    /// Returns the current dissolve threshold, from `0.0` hidden to `1.0` fully revealed.
+    #[inline]
+    #[must_use]
+    pub fn threshold(&self) -> f32 {
+        let progress = match self.duration > 0.0 {
+            true => (self.elapsed / self.duration).min(1.0),
+            false => 1.0,
+        };
+
+        match self.reveal {
+            true => progress,
+            false => 1.0 - progress,
+        }
+    }
+
+    /// Returns whether the transition has finished.
+    #[inline]
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Advances the transition by `delta` seconds.
+    #[inline]
+    pub(crate) fn advance(&mut self, delta: f32) {
+        self.elapsed += delta;
+    }
+}
+
+/// Fades in a freshly processed parallax layer, from transparent to fully opaque.
+///
+/// Added when a [`ParallaxLayer`] with a `fade_in` duration is first processed, and removed
+/// once the layer is fully opaque. The opacity is driven by the
+/// [`ParallaxClock`](crate::resources::ParallaxClock), and multiplies the opacity set by a
+/// [`ParallaxFocusDepth`](crate::resources::ParallaxFocusDepth) while fading.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ParallaxFadeIn {
+    remaining: f32,
+    duration: f32,
+}
+
+impl ParallaxFadeIn {
+    #[inline]
+    #[must_use]
+    pub(crate) fn new(duration: f32) -> Self {
+        Self {
+            remaining: duration,
+            duration,
+        }
+    }
+
+    /// Returns the remaining time of the fade in seconds.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> f32 {
+        self.remaining
+    }
+
+    /// Returns the current opacity, from `0.0` transparent to `1.0` fully opaque.
+    #[inline]
+    #[must_use]
+    pub fn opacity(&self) -> f32 {
+        match self.duration > 0.0 {
+            true => (1.0 - self.remaining / self.duration).clamp(0.0, 1.0),
+            false => 1.0,
+        }
+    }
+
+    /// Advances the fade by `delta` seconds.
+    #[inline]
+    pub(crate) fn advance(&mut self, delta: f32) {
+        self.remaining = (self.remaining - delta).max(0.0);
+    }
+}
+
+/// Transparency of a parallax layer, from `0.0` for invisible to `1.0` for opaque, such as to
+/// fade a vignette based on the state of the game.
+///
+/// The alpha multiplies the alpha of the layer, on top of the fading by a [`ParallaxFadeIn`] or
+/// the [`ParallaxFocusDepth`](crate::resources::ParallaxFocusDepth). Removing the component
+/// makes the layer opaque again. A layer sharing its material gets a material of its own, see
+/// [`ParallaxPlugin::set_share_materials`](crate::plugin::ParallaxPlugin::set_share_materials).
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Query, Res, Time, With};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// # #[derive(bevy::prelude::Component)]
+/// # struct Vignette;
+/// fn fade_out_vignette(mut alpha_query: Query<&mut ParallaxAlpha, With<Vignette>>, time: Res<Time>) {
+///     for mut alpha in alpha_query.iter_mut() {
+///         alpha.0 = (alpha.0 - time.delta_seconds()).max(0.0);
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct ParallaxAlpha(pub f32);
+
+impl Default for ParallaxAlpha {
+    #[inline]
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Reveals a parallax layer only within a circle around the mouse cursor, such as a spotlight or
+/// a looking glass.
+///
+/// The circle follows the cursor over the primary window, and its radius is given in logical
+/// pixels. While the cursor is outside of the window the circle holds its last position, and
+/// the layer stays hidden until the cursor first enters the window. The circle combines with
+/// the mask image of the layer. Removing the component reveals the whole layer again.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         ParallaxLayer {
+///             image: "hidden_passages.png".into(),
+///             depth: 5.0.into(),
+///             ..default()
+///         },
+///         ParallaxCursorMask { radius: 64.0 },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct ParallaxCursorMask {
+    /// Radius of the revealed circle in logical pixels.
+    pub radius: f32,
+}
+
+/// Keeps the time uniform of the material of a parallax layer at the elapsed time of the
+/// [`ParallaxClock`](crate::resources::ParallaxClock), for shaders animating with
+/// `parallax_material.time`.
+///
+/// Only the materials of layers with this component get the time written, since every write
+/// prepares the material again for rendering. The time stops along with the clock, such as
+/// while it is paused.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         ParallaxLayer {
+///             image: "aurora.png".into(),
+///             ..default()
+///         },
+///         ParallaxMaterialTime,
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ParallaxMaterialTime;
+
+/// Perturbs the sampled texture of a parallax layer with an oscillation, such as the shimmer of
+/// water or heat, without moving the layer.
+///
+/// The texture gets displaced by `amplitude * sin(2π * frequency * t)` per axis, where `t` is
+/// the elapsed time of the [`ParallaxClock`](crate::resources::ParallaxClock). The displacement
+/// composes with the tiling and scrolling of the texture.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands, Vec2};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         ParallaxLayer {
+///             image: "water.png".into(),
+///             flags: ParallaxFlags::REPEAT_X_AXIS,
+///             ..default()
+///         },
+///         ParallaxShimmer {
+///             amplitude: Vec2::new(1.5, 0.5),
+///             frequency: Vec2::new(0.5, 0.8),
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ParallaxShimmer {
+    /// Largest displacement of the texture, in texture pixels.
+    pub amplitude: Vec2,
+    /// Oscillations per second.
+    pub frequency: Vec2,
+}
+
+impl ParallaxShimmer {
+    /// Returns the displacement of the texture, in texture pixels, at time `t` in seconds.
+    #[inline]
+    #[must_use]
+    pub fn displacement(&self, t: f32) -> Vec2 {
+        let phase = std::f32::consts::TAU * self.frequency * t;
+        self.amplitude * Vec2::new(phase.x.sin(), phase.y.sin())
+    }
+}
+
+/// Rotates the sampled texture of a parallax layer around the center of the layer, such as for
+/// a slowly spinning galaxy backdrop, without rotating the layer itself.
+///
+/// The texture is rotated counterclockwise by `radians_per_sec * t`, where `t` is the elapsed
+/// time of the [`ParallaxClock`](crate::resources::ParallaxClock). The rotation applies before
+/// the tiling and scrolling of the texture.
+///
+/// On a repeating axis, the whole tiled content rotates, including the grid of the tiles, around
+/// the center of the layer, which covers the camera view. On an axis which does not repeat, the
+/// corners of the rotated image fall outside of the layer and get cut off, while the edges of
+/// the image get stretched to fill the uncovered corners. Either repeat the layer on both axes,
+/// or give the image a transparent border at least as wide as the corners stick out.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         ParallaxLayer {
+///             image: "galaxy.png".into(),
+///             depth: 90.0.into(),
+///             flags: ParallaxFlags::NONE,
+///             ..default()
+///         },
+///         // A full turn every minute
+///         ParallaxUvRotation {
+///             radians_per_sec: std::f32::consts::TAU / 60.0,
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct ParallaxUvRotation {
+    /// Counterclockwise rotation of the texture per second.
+    pub radians_per_sec: f32,
+}
+
+impl ParallaxUvRotation {
+    /// Returns the rotation of the texture, in radians, at time `t` in seconds.
+    #[inline]
+    #[must_use]
+    pub fn angle(&self, t: f32) -> f32 {
+        (self.radians_per_sec * t).rem_euclid(std::f32::consts::TAU)
+    }
+}
+
+/// Multiplies the depth factor of a single parallax layer while the camera moves, such as to
+/// momentarily exaggerate the parallax of a layer for a gameplay cue.
+///
+/// The boost scales the factor by which the layer follows the camera, so the layer returns to
+/// its usual position once the multiplier is back at `1.0`. Away from the origin a change of the
+/// multiplier moves the layer, which is why it is best eased in and out. Locked and camera-local
+/// axes are not affected. The boost composes with the speed boost of the
+/// [`ParallaxPlugin`](crate::plugin::ParallaxPlugin).
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Query, Res, Time};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn relax_boost(mut boost_query: Query<&mut ParallaxFactorBoost>, time: Res<Time>) {
+///     for mut boost in boost_query.iter_mut() {
+///         boost.multiplier += (1.0 - boost.multiplier) * (2.0 * time.delta_seconds()).min(1.0);
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct ParallaxFactorBoost {
+    /// Multiplier of the depth factor, where `1.0` leaves the movement of the layer unchanged.
+    pub multiplier: f32,
+}
+
+impl Default for ParallaxFactorBoost {
+    #[inline]
+    fn default() -> Self {
+        Self { multiplier: 1.0 }
+    }
+}
+
+/// World space rectangle covered by a parallax layer, such as for a physics integration using
+/// the layer as a gameplay surface.
+///
+/// Insert this component on a parallax layer to opt in, after which the rectangle is kept in
+/// sync with the layer as it moves with the camera. A repeating axis covers the camera view
+/// wherever it goes, meaning the rectangle is unbounded along it. The rectangle is empty until
+/// the layer is processed.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands, Query};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         ParallaxLayer {
+///             image: "platform.png".into(),
+///             flags: ParallaxFlags::NONE,
+///             ..default()
+///         },
+///         ParallaxLayerBounds::default(),
+///     ));
+/// }
+///
+/// fn sync_colliders(bounds_query: Query<&ParallaxLayerBounds>) {
+///     for bounds in bounds_query.iter() {
+///         let rect = bounds.rect();
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ParallaxLayerBounds(pub(crate) Rect);
+
+impl ParallaxLayerBounds {
+    /// Returns the world space rectangle covered by the layer.
+    #[inline]
+    #[must_use]
+    pub fn rect(&self) -> Rect {
+        self.0
+    }
 }
 
-/// Internal data structure for parallax layer configuration.
-#[derive(Component)]
-pub(crate) struct ParallaxLayerData {
+/// Region of the world in which a parallax layer exists, such as for streaming a large level.
+///
+/// The described layer is spawned when its camera enters the `bounds`, and despawned again when
+/// the camera leaves them, which frees the texture of the layer when no other layer uses it. The
+/// camera is the [`target_camera`](ParallaxLayer::target_camera) of the layer, or else the
+/// primary camera. The region itself is not a parallax layer and is kept when the layer is
+/// despawned. Despawning the region, or removing this component from it, despawns its layer.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands, Rect};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // The cave backdrop only exists while the camera is in the cave
+///     commands.spawn(ParallaxStreamRegion::new(
+///         Rect::new(2000.0, -500.0, 3000.0, 0.0),
+///         ParallaxLayer {
+///             image: "cave.png".into(),
+///             depth: 4.0.into(),
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug)]
+pub struct ParallaxStreamRegion {
+    /// World space rectangle in which the layer exists.
+    pub bounds: Rect,
+    /// Layer spawned while the camera is within the `bounds`.
+    pub layer: ParallaxLayer,
+    pub(crate) spawned: Option<Entity>,
+}
+
+impl ParallaxStreamRegion {
+    /// Creates a region spawning the `layer` while the camera is within the `bounds`.
+    #[inline]
+    #[must_use]
+    pub fn new(bounds: Rect, layer: ParallaxLayer) -> Self {
+        Self {
+            bounds,
+            layer,
+            spawned: None,
+        }
+    }
+
+    /// Returns the currently spawned layer of the region, if any.
+    #[inline]
+    #[must_use]
+    pub fn spawned_layer(&self) -> Option<Entity> {
+        self.spawned
+    }
+}
+
+/// Marks a parallax layer spawned by the [`ParallaxStreamRegion`] on the given entity.
+#[derive(Component, Debug)]
+pub(crate) struct ParallaxStreamedLayer(pub Entity);
+
+/// Binds a parallax layer to the parallax camera it belongs to.
+///
+/// The layer is sized for the view of the camera and moves with it, instead of with the primary
+/// camera. A layer bound to a camera which does not exist is neither processed nor moved. Use
+/// render layers to only render the layer with its camera, such as for split-screen.
+///
+/// Commands scoped to a camera, such as
+/// [`despawn_front_layer_for`](crate::commands::ParallaxDespawnCommands::despawn_front_layer_for),
+/// only consider the layers bound to that camera along with the layers not bound to any camera.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands, Entity};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands, camera: Entity) {
+///     commands.spawn((
+///         ParallaxLayer {
+///             image: "background.png".into(),
+///             ..default()
+///         },
+///         ParallaxTargetCamera(camera),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParallaxTargetCamera(pub Entity);
+
+/// Component for creating a parallax layer of a single color, filling the view of the camera.
+///
+/// Useful as a plain backdrop at a given depth, without making a texture for it. The layer is
+/// placed and resolved like a [`ParallaxLayer`] repeating on both axes, and can be modified
+/// through its [`ParallaxLayerData`] once loaded.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Color, Commands};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(ParallaxSolidLayer {
+///         color: Color::MIDNIGHT_BLUE,
+///         depth: 90.0.into(),
+///     });
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ParallaxSolidLayer {
+    /// Color of the layer.
+    pub color: Color,
+    /// Depth of the layer, see [`ParallaxLayer::depth`].
     pub depth: Depth,
-    pub offset: Vec2,
-    pub flags: ParallaxFlags,
+}
+
+/// Reference to a depth shared between parallax layers, stored in the
+/// [`ParallaxDepthRegistry`](crate::resources::ParallaxDepthRegistry).
+///
+/// Every layer with the same `DepthHandle` gets its depth set from the registry, such that
+/// changing the shared depth updates all referencing layers together.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands, ResMut};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands, mut depths: ResMut<ParallaxDepthRegistry>) {
+///     let tier = depths.add(40.0);
+///
+///     commands.spawn((
+///         ParallaxLayer {
+///             image: "hills.png".into(),
+///             ..default()
+///         },
+///         tier,
+///     ));
+///     commands.spawn((
+///         ParallaxLayer {
+///             image: "houses.png".into(),
+///             ..default()
+///         },
+///         tier,
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DepthHandle(pub(crate) usize);
+
+/// Marks a parallax layer sharing its material with identical layers, see
+/// [`ParallaxPlugin::set_share_materials`](crate::plugin::ParallaxPlugin::set_share_materials).
+#[derive(Component, Debug)]
+pub(crate) struct ParallaxSharedMaterial;
+
+/// Keeps the depth of a parallax layer at an offset from the depth of another layer, such as
+/// to place a decal just in front of a wall.
+///
+/// The depth of the layer is set to the depth the reference layer was created with plus
+/// `delta`, following the reference when its depth changes. The delta is in the space of the
+/// depth of the reference, see [`Depth`]: with the default depth ordering, a negative delta
+/// places the layer in front of a reference with a parallax depth, while a positive delta does
+/// for a reference with a world depth.
+///
+/// If the reference is not a parallax layer, such as when it got despawned, a warning is
+/// logged and the component is removed, leaving the layer at its current depth.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{default, Commands};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     let wall = commands
+///         .spawn(ParallaxLayer {
+///             image: "wall.png".into(),
+///             depth: 20.0.into(),
+///             ..default()
+///         })
+///         .id();
+///     commands.spawn((
+///         ParallaxLayer {
+///             image: "poster.png".into(),
+///             ..default()
+///         },
+///         ParallaxRelativeDepth {
+///             reference: wall,
+///             delta: -2.0,
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct ParallaxRelativeDepth {
+    /// The layer whose depth is followed.
+    pub reference: Entity,
+    /// Offset from the depth of the reference layer.
+    pub delta: f32,
+}
+
+/// Marker component for parallax layers waiting for their image to load before being processed.
+///
+/// The marker is removed once the layer is processed, or when its image fails to load, see
+/// [`ParallaxLayerLoadFailed`](crate::events::ParallaxLayerLoadFailed).
+#[derive(Component, Debug)]
+pub struct ParallaxLayerPending;
+
+/// Marker component for parallax layers whose [`ParallaxLayerData`] has been processed.
+///
+/// Only processed layers are moved by the parallax system.
+#[derive(Component, Debug)]
+pub struct ParallaxLayerProcessed;
+
+/// Marker component for processed parallax layers to be laid out again, as the view of their
+/// camera or the neutral depth changed, reusing their processed image and mesh.
+#[derive(Component, Debug)]
+pub(crate) struct ParallaxLayerResized;
+
+/// Marker component for the [`Name`](bevy::prelude::Name) given to an unnamed parallax layer in
+/// debug builds, which is not part of the layer itself.
+#[derive(Component, Debug)]
+pub(crate) struct ParallaxDefaultName;
+
+/// Data of a loaded parallax layer.
+///
+/// Replaces the [`ParallaxLayer`] component once the layer has been initialised. The layer
+/// can be modified at runtime through the setters, which causes it to be processed again.
+///
+/// ## Layer states
+/// 1. A spawned [`ParallaxLayer`] gets replaced by `ParallaxLayerData` with the depth and
+///    offset as defined by the user. The layer is not yet moved by the parallax system.
+/// 2. Once processed, the depth and offset are resolved and the layer is marked with
+///    [`ParallaxLayerProcessed`]. From this point the layer follows the parallax camera.
+/// 3. Changing the layer, or the [`ParallaxContext`](crate::resources::ParallaxContext),
+///    causes the layer to be processed again. It keeps following the camera meanwhile. Zooming
+///    the camera, or animating the neutral depth, only lays out the layer again.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Query, Vec2};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn push_back_layers(mut layer_query: Query<&mut ParallaxLayerData>) {
+///     for mut layer in layer_query.iter_mut() {
+///         layer
+///             .set_depth(60.0)
+///             .set_offset(Vec2::Y * 20.0)
+///             .set_flags(ParallaxFlags::REPEAT_X_AXIS);
+///     }
+/// }
+/// ```
+#[derive(Component, Debug)]
+pub struct ParallaxLayerData {
+    /// The resolved depth of the layer.
+    pub(crate) depth: Depth,
+    /// The resolved offset of the layer.
+    pub(crate) offset: Vec2,
+    pub(crate) flags: ParallaxFlags,
+    /// The image as defined by the user, an empty path for a layer without an image source.
+    pub(crate) image: ParallaxImageSource,
+    /// The color as defined by the user.
+    pub(crate) color: Color,
+    /// The depth as defined by the user.
+    pub(crate) authored_depth: Depth,
+    /// The offset as defined by the user.
+    pub(crate) authored_offset: Vec2,
+    /// Depth offset taken from the initial transform of the layer.
+    pub(crate) z_offset: f32,
+    pub(crate) tile_origin: Option<Vec2>,
+    pub(crate) tile_scale: Option<Vec2>,
+    pub(crate) pivot: Vec2,
+    pub(crate) offset_space: OffsetSpace,
+    /// Duration of the fade in once first processed.
+    pub(crate) fade_in: Option<f32>,
+    pub(crate) detile_strength: f32,
+    pub(crate) order_in_tier: i32,
+    pub(crate) z_bias: f32,
+    pub(crate) anisotropy: u16,
+    pub(crate) ignore_global_scale: bool,
+    pub(crate) sdf: bool,
+    pub(crate) atlas: Option<ParallaxAtlas>,
+    /// Texture pixel rate of the repeating axes, taken from a [`ParallaxPixelScroll`].
+    pub(crate) pixel_scroll: Option<Vec2>,
+    /// Multiplier of the depth factor from the speed boost and a [`ParallaxFactorBoost`].
+    pub(crate) factor_boost: f32,
+    /// Size of the layer in world space, resolved during processing.
+    pub(crate) size: Vec2,
+    /// Greatest distance of the layer from its camera on the clamped axes, resolved during
+    /// processing, see [`ParallaxFlags::CLAMP_X_AXIS`].
+    pub(crate) clamp_extent: Vec2,
+    /// Rotation applied on top of the rotation of the layer to turn it with its camera, see
+    /// [`ParallaxFlags::LOCK_ROTATION`].
+    pub(crate) camera_turn: f32,
+    /// Whether the camera of the layer was rotated during processing, growing the repeating
+    /// axes to the diagonal of the camera view.
+    pub(crate) view_rotated: bool,
+    /// Whether the layer got a mesh of its size during processing, see
+    /// [`ParallaxFlags::UNIT_SCALE`].
+    pub(crate) unit_mesh: bool,
+}
+
+impl ParallaxLayerData {
+    #[inline]
+    #[must_use]
+    pub(crate) fn new(depth: Depth, offset: Vec2, flags: ParallaxFlags) -> Self {
+        Self {
+            depth,
+            offset,
+            flags,
+            image: ParallaxImageSource::default(),
+            color: Color::default(),
+            authored_depth: depth,
+            authored_offset: offset,
+            z_offset: 0.0,
+            tile_origin: None,
+            tile_scale: None,
+            pivot: Vec2::splat(0.5),
+            offset_space: OffsetSpace::World,
+            fade_in: None,
+            detile_strength: 0.0,
+            order_in_tier: 0,
+            z_bias: 0.0,
+            anisotropy: 1,
+            ignore_global_scale: false,
+            sdf: false,
+            atlas: None,
+            pixel_scroll: None,
+            factor_boost: 1.0,
+            size: Vec2::ZERO,
+            clamp_extent: Vec2::INFINITY,
+            camera_turn: 0.0,
+            view_rotated: false,
+            unit_mesh: false,
+        }
+    }
+
+    /// Returns the size of the layer as rendered with the given transform, which is either the
+    /// scale of the unit mesh or the scaled size of the mesh of the layer, see
+    /// [`ParallaxFlags::UNIT_SCALE`].
+    #[inline]
+    #[must_use]
+    pub(crate) fn rendered_size(&self, transform: &Transform) -> Vec2 {
+        match self.flags.contains(ParallaxFlags::UNIT_SCALE) {
+            true => self.size * transform.scale.truncate(),
+            false => transform.scale.truncate(),
+        }
+    }
+
+    /// Returns the displacement of the layer content by its factor boost at the camera position,
+    /// split into the part moving the layer, and the part scrolling the texture along the axes
+    /// where the layer follows the camera.
+    ///
+    /// A layer moves `1 - factor` per unit of camera movement, so the boosted factor displaces
+    /// the content by `-camera * factor * (boost - 1)`. Locked and camera-local axes are not
+    /// displaced.
+    #[inline]
+    #[must_use]
+    pub(crate) fn split_boost_shift(&self, camera_translation: Vec2) -> (Vec2, Vec2) {
+        if self.factor_boost == 1.0 {
+            return (Vec2::ZERO, Vec2::ZERO);
+        }
+
+        let depth_factor = self.depth.depth_factor_xy().unwrap_or(Vec2::ZERO);
+        let locked = BVec2::new(
+            self.flags.contains(ParallaxFlags::LOCKED_X_AXIS),
+            self.flags.contains(ParallaxFlags::LOCKED_Y_AXIS),
+        );
+        let shift = Vec2::select(
+            locked | self.camera_local(),
+            Vec2::ZERO,
+            -camera_translation * depth_factor * (self.factor_boost - 1.0),
+        );
+
+        let scrolls_texture = self.scrolls_texture();
+        (
+            Vec2::select(scrolls_texture, Vec2::ZERO, shift),
+            Vec2::select(scrolls_texture, shift, Vec2::ZERO),
+        )
+    }
+
+    /// Returns the axes where the layer follows the camera and the texture scrolls instead.
+    #[inline]
+    #[must_use]
+    pub(crate) fn scrolls_texture(&self) -> BVec2 {
+        scrolling_axes(self.flags)
+    }
+
+    /// Returns the factors of the layer and of its texture per axis, see [`axis_factors`]. A
+    /// [`ParallaxPixelScroll`] replaces the texture factor of the repeating axes by its rate.
+    #[inline]
+    #[must_use]
+    pub(crate) fn split_factor(&self) -> (Vec2, Vec2) {
+        let depth_factor = self.depth.depth_factor_xy().unwrap_or(Vec2::ZERO);
+        let (layer_factor, texture_factor) = axis_factors(depth_factor, self.flags);
+
+        let Some(pixel_scroll) = self.pixel_scroll else {
+            return (layer_factor, texture_factor);
+        };
+        let repeats = BVec2::new(
+            self.flags.contains(ParallaxFlags::REPEAT_X_AXIS),
+            self.flags.contains(ParallaxFlags::REPEAT_Y_AXIS),
+        ) & self.scrolls_texture();
+        (
+            layer_factor,
+            Vec2::select(repeats, pixel_scroll, texture_factor),
+        )
+    }
+
+    /// Returns the factor per axis by which the content of the layer follows the depth, after
+    /// applying the flags, the [`OffsetSpace`] and a [`ParallaxPixelScroll`].
+    ///
+    /// At `1.0` the content stays in place in the world, while at `0.0` it stays in place on
+    /// screen. On the repeating and UV scrolling axes this is the factor of the texture, on the
+    /// other axes the factor of the layer itself. A locked axis is `1.0`, and a camera-local
+    /// axis is `0.0`. The factor is only resolved once the layer is processed, and does not
+    /// include dynamic effects such as the speed boost of the
+    /// [`ParallaxPlugin`](crate::plugin::ParallaxPlugin).
+    #[inline]
+    #[must_use]
+    pub fn effective_factor(&self) -> Vec2 {
+        let (layer_factor, texture_factor) = self.split_factor();
+        let factor = Vec2::select(self.scrolls_texture(), texture_factor, layer_factor);
+        Vec2::select(self.camera_local(), Vec2::ZERO, factor)
+    }
+
+    /// Returns the axes where the layer is placed relative to the camera, see
+    /// [`OffsetSpace::CameraLocal`].
+    #[inline]
+    #[must_use]
+    pub(crate) fn camera_local(&self) -> BVec2 {
+        if self.offset_space != OffsetSpace::CameraLocal {
+            return BVec2::FALSE;
+        }
+
+        let locked = BVec2::new(
+            self.flags.contains(ParallaxFlags::LOCKED_X_AXIS),
+            self.flags.contains(ParallaxFlags::LOCKED_Y_AXIS),
+        );
+        !(locked | self.scrolls_texture())
+    }
+
+    /// Returns the camera position at which `texture_point` lies at the center of the screen.
+    ///
+    /// The texture point is given in texture pixels relative to the center of the layer, with
+    /// the y-axis pointing up. A texture pixel spans one world unit of the layer.
+    ///
+    /// ## Assumptions
+    /// - The layer is processed, since the depth factor and the offset get resolved then.
+    /// - On a non-repeating axis the layer moves by the depth factor, meaning the position
+    ///   follows from the translation of the layer. A locked axis does not move at all.
+    /// - On a repeating or UV scrolling axis the layer follows the camera while the texture
+    ///   scrolls. The point is relative to the content at the center of the layer while the
+    ///   texture is not scrolled, although the same point of every other tile gets centered at
+    ///   positions a tile size divided by the depth factor apart. The tile origin and a
+    ///   [`ParallaxPixelScroll`] are not taken into account, and like the texture scrolling the
+    ///   y-axis is mirrored.
+    /// - A camera-local axis, see [`OffsetSpace::CameraLocal`], keeps the same point on screen,
+    ///   the result of such an axis is meaningless.
+    /// - An axis with a depth factor of `0.0` keeps the same point centered at any camera
+    ///   position, resulting in a non-finite coordinate.
+    /// - Dynamic effects, such as the speed boost and a [`ParallaxFactorBoost`], are not taken
+    ///   into account.
+    #[inline]
+    #[must_use]
+    pub fn camera_to_center(&self, texture_point: Vec2) -> Vec2 {
+        let depth_factor = self.depth.depth_factor_xy().unwrap_or(Vec2::ZERO);
+
+        // The layer at `camera * (1 - factor) + offset` centers the point when
+        // `camera * factor = offset + point`
+        let (layer_factor, _) = axis_factors(depth_factor, self.flags);
+        let layer_camera = (self.offset + texture_point) / layer_factor;
+
+        // Inverts the UV computation in `parallax_material.wgsl`, where the UV y-axis points down
+        let flip = Vec2::new(1.0, -1.0);
+        let texture_camera = flip * (texture_point + self.offset) / depth_factor - self.offset;
+
+        Vec2::select(self.scrolls_texture(), texture_camera, layer_camera)
+    }
+
+    /// Returns the resolved depth of the layer.
+    #[inline]
+    #[must_use]
+    pub fn depth(&self) -> Depth {
+        self.depth
+    }
+
+    /// Returns the resolved offset of the layer.
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> Vec2 {
+        self.offset
+    }
+
+    /// Returns the flags of the layer.
+    #[inline]
+    #[must_use]
+    pub fn flags(&self) -> ParallaxFlags {
+        self.flags
+    }
+
+    /// Sets the depth of the layer.
+    ///
+    /// The resolved depth gets updated the next time the layer is processed.
+    #[inline]
+    pub fn set_depth(&mut self, depth: impl Into<Depth>) -> &mut Self {
+        self.authored_depth = depth.into();
+        self
+    }
+
+    /// Sets the depth of the layer in parallax space, such as to push a layer further back
+    /// during a cutscene.
+    ///
+    /// The layer is processed again, converting the depth to a world depth and depth factor
+    /// with the [`ParallaxContext`](crate::resources::ParallaxContext), and updating its
+    /// transform and material. See [`set_depth`](ParallaxLayerData::set_depth) for setting a
+    /// depth in world space.
+    #[inline]
+    pub fn set_parallax_depth(&mut self, depth: f32) -> &mut Self {
+        self.set_depth(Depth::from_parallax(depth))
+    }
+
+    /// Sets the offset of the layer, see [`ParallaxLayer::offset`].
+    ///
+    /// The resolved offset gets updated the next time the layer is processed.
+    #[inline]
+    pub fn set_offset(&mut self, offset: Vec2) -> &mut Self {
+        self.authored_offset = offset;
+        self
+    }
+
+    /// Sets the flags of the layer.
+    #[inline]
+    pub fn set_flags(&mut self, flags: ParallaxFlags) -> &mut Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Returns the tile origin of the layer.
+    #[inline]
+    #[must_use]
+    pub fn tile_origin(&self) -> Option<Vec2> {
+        self.tile_origin
+    }
+
+    /// Sets the tile origin of the layer, see [`ParallaxLayer::tile_origin`].
+    #[inline]
+    pub fn set_tile_origin(&mut self, tile_origin: Option<Vec2>) -> &mut Self {
+        self.tile_origin = tile_origin;
+        self
+    }
+
+    /// Returns the size of the tiles of the repeating axes, relative to the size of the image.
+    #[inline]
+    #[must_use]
+    pub fn tile_scale(&self) -> Option<Vec2> {
+        self.tile_scale
+    }
+
+    /// Sets the size of the tiles of the repeating axes, see [`ParallaxLayer::tile_scale`].
+    #[inline]
+    pub fn set_tile_scale(&mut self, tile_scale: Option<Vec2>) -> &mut Self {
+        self.tile_scale = tile_scale;
+        self
+    }
+
+    /// Returns the pivot of the layer.
+    #[inline]
+    #[must_use]
+    pub fn pivot(&self) -> Vec2 {
+        self.pivot
+    }
+
+    /// Sets the pivot of the layer, see [`ParallaxLayer::pivot`].
+    #[inline]
+    pub fn set_pivot(&mut self, pivot: Vec2) -> &mut Self {
+        self.pivot = pivot;
+        self
+    }
+
+    /// Returns the space in which the offset of the layer is interpreted.
+    #[inline]
+    #[must_use]
+    pub fn offset_space(&self) -> OffsetSpace {
+        self.offset_space
+    }
+
+    /// Sets the space in which the offset of the layer is interpreted, see [`OffsetSpace`].
+    #[inline]
+    pub fn set_offset_space(&mut self, offset_space: OffsetSpace) -> &mut Self {
+        self.offset_space = offset_space;
+        self
+    }
+
+    /// Returns the size of the layer in world space, resolved during processing.
+    #[inline]
+    #[must_use]
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Returns the part of the layer visible on the window, in logical pixels from the top left
+    /// corner of the window, such as for placing UI over the layer.
+    ///
+    /// The layer at `transform` is projected with the translation and projection of the
+    /// `camera`, which is assumed to render to the whole window without rotation. A layer
+    /// partially on screen is clipped to the window. Returns `None` if the layer is off screen.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use bevy::prelude::{OrthographicProjection, Query, Transform, Window, With};
+    /// use bevy_parallaxation2d::prelude::*;
+    ///
+    /// fn place_callout(
+    ///     layer_query: Query<(&ParallaxLayerData, &Transform)>,
+    ///     camera_query: Query<(&Transform, &OrthographicProjection), With<ParallaxCamera>>,
+    ///     window_query: Query<&Window>,
+    /// ) {
+    ///     let camera = camera_query.single();
+    ///     let window = window_query.single();
+    ///     for (parallax, transform) in layer_query.iter() {
+    ///         if let Some(screen_rect) = parallax.screen_rect(transform, camera, window) {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn screen_rect(
+        &self,
+        transform: &Transform,
+        (camera_transform, camera_projection): (&Transform, &OrthographicProjection),
+        window: &Window,
+    ) -> Option<Rect> {
+        let window_size = Vec2::new(window.width(), window.height());
+        let view_min = camera_transform.translation.truncate() + camera_projection.area.min;
+        let view_size = camera_projection.area.size();
+
+        // The screen y-axis points down
+        let to_screen = |position: Vec2| {
+            let normalized = (position - view_min) / view_size;
+            Vec2::new(normalized.x, 1.0 - normalized.y) * window_size
+        };
+        let rect = Rect::from_center_size(
+            transform.translation.truncate(),
+            self.rendered_size(transform).abs(),
+        );
+        let screen_rect = Rect::from_corners(to_screen(rect.min), to_screen(rect.max))
+            .intersect(Rect::from_corners(Vec2::ZERO, window_size));
+
+        (!screen_rect.is_empty()).then_some(screen_rect)
+    }
+
+    /// Returns the strength of the tile variation of the layer.
+    #[inline]
+    #[must_use]
+    pub fn detile_strength(&self) -> f32 {
+        self.detile_strength
+    }
+
+    /// Sets the strength of the tile variation of the layer, see
+    /// [`ParallaxLayer::detile_strength`].
+    #[inline]
+    pub fn set_detile_strength(&mut self, detile_strength: f32) -> &mut Self {
+        self.detile_strength = detile_strength;
+        self
+    }
+
+    /// Returns the order of the layer among the layers at the same depth.
+    #[inline]
+    #[must_use]
+    pub fn order_in_tier(&self) -> i32 {
+        self.order_in_tier
+    }
+
+    /// Sets the order of the layer among the layers at the same depth, see
+    /// [`ParallaxLayer::order_in_tier`].
+    #[inline]
+    pub fn set_order_in_tier(&mut self, order_in_tier: i32) -> &mut Self {
+        self.order_in_tier = order_in_tier;
+        self
+    }
+
+    /// Returns the distance by which the z-position of the layer is moved forward.
+    #[inline]
+    #[must_use]
+    pub fn z_bias(&self) -> f32 {
+        self.z_bias
+    }
+
+    /// Sets the distance by which the z-position of the layer is moved forward, see
+    /// [`ParallaxLayer::z_bias`].
+    #[inline]
+    pub fn set_z_bias(&mut self, z_bias: f32) -> &mut Self {
+        self.z_bias = z_bias;
+        self
+    }
+
+    /// Returns the maximum level of anisotropic filtering of the texture of the layer.
+    #[inline]
+    #[must_use]
+    pub fn anisotropy(&self) -> u16 {
+        self.anisotropy
+    }
+
+    /// Sets the maximum level of anisotropic filtering of the texture of the layer, see
+    /// [`ParallaxLayer::anisotropy`].
+    #[inline]
+    pub fn set_anisotropy(&mut self, anisotropy: u16) -> &mut Self {
+        self.anisotropy = anisotropy;
+        self
+    }
+
+    /// Returns whether the depth factor of the layer ignores the scale of the parallax effect.
+    #[inline]
+    #[must_use]
+    pub fn ignore_global_scale(&self) -> bool {
+        self.ignore_global_scale
+    }
+
+    /// Sets whether the depth factor of the layer ignores the scale of the parallax effect, see
+    /// [`ParallaxLayer::ignore_global_scale`].
+    #[inline]
+    pub fn set_ignore_global_scale(&mut self, ignore_global_scale: bool) -> &mut Self {
+        self.ignore_global_scale = ignore_global_scale;
+        self
+    }
+}
+
+/// Returns the factors by which a layer and by which its texture follow the depth factor, per
+/// axis and with respect to the flags.
+///
+/// A locked axis stays in place in the world. On a repeating or UV scrolling axis the layer
+/// follows the camera, while the texture scrolls by the depth factor instead.
+#[inline]
+#[must_use]
+pub(crate) fn axis_factors(depth_factor: Vec2, flags: ParallaxFlags) -> (Vec2, Vec2) {
+    let locked = BVec2::new(
+        flags.contains(ParallaxFlags::LOCKED_X_AXIS),
+        flags.contains(ParallaxFlags::LOCKED_Y_AXIS),
+    );
+    let scrolls = scrolling_axes(flags);
+
+    let layer_factor = Vec2::select(
+        locked,
+        Vec2::ONE,
+        Vec2::select(scrolls, Vec2::ZERO, depth_factor),
+    );
+    let texture_factor = Vec2::select(scrolls, depth_factor, Vec2::ZERO);
+    (layer_factor, texture_factor)
+}
+
+/// Returns the axes where a layer follows the camera and the texture scrolls instead.
+#[inline]
+#[must_use]
+fn scrolling_axes(flags: ParallaxFlags) -> BVec2 {
+    if flags.contains(ParallaxFlags::LOCK_TILE_PHASE) {
+        return BVec2::FALSE;
+    }
+
+    BVec2::new(
+        flags.intersects(ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::SCROLL_UV_X_AXIS),
+        flags.intersects(ParallaxFlags::REPEAT_Y_AXIS | ParallaxFlags::SCROLL_UV_Y_AXIS),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flags::Axis2;
+    use bevy::window::WindowResolution;
+
+    #[test]
+    fn components_validate_layer() {
+        let valid = ParallaxLayer {
+            image: "mountains.png".into(),
+            depth: Depth::from_world(10.0, f32::INFINITY),
+            flags: ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::LOCKED_Y_AXIS,
+            tile_origin: Some(Vec2::ONE),
+            ..Default::default()
+        };
+        assert_eq!(valid.validate(), Ok(()));
+
+        let invalid = |layer: ParallaxLayer| layer.validate().unwrap_err();
+        assert_eq!(
+            invalid(ParallaxLayer {
+                image: "".into(),
+                ..valid.clone()
+            }),
+            ParallaxLayerError::EmptyImage
+        );
+        assert_eq!(
+            invalid(ParallaxLayer {
+                depth: f32::NAN.into(),
+                ..valid.clone()
+            }),
+            ParallaxLayerError::InvalidDepth
+        );
+        assert_eq!(
+            invalid(ParallaxLayer {
+                depth: Depth::from_world(f32::INFINITY, 1.0),
+                ..valid.clone()
+            }),
+            ParallaxLayerError::InvalidDepth
+        );
+        assert_eq!(
+            invalid(ParallaxLayer {
+                depth: Depth::from_world(1.0, f32::NAN),
+                ..valid.clone()
+            }),
+            ParallaxLayerError::InvalidDepth
+        );
+        assert_eq!(
+            invalid(ParallaxLayer {
+                offset: Vec2::new(0.0, f32::NAN),
+                ..valid.clone()
+            }),
+            ParallaxLayerError::InvalidOffset
+        );
+        assert_eq!(
+            invalid(ParallaxLayer {
+                tile_origin: Some(Vec2::new(f32::NEG_INFINITY, 0.0)),
+                ..valid.clone()
+            }),
+            ParallaxLayerError::InvalidOffset
+        );
+        assert_eq!(
+            invalid(ParallaxLayer {
+                z_bias: f32::INFINITY,
+                ..valid.clone()
+            }),
+            ParallaxLayerError::InvalidDepth
+        );
+        assert_eq!(
+            invalid(ParallaxLayer {
+                pivot: Vec2::splat(f32::NAN),
+                ..valid.clone()
+            }),
+            ParallaxLayerError::InvalidPivot
+        );
+        assert_eq!(
+            invalid(ParallaxLayer {
+                tile_scale: Some(Vec2::new(1.0, -2.0)),
+                ..valid.clone()
+            }),
+            ParallaxLayerError::InvalidTileScale
+        );
+        for atlas in [
+            ParallaxAtlas::new(0, 2, 12.0),
+            ParallaxAtlas {
+                frame_count: 9,
+                ..ParallaxAtlas::new(4, 2, 12.0)
+            },
+            ParallaxAtlas::new(4, 2, -1.0),
+        ] {
+            assert_eq!(
+                invalid(ParallaxLayer {
+                    atlas: Some(atlas),
+                    ..valid.clone()
+                }),
+                ParallaxLayerError::InvalidAtlas
+            );
+        }
+        assert_eq!(
+            invalid(ParallaxLayer {
+                flags: ParallaxFlags::SCROLL_UV_Y_AXIS | ParallaxFlags::LOCKED_Y_AXIS,
+                ..valid.clone()
+            }),
+            ParallaxLayerError::Flags(FlagConflictError::LockAndScrollUv(Axis2::Y))
+        );
+    }
+
+    #[test]
+    fn components_screen_rect() {
+        // A view of 100 by 50 world units centered at (10, 0), on a window of 800 by 400
+        let camera = (
+            Transform::from_xyz(10.0, 0.0, 0.0),
+            OrthographicProjection {
+                area: Rect::from_center_size(Vec2::ZERO, Vec2::new(100.0, 50.0)),
+                ..Default::default()
+            },
+        );
+        let window = Window {
+            resolution: WindowResolution::new(800.0, 400.0).with_scale_factor_override(1.0),
+            ..Default::default()
+        };
+        let parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE);
+        let screen_rect = |translation: Vec2| {
+            let transform = Transform::from_translation(translation.extend(0.0))
+                .with_scale(Vec2::new(20.0, 10.0).extend(1.0));
+            parallax.screen_rect(&transform, (&camera.0, &camera.1), &window)
+        };
+
+        let assert_screen_rect = |translation: Vec2, expected: Rect| {
+            let rect = screen_rect(translation).unwrap();
+            assert!(
+                rect.min.abs_diff_eq(expected.min, 1e-3),
+                "{rect:?} != {expected:?}"
+            );
+            assert!(
+                rect.max.abs_diff_eq(expected.max, 1e-3),
+                "{rect:?} != {expected:?}"
+            );
+        };
+
+        // Fully on screen, with the screen y-axis pointing down
+        assert_screen_rect(Vec2::new(10.0, 10.0), Rect::new(320.0, 80.0, 480.0, 160.0));
+        // Partially on screen, clipped to the window
+        assert_screen_rect(Vec2::new(60.0, 0.0), Rect::new(720.0, 160.0, 800.0, 240.0));
+        // Off screen
+        assert_eq!(screen_rect(Vec2::new(200.0, 0.0)), None);
+        assert_eq!(screen_rect(Vec2::new(10.0, -40.0)), None);
+    }
+
+    #[test]
+    fn components_layer_builder() {
+        let layer = ParallaxLayer::new("background.png")
+            .depth(40.0)
+            .offset(Vec2::Y * 10.0)
+            .repeat_x()
+            .offset_camera_top()
+            .build();
+
+        assert_eq!(layer.image, "background.png".into());
+        assert_eq!(layer.depth, Depth::from_parallax(40.0));
+        assert_eq!(layer.offset, Vec2::Y * 10.0);
+        assert_eq!(
+            layer.flags,
+            ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::OFFSET_CAMERA_TOP
+        );
+
+        // Flags start empty, and a new camera offset replaces the previous one
+        let layer = ParallaxLayer::new("foreground.png").build();
+        assert_eq!(layer.flags, ParallaxFlags::NONE);
+        let layer = ParallaxLayer::new("foreground.png")
+            .offset_camera_right()
+            .offset_camera_bottom()
+            .lock_y()
+            .build();
+        assert_eq!(
+            layer.flags,
+            ParallaxFlags::OFFSET_CAMERA_BOTTOM | ParallaxFlags::LOCKED_Y_AXIS
+        );
+        assert_eq!(layer.validate(), Ok(()));
+    }
+
+    #[test]
+    fn components_atlas_frames() {
+        let atlas = ParallaxAtlas {
+            frame_count: 6,
+            ..ParallaxAtlas::new(4, 2, 4.0)
+        };
+
+        assert_eq!(atlas.frame_at(0.0), 0);
+        assert_eq!(atlas.frame_at(0.3), 1);
+        assert_eq!(atlas.frame_at(1.25), 5);
+        // Loops over the played frames only
+        assert_eq!(atlas.frame_at(1.5), 0);
+        assert_eq!(atlas.frame_at(2.25), 3);
+        // A still atlas shows the first frame
+        assert_eq!(ParallaxAtlas::new(4, 2, 0.0).frame_at(10.0), 0);
+    }
 }
@@ -1,14 +1,20 @@
 use crate::resources::ParallaxContext;
+use bevy::prelude::{Reflect, Vec2};
+#[cfg(feature = "serialize")]
+use bevy::reflect::{ReflectDeserialize, ReflectSerialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use DepthType::*;
 
 /// Represents different types of depth.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 enum DepthType {
     /// Indicates a user-space depth.
     Parallax(f32),
-    /// The depth used in the parallax system.
-    WorldWithFactor(f32, f32),
+    /// The depth used in the parallax system, with a factor per axis.
+    WorldWithFactor(f32, Vec2),
 }
 
 /// Depth of a parallax layer.
@@ -34,7 +40,16 @@ enum DepthType {
 ///
 /// let depth = Depth::from_world(10.0, 2.0);
 /// ```
-#[derive(Clone, Copy, Debug)]
+///
+/// With the `serialize` feature, the depth serializes along with its kind, such that a parallax
+/// depth and a world depth with factors both round-trip.
+#[derive(Clone, Copy, Debug, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect_value(Debug, PartialEq, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect_value(Debug, PartialEq))]
 pub struct Depth(DepthType);
 
 impl Depth {
@@ -61,7 +76,18 @@ impl Depth {
     /// The factor does ***not*** get multiplied by the scaling factor defined in the plugin.
     #[inline]
     pub fn from_world(depth: f32, factor: f32) -> Self {
-        Self(WorldWithFactor(depth, factor))
+        Self(WorldWithFactor(depth, Vec2::splat(factor)))
+    }
+
+    /// Creates a new `Depth` from a custom depth and a factor per axis, such as for a stronger
+    /// horizontal than vertical parallax effect in a side-scroller.
+    ///
+    /// See [`from_world`](crate::depth::Depth::from_world) for the depth and the factors. Where
+    /// a single factor is needed, such as for selecting a [`ParallaxLod`](crate::components::ParallaxLod),
+    /// the horizontal factor is used.
+    #[inline]
+    pub fn from_world_xy(depth: f32, factor_x: f32, factor_y: f32) -> Self {
+        Self(WorldWithFactor(depth, Vec2::new(factor_x, factor_y)))
     }
 
     #[inline]
@@ -73,15 +99,32 @@ impl Depth {
         }
     }
 
+    /// Returns the horizontal factor, see [`depth_factor_xy`](Depth::depth_factor_xy).
     #[inline]
     #[must_use]
     pub(crate) fn depth_factor(&self) -> Option<f32> {
+        self.depth_factor_xy().map(|factor| factor.x)
+    }
+
+    #[inline]
+    #[must_use]
+    pub(crate) fn depth_factor_xy(&self) -> Option<Vec2> {
         match self.0 {
             Parallax(_) => None,
             WorldWithFactor(_, factor) => Some(factor),
         }
     }
 
+    /// Adds a delta to the depth, keeping the factor of a world depth.
+    #[inline]
+    #[must_use]
+    pub(crate) fn with_offset(self, delta: f32) -> Self {
+        match self.0 {
+            Parallax(depth) => Self(Parallax(depth + delta)),
+            WorldWithFactor(depth, factor) => Self(WorldWithFactor(depth + delta, factor)),
+        }
+    }
+
     /// Translates a parallax depth to world depth with a factor using the given context.
     #[inline]
     #[must_use]
@@ -90,7 +133,22 @@ impl Depth {
             Parallax(depth) => {
                 let depth = context.convert_depth(depth);
                 let factor = context.calculate_depth_factor(depth);
-                Self(WorldWithFactor(depth, factor))
+                Self(WorldWithFactor(depth, Vec2::splat(factor)))
+            }
+            WorldWithFactor(_, _) => self,
+        }
+    }
+
+    /// Translates a parallax depth to world depth with a factor using the given context, as if
+    /// the scale of the context were `1.0`.
+    #[inline]
+    #[must_use]
+    pub(crate) fn to_world_with_unscaled_factor(self, context: &ParallaxContext) -> Self {
+        match self.0 {
+            Parallax(depth) => {
+                let depth = context.convert_depth(depth);
+                let factor = context.calculate_unscaled_depth_factor(depth);
+                Self(WorldWithFactor(depth, Vec2::splat(factor)))
             }
             WorldWithFactor(_, _) => self,
         }
@@ -176,6 +234,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
     fn depth_ordering() {
         let context = ParallaxContext::new(ParallaxConfig {
             scale: 2.0,
@@ -237,4 +296,49 @@ mod tests {
         assert_eq!(depth.depth(), 50.0);
         assert_eq!(depth.depth_factor(), Some(-10.0));
     }
+
+    #[test]
+    fn depth_from_world_xy() {
+        let depth = Depth::from_world_xy(-10.0, 0.75, 0.25);
+        assert_eq!(depth.depth(), -10.0);
+        assert_eq!(depth.depth_factor_xy(), Some(Vec2::new(0.75, 0.25)));
+        assert_eq!(depth.depth_factor(), Some(0.75));
+
+        // A single factor applies to both axes
+        let depth = Depth::from_world(-10.0, 0.5);
+        assert_eq!(depth.depth_factor_xy(), Some(Vec2::splat(0.5)));
+        assert_eq!(Depth::from_parallax(10.0).depth_factor_xy(), None);
+    }
+
+    #[test]
+    fn depth_to_world_with_unscaled_factor() {
+        let context = ParallaxContext::new(ParallaxConfig {
+            scale: 2.0,
+            neutral_depth: 5.0,
+            ..Default::default()
+        });
+
+        let depth = Depth::from_parallax(20.0);
+        assert_eq!(
+            depth.to_world_with_factor(&context).depth_factor(),
+            Some(1.0)
+        );
+        let unscaled = depth.to_world_with_unscaled_factor(&context);
+        assert_eq!(unscaled.depth(), -15.0);
+        assert_eq!(unscaled.depth_factor(), Some(0.5));
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn depth_serialize() {
+        for depth in [
+            Depth::from_parallax(10.0),
+            Depth::from_world_xy(-10.0, 0.75, 0.25),
+        ] {
+            let serialized = ron::to_string(&depth).unwrap();
+            let deserialized: Depth = ron::from_str(&serialized).unwrap();
+            assert_eq!(deserialized, depth);
+            assert_eq!(deserialized.depth_factor_xy(), depth.depth_factor_xy());
+        }
+    }
 }
@@ -0,0 +1,55 @@
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    prelude::App,
+    utils::Instant,
+};
+
+/// Diagnostics measuring the time spent in the parallax systems each frame, in milliseconds,
+/// requiring the `diagnostics` feature.
+///
+/// The diagnostics are registered by the [`ParallaxPlugin`](crate::plugin::ParallaxPlugin), and
+/// can be read from the [`DiagnosticsStore`](bevy::diagnostic::DiagnosticsStore), or logged
+/// with the [`LogDiagnosticsPlugin`](bevy::diagnostic::LogDiagnosticsPlugin).
+///
+/// ## Examples
+/// ```
+/// # use bevy::{diagnostic::DiagnosticsStore, prelude::Res};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn report_move_cost(diagnostics: Res<DiagnosticsStore>) {
+///     if let Some(milliseconds) = diagnostics
+///         .get(&ParallaxDiagnostics::MOVE_LAYERS)
+///         .and_then(|diagnostic| diagnostic.smoothed())
+///     {
+///         println!("Moving the parallax layers took {milliseconds:.3} ms");
+///     }
+/// }
+/// ```
+pub struct ParallaxDiagnostics;
+
+impl ParallaxDiagnostics {
+    /// Time spent loading new parallax layers.
+    pub const INITIAL_LOAD_LAYERS: DiagnosticPath =
+        DiagnosticPath::const_new("parallax/initial_load_layers");
+    /// Time spent processing new and changed parallax layers.
+    pub const PROCESS_LAYERS: DiagnosticPath = DiagnosticPath::const_new("parallax/process_layers");
+    /// Time spent moving the parallax layers with the camera.
+    pub const MOVE_LAYERS: DiagnosticPath = DiagnosticPath::const_new("parallax/move_layers");
+
+    /// Registers the diagnostics.
+    pub(crate) fn register(app: &mut App) {
+        for path in [
+            Self::INITIAL_LOAD_LAYERS,
+            Self::PROCESS_LAYERS,
+            Self::MOVE_LAYERS,
+        ] {
+            app.register_diagnostic(Diagnostic::new(path).with_suffix("ms"));
+        }
+    }
+
+    /// Adds the time elapsed since `start` as a measurement of the diagnostic.
+    #[inline]
+    pub(crate) fn measure(diagnostics: &mut Diagnostics, path: &DiagnosticPath, start: Instant) {
+        diagnostics.add_measurement(path, || start.elapsed().as_secs_f64() * 1000.0);
+    }
+}
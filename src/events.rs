@@ -0,0 +1,73 @@
+use crate::depth::Depth;
+use bevy::prelude::{Entity, Event, Handle, Image};
+
+/// Event signalling that the parallax camera was teleported this frame, such as when
+/// respawning at a checkpoint.
+///
+/// The layers follow the camera to its new position as usual, but the jump of the camera is
+/// not treated as movement. The jump thereby does not raise the speed boost, and the layers get
+/// no motion blur velocity from it.
+///
+/// Send the event in the same frame as the camera is moved, before `PostUpdate`.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{EventWriter, Query, Transform, Vec3, With};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn respawn(
+///     mut camera_query: Query<&mut Transform, With<ParallaxCamera>>,
+///     mut teleport_events: EventWriter<ParallaxTeleport>,
+/// ) {
+///     camera_query.single_mut().translation = Vec3::new(0.0, 0.0, 0.0);
+///     teleport_events.send(ParallaxTeleport);
+/// }
+/// ```
+#[derive(Event, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParallaxTeleport;
+
+/// Event signalling that a parallax layer was processed for the first time, meaning its image
+/// is loaded and its transform and material are set up.
+///
+/// A spawned [`ParallaxLayer`](crate::components::ParallaxLayer) only renders correctly from
+/// this point on. Processing the layer again, such as after changing its depth, sends no
+/// further event.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{EventReader, Local};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// const LEVEL_LAYERS: usize = 4;
+///
+/// fn hide_loading_screen(
+///     mut ready_events: EventReader<ParallaxLayerReady>,
+///     mut ready_layers: Local<usize>,
+/// ) {
+///     *ready_layers += ready_events.read().count();
+///     if *ready_layers == LEVEL_LAYERS {
+///         // Fade out the loading screen
+///     }
+/// }
+/// ```
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct ParallaxLayerReady {
+    /// The parallax layer.
+    pub entity: Entity,
+    /// The resolved depth of the layer.
+    pub depth: Depth,
+}
+
+/// Event signalling that the image of a parallax layer failed to load, such as for a path
+/// which does not exist.
+///
+/// The layer is not processed, and stays invisible. Changing the
+/// [`ParallaxLayerData`](crate::components::ParallaxLayerData) of the layer tries to process it
+/// again.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct ParallaxLayerLoadFailed {
+    /// The parallax layer.
+    pub entity: Entity,
+    /// The image which failed to load.
+    pub image: Handle<Image>,
+}
@@ -1,4 +1,8 @@
+use bevy::prelude::Reflect;
+#[cfg(feature = "serialize")]
+use bevy::reflect::{ReflectDeserialize, ReflectSerialize};
 use bitflags::bitflags;
+use std::fmt;
 
 bitflags! {
     /// Represents various parallax scrolling settings using bit flags.
@@ -6,6 +10,8 @@ bitflags! {
     /// ## Features
     /// * Repeat - Stretches the parallax layer with correct tiling along the specified axis.
     /// * Lock - Locks the layer's translation on the specified axis.
+    /// * Scroll UV - Scrolls the texture of a non-repeating layer instead of moving the layer.
+    /// * Clamp - Scrolls a non-repeating layer with its depth factor, up to its own edges.
     /// * Offset - A [`ParallaxLayer`](crate::components::ParallaxLayer) can specify an offset, flags can adjust it to the camera boundary.
     ///
     /// ## Examples
@@ -19,8 +25,13 @@ bitflags! {
     /// assert!(flags.intersects(ParallaxFlags::OFFSET_CAMERA_TOP));
     /// assert!(flags.contains(ParallaxFlags::NONE | ParallaxFlags::HORIZONTAL_OFFSET));
     /// ```
-    #[derive(Clone, Copy, Debug, PartialEq)]
-    pub struct ParallaxFlags: u8 {
+    ///
+    /// With the `serialize` feature, the flags serialize as the names of the set flags.
+    #[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+    #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "serialize", reflect_value(Debug, PartialEq, Serialize, Deserialize))]
+    #[cfg_attr(not(feature = "serialize"), reflect_value(Debug, PartialEq))]
+    pub struct ParallaxFlags: u32 {
         /// No set bit flags.
         const NONE = 0;
         /// Repeats the parallax effect along the X axis.
@@ -45,6 +56,76 @@ bitflags! {
         const OFFSET_CAMERA_BOTTOM = ParallaxFlags::OFFSET_TO_CAMERA.bits();
         /// Offsets the parallax effect to the camera's top.
         const OFFSET_CAMERA_TOP = ParallaxFlags::OFFSET_CAMERA_BOTTOM.bits() | ParallaxFlags::POSITIVE_OFFSET.bits();
+        /// Scrolls the texture along the X axis instead of moving the layer, on a non-repeating axis.
+        ///
+        /// The layer keeps its native width and follows the camera, while the texture pans within
+        /// the layer. Beyond the texture the edge pixels are shown.
+        const SCROLL_UV_X_AXIS = 128;
+        /// Scrolls the texture along the Y axis instead of moving the layer, on a non-repeating axis.
+        ///
+        /// The layer keeps its native height and follows the camera, while the texture pans within
+        /// the layer. Beyond the texture the edge pixels are shown.
+        const SCROLL_UV_Y_AXIS = 256;
+        /// Shows only whole tiles on the repeating axes.
+        ///
+        /// The layer gets stretched to the smallest whole number of tiles covering the camera
+        /// view, instead of exactly the camera view, so no partial tile shows at its edges.
+        const WHOLE_TILES = 512;
+        /// Locks the tiling phase of the texture to the layer on the repeating and UV scrolling
+        /// axes.
+        ///
+        /// The layer moves with its depth factor like on any other axis, instead of following the
+        /// camera and scrolling its texture. The texture is not scrolled, meaning the pattern
+        /// rides along with the moving layer rather than sliding across it.
+        const LOCK_TILE_PHASE = 1024;
+        /// Scales a non-repeating layer to fit inside the camera view, keeping its aspect ratio.
+        ///
+        /// The layer touches the edges of the camera view along one axis, leaving empty space
+        /// along the other axis unless the aspect ratios match. Cannot be combined with a
+        /// repeating axis.
+        const CONTAIN = 2048;
+        /// Repeats the layer along both axes, covering the camera view regardless of the size of
+        /// the texture. The tiles at the edges of the camera view may be cut off.
+        const COVER = ParallaxFlags::REPEAT_X_AXIS.bits() | ParallaxFlags::REPEAT_Y_AXIS.bits();
+        /// Sizes the layer with a mesh of its own instead of the scale of its transform.
+        ///
+        /// The transform keeps a unit scale, so children of the layer are not distorted. The
+        /// layer gets a rectangle mesh of its size, replacing the shared
+        /// [`ParallaxMesh`](crate::resources::ParallaxMesh). Set it when spawning the layer.
+        const UNIT_SCALE = 4096;
+        /// Snaps the position of the layer, and the scrolling of its texture, to the screen
+        /// pixel grid of its camera, such as for pixel art with nearest filtering.
+        ///
+        /// The size of a screen pixel in world units follows the projection of the camera, and
+        /// thereby its scaling mode and zoom. The grid of
+        /// [`ParallaxPlugin::set_pixel_perfect`](crate::plugin::ParallaxPlugin::set_pixel_perfect)
+        /// takes precedence when set.
+        const PIXEL_SNAP = 8192;
+        /// Keeps the rotation of the layer, instead of rotating it with the camera.
+        ///
+        /// Without this flag, a layer turns around its camera along with it, scaled by one minus
+        /// its depth factor, such that distant layers turn with the camera and appear to rotate
+        /// less on screen than near ones. The turn applies on top of the rotation of the layer
+        /// itself, and a repeating layer covers the diagonal of the view of a rotated camera.
+        const LOCK_ROTATION = 16384;
+        /// Keeps the color of the layer, instead of tinting it towards the fog color of the
+        /// atmosphere, see
+        /// [`ParallaxPlugin::set_atmosphere`](crate::plugin::ParallaxPlugin::set_atmosphere).
+        const NO_ATMOSPHERE = 32768;
+        /// Limits the scrolling of a non-repeating layer along the X axis to its own edges.
+        ///
+        /// The layer keeps its native width and moves with its depth factor, like on any other
+        /// non-repeating axis, until an edge of the layer reaches the edge of the camera view.
+        /// From there the layer follows the camera, such that the camera view never scrolls past
+        /// the layer. A layer narrower than the camera view is kept inside it instead.
+        const CLAMP_X_AXIS = 65536;
+        /// Limits the scrolling of a non-repeating layer along the Y axis to its own edges.
+        ///
+        /// The layer keeps its native height and moves with its depth factor, like on any other
+        /// non-repeating axis, until an edge of the layer reaches the edge of the camera view.
+        /// From there the layer follows the camera, such that the camera view never scrolls past
+        /// the layer. A layer lower than the camera view is kept inside it instead.
+        const CLAMP_Y_AXIS = 131072;
         /// Default parallax settings with repeat along X axis and offset to the camera's bottom.
         const DEFAULT = ParallaxFlags::REPEAT_X_AXIS.bits() | ParallaxFlags::OFFSET_CAMERA_BOTTOM.bits();
     }
@@ -56,3 +137,383 @@ impl Default for ParallaxFlags {
         Self::DEFAULT
     }
 }
+
+/// Selection of axes, used when building [`ParallaxFlags`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Axis2 {
+    /// Neither axis.
+    #[default]
+    None,
+    /// The X axis.
+    X,
+    /// The Y axis.
+    Y,
+    /// Both the X and Y axes.
+    Both,
+}
+
+impl Axis2 {
+    #[inline]
+    #[must_use]
+    fn has_x(self) -> bool {
+        matches!(self, Self::X | Self::Both)
+    }
+
+    #[inline]
+    #[must_use]
+    fn has_y(self) -> bool {
+        matches!(self, Self::Y | Self::Both)
+    }
+}
+
+/// Camera boundary which the offset of a parallax layer gets adjusted to, used when building
+/// [`ParallaxFlags`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Anchor {
+    /// Same as [`ParallaxFlags::OFFSET_CAMERA_LEFT`].
+    Left,
+    /// Same as [`ParallaxFlags::OFFSET_CAMERA_RIGHT`].
+    Right,
+    /// Same as [`ParallaxFlags::OFFSET_CAMERA_BOTTOM`].
+    Bottom,
+    /// Same as [`ParallaxFlags::OFFSET_CAMERA_TOP`].
+    Top,
+}
+
+impl ParallaxFlags {
+    /// Builds a set of flags from the axes to repeat along, the axes to lock and an optional
+    /// camera anchor.
+    ///
+    /// ## Panics
+    /// Panics in debug builds if the same axis is both repeated and locked.
+    ///
+    /// ## Examples
+    /// ```
+    /// use bevy_parallaxation2d::prelude::*;
+    ///
+    /// let flags = ParallaxFlags::build(Axis2::X, Axis2::None, Some(Anchor::Top));
+    /// assert_eq!(flags, ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::OFFSET_CAMERA_TOP);
+    /// ```
+    #[must_use]
+    pub fn build(repeat: Axis2, lock: Axis2, anchor: Option<Anchor>) -> Self {
+        let mut flags = Self::NONE;
+        flags.set(Self::REPEAT_X_AXIS, repeat.has_x());
+        flags.set(Self::REPEAT_Y_AXIS, repeat.has_y());
+        flags.set(Self::LOCKED_X_AXIS, lock.has_x());
+        flags.set(Self::LOCKED_Y_AXIS, lock.has_y());
+
+        let validated = flags.validate();
+        debug_assert!(validated.is_ok(), "{}", validated.unwrap_err());
+
+        flags
+            | match anchor {
+                Some(Anchor::Left) => Self::OFFSET_CAMERA_LEFT,
+                Some(Anchor::Right) => Self::OFFSET_CAMERA_RIGHT,
+                Some(Anchor::Bottom) => Self::OFFSET_CAMERA_BOTTOM,
+                Some(Anchor::Top) => Self::OFFSET_CAMERA_TOP,
+                None => Self::NONE,
+            }
+    }
+
+    /// Creates flags from raw bits, rejecting undefined bits and contradicting flags.
+    ///
+    /// See [`validate`](ParallaxFlags::validate) for the rules. The unchecked bitflags
+    /// constructors stay available for advanced use.
+    ///
+    /// ## Examples
+    /// ```
+    /// use bevy_parallaxation2d::prelude::*;
+    ///
+    /// let flags = ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::LOCKED_Y_AXIS;
+    /// assert_eq!(ParallaxFlags::try_new(flags.bits()), Ok(flags));
+    ///
+    /// let flags = ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::LOCKED_X_AXIS;
+    /// assert_eq!(
+    ///     ParallaxFlags::try_new(flags.bits()),
+    ///     Err(FlagConflictError::RepeatAndLock(Axis2::X))
+    /// );
+    /// ```
+    pub fn try_new(bits: u32) -> Result<Self, FlagConflictError> {
+        let flags = Self::from_bits(bits)
+            .ok_or(FlagConflictError::UnknownBits(bits & !Self::all().bits()))?;
+        flags.validate()?;
+        Ok(flags)
+    }
+
+    /// Checks the flags for contradictions, per axis:
+    /// * An axis cannot both repeat and be locked.
+    /// * An axis cannot both repeat and scroll the UV, as a repeating texture already scrolls.
+    /// * An axis cannot both be locked and scroll the UV, as a UV scrolling layer follows the
+    ///   camera.
+    /// * An axis cannot both repeat and be fitted inside the camera view with
+    ///   [`CONTAIN`](ParallaxFlags::CONTAIN).
+    /// * A clamped axis cannot repeat, be locked or scroll the UV, as a clamped layer moves with
+    ///   its depth factor up to its edges.
+    pub fn validate(&self) -> Result<(), FlagConflictError> {
+        let axes = [
+            (
+                Axis2::X,
+                Self::REPEAT_X_AXIS,
+                Self::LOCKED_X_AXIS,
+                Self::SCROLL_UV_X_AXIS,
+                Self::CLAMP_X_AXIS,
+            ),
+            (
+                Axis2::Y,
+                Self::REPEAT_Y_AXIS,
+                Self::LOCKED_Y_AXIS,
+                Self::SCROLL_UV_Y_AXIS,
+                Self::CLAMP_Y_AXIS,
+            ),
+        ];
+
+        for (axis, repeat, lock, scroll_uv, clamp) in axes {
+            if self.contains(repeat | lock) {
+                return Err(FlagConflictError::RepeatAndLock(axis));
+            }
+            if self.contains(repeat | scroll_uv) {
+                return Err(FlagConflictError::RepeatAndScrollUv(axis));
+            }
+            if self.contains(lock | scroll_uv) {
+                return Err(FlagConflictError::LockAndScrollUv(axis));
+            }
+            if self.contains(repeat | Self::CONTAIN) {
+                return Err(FlagConflictError::RepeatAndContain(axis));
+            }
+            if self.contains(repeat | clamp) {
+                return Err(FlagConflictError::RepeatAndClamp(axis));
+            }
+            if self.contains(lock | clamp) {
+                return Err(FlagConflictError::LockAndClamp(axis));
+            }
+            if self.contains(scroll_uv | clamp) {
+                return Err(FlagConflictError::ScrollUvAndClamp(axis));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Inserts or removes the specified flags, like [`set`](ParallaxFlags::set), unless the
+    /// result contradicts itself, in which case the flags are left unchanged.
+    pub fn try_set(&mut self, other: Self, value: bool) -> Result<(), FlagConflictError> {
+        let mut flags = *self;
+        flags.set(other, value);
+        flags.validate()?;
+        *self = flags;
+        Ok(())
+    }
+}
+
+/// Contradiction found when validating [`ParallaxFlags`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlagConflictError {
+    /// The axis is both repeated and locked.
+    RepeatAndLock(Axis2),
+    /// The axis is both repeated and scrolling the UV.
+    RepeatAndScrollUv(Axis2),
+    /// The axis is both locked and scrolling the UV.
+    LockAndScrollUv(Axis2),
+    /// The axis is repeated while the layer is fitted inside the camera view.
+    RepeatAndContain(Axis2),
+    /// The axis is both repeated and clamped.
+    RepeatAndClamp(Axis2),
+    /// The axis is both locked and clamped.
+    LockAndClamp(Axis2),
+    /// The axis is both scrolling the UV and clamped.
+    ScrollUvAndClamp(Axis2),
+    /// The bits do not correspond to any flag.
+    UnknownBits(u32),
+}
+
+impl fmt::Display for FlagConflictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RepeatAndLock(axis) => {
+                write!(
+                    f,
+                    "Parallax flags cannot both repeat and lock the {axis:?} axis."
+                )
+            }
+            Self::RepeatAndScrollUv(axis) => write!(
+                f,
+                "Parallax flags cannot both repeat and scroll the UV of the {axis:?} axis."
+            ),
+            Self::LockAndScrollUv(axis) => write!(
+                f,
+                "Parallax flags cannot both lock and scroll the UV of the {axis:?} axis."
+            ),
+            Self::RepeatAndContain(axis) => write!(
+                f,
+                "Parallax flags cannot both repeat the {axis:?} axis and contain the layer."
+            ),
+            Self::RepeatAndClamp(axis) => write!(
+                f,
+                "Parallax flags cannot both repeat and clamp the {axis:?} axis."
+            ),
+            Self::LockAndClamp(axis) => write!(
+                f,
+                "Parallax flags cannot both lock and clamp the {axis:?} axis."
+            ),
+            Self::ScrollUvAndClamp(axis) => write!(
+                f,
+                "Parallax flags cannot both clamp and scroll the UV of the {axis:?} axis."
+            ),
+            Self::UnknownBits(bits) => write!(f, "Parallax flags have unknown bits {bits:#x}."),
+        }
+    }
+}
+
+impl std::error::Error for FlagConflictError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_build() {
+        assert_eq!(
+            ParallaxFlags::build(Axis2::None, Axis2::None, None),
+            ParallaxFlags::NONE
+        );
+        assert_eq!(
+            ParallaxFlags::build(Axis2::X, Axis2::None, Some(Anchor::Bottom)),
+            ParallaxFlags::DEFAULT
+        );
+        assert_eq!(
+            ParallaxFlags::build(Axis2::X, Axis2::None, Some(Anchor::Top)),
+            ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::OFFSET_CAMERA_TOP
+        );
+        assert_eq!(
+            ParallaxFlags::build(Axis2::Both, Axis2::None, None),
+            ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS
+        );
+        assert_eq!(
+            ParallaxFlags::build(Axis2::Y, Axis2::X, Some(Anchor::Left)),
+            ParallaxFlags::REPEAT_Y_AXIS
+                | ParallaxFlags::LOCKED_X_AXIS
+                | ParallaxFlags::OFFSET_CAMERA_LEFT
+        );
+        assert_eq!(
+            ParallaxFlags::build(Axis2::None, Axis2::Both, Some(Anchor::Right)),
+            ParallaxFlags::LOCKED_X_AXIS
+                | ParallaxFlags::LOCKED_Y_AXIS
+                | ParallaxFlags::OFFSET_CAMERA_RIGHT
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "repeat and lock the X axis")]
+    fn flags_build_contradiction_x() {
+        let _ = ParallaxFlags::build(Axis2::X, Axis2::Both, None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "repeat and lock the Y axis")]
+    fn flags_build_contradiction_y() {
+        let _ = ParallaxFlags::build(Axis2::Both, Axis2::Y, Some(Anchor::Top));
+    }
+
+    #[test]
+    fn flags_try_new_conflicts() {
+        let conflicts = [
+            (
+                ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::LOCKED_X_AXIS,
+                FlagConflictError::RepeatAndLock(Axis2::X),
+            ),
+            (
+                ParallaxFlags::REPEAT_Y_AXIS | ParallaxFlags::LOCKED_Y_AXIS,
+                FlagConflictError::RepeatAndLock(Axis2::Y),
+            ),
+            (
+                ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::SCROLL_UV_X_AXIS,
+                FlagConflictError::RepeatAndScrollUv(Axis2::X),
+            ),
+            (
+                ParallaxFlags::REPEAT_Y_AXIS | ParallaxFlags::SCROLL_UV_Y_AXIS,
+                FlagConflictError::RepeatAndScrollUv(Axis2::Y),
+            ),
+            (
+                ParallaxFlags::LOCKED_X_AXIS | ParallaxFlags::SCROLL_UV_X_AXIS,
+                FlagConflictError::LockAndScrollUv(Axis2::X),
+            ),
+            (
+                ParallaxFlags::LOCKED_Y_AXIS | ParallaxFlags::SCROLL_UV_Y_AXIS,
+                FlagConflictError::LockAndScrollUv(Axis2::Y),
+            ),
+            (
+                ParallaxFlags::REPEAT_Y_AXIS | ParallaxFlags::CONTAIN,
+                FlagConflictError::RepeatAndContain(Axis2::Y),
+            ),
+            (
+                ParallaxFlags::COVER | ParallaxFlags::CONTAIN,
+                FlagConflictError::RepeatAndContain(Axis2::X),
+            ),
+            (
+                ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::CLAMP_X_AXIS,
+                FlagConflictError::RepeatAndClamp(Axis2::X),
+            ),
+            (
+                ParallaxFlags::LOCKED_Y_AXIS | ParallaxFlags::CLAMP_Y_AXIS,
+                FlagConflictError::LockAndClamp(Axis2::Y),
+            ),
+            (
+                ParallaxFlags::SCROLL_UV_X_AXIS | ParallaxFlags::CLAMP_X_AXIS,
+                FlagConflictError::ScrollUvAndClamp(Axis2::X),
+            ),
+        ];
+
+        for (flags, error) in conflicts {
+            let flags = flags | ParallaxFlags::OFFSET_CAMERA_TOP;
+            assert_eq!(ParallaxFlags::try_new(flags.bits()), Err(error));
+            assert_eq!(flags.validate(), Err(error));
+        }
+
+        assert_eq!(
+            ParallaxFlags::try_new(1 << 31 | ParallaxFlags::DEFAULT.bits()),
+            Err(FlagConflictError::UnknownBits(1 << 31))
+        );
+        assert_eq!(
+            FlagConflictError::RepeatAndLock(Axis2::X).to_string(),
+            "Parallax flags cannot both repeat and lock the X axis."
+        );
+    }
+
+    #[test]
+    fn flags_try_new_valid() {
+        let valid = [
+            ParallaxFlags::NONE,
+            ParallaxFlags::DEFAULT,
+            ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS,
+            ParallaxFlags::LOCKED_X_AXIS | ParallaxFlags::LOCKED_Y_AXIS,
+            ParallaxFlags::SCROLL_UV_X_AXIS | ParallaxFlags::SCROLL_UV_Y_AXIS,
+            ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::LOCKED_Y_AXIS,
+            ParallaxFlags::REPEAT_Y_AXIS | ParallaxFlags::LOCKED_X_AXIS,
+            ParallaxFlags::CLAMP_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS,
+            ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::SCROLL_UV_Y_AXIS,
+            ParallaxFlags::SCROLL_UV_X_AXIS | ParallaxFlags::LOCKED_Y_AXIS,
+            ParallaxFlags::OFFSET_CAMERA_RIGHT | ParallaxFlags::REPEAT_Y_AXIS,
+            ParallaxFlags::COVER,
+            ParallaxFlags::CONTAIN | ParallaxFlags::LOCKED_X_AXIS,
+        ];
+
+        for flags in valid {
+            assert_eq!(ParallaxFlags::try_new(flags.bits()), Ok(flags));
+        }
+    }
+
+    #[test]
+    fn flags_try_set() {
+        let mut flags = ParallaxFlags::DEFAULT;
+        assert_eq!(
+            flags.try_set(ParallaxFlags::LOCKED_X_AXIS, true),
+            Err(FlagConflictError::RepeatAndLock(Axis2::X))
+        );
+        assert_eq!(flags, ParallaxFlags::DEFAULT);
+
+        assert_eq!(flags.try_set(ParallaxFlags::LOCKED_Y_AXIS, true), Ok(()));
+        assert_eq!(flags, ParallaxFlags::DEFAULT | ParallaxFlags::LOCKED_Y_AXIS);
+    }
+}
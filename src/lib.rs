@@ -5,7 +5,10 @@
 //! * **[`ParallaxPlugin`](crate::plugin::ParallaxPlugin)** - Plugin required for the parallax functionality.
 //! * **[`ParallaxCamera`](crate::components::ParallaxCamera)** - Component for marking the parallax camera.
 //! * **[`ParallaxLayer`](crate::components::ParallaxLayer)** - Component for creating a parallax layer.
+//! * **[`ParallaxLayerData`](crate::components::ParallaxLayerData)** - Component for modifying a loaded parallax layer.
+//! * **[`ParallaxContext`](crate::resources::ParallaxContext)** - Resource for modifying the parallax configuration at runtime.
 //! * **[`ParallaxFlags`](crate::flags::ParallaxFlags)** - Bit flags for defining attributes of a parallax layer.
+//! * **[`ParallaxMaterial`](crate::material::ParallaxMaterial)** - Material of a parallax layer, for changing its uniforms directly.
 //!
 //! ## Examples
 //! ```no_run
@@ -25,7 +28,7 @@
 //!     // Spawn parallax camera
 //!     commands
 //!         .spawn(Camera2dBundle::default())
-//!         .insert(ParallaxCamera);
+//!         .insert(ParallaxCamera::default());
 //!
 //!     // Spawn:
 //!     // * Main background that repeats in both directions.
@@ -33,18 +36,18 @@
 //!     // * Foreground at the top of the screen.
 //!     commands.spawn_batch(vec![
 //!         ParallaxLayer {
-//!             image: "main_background.png",
+//!             image: "main_background.png".into(),
 //!             depth: 80.0.into(),
 //!             flags: ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS,
 //!             ..default()
 //!         },
 //!         ParallaxLayer {
-//!             image: "hills_background.png",
+//!             image: "hills_background.png".into(),
 //!             depth: 40.0.into(),
 //!             ..default()
 //!         },
 //!         ParallaxLayer {
-//!             image: "branches_foreground.png",
+//!             image: "branches_foreground.png".into(),
 //!             depth: (-5.0).into(),
 //!             flags: ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::OFFSET_CAMERA_TOP,
 //!             ..default()
@@ -55,9 +58,13 @@
 
 mod commands;
 mod components;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod events;
 mod flags;
 mod material;
 mod plugin;
+mod queries;
 mod resources;
 mod systems;
 
@@ -70,7 +77,7 @@ mod systems;
 /// # use bevy::prelude::default;
 /// # use bevy_parallaxation2d::prelude::*;
 /// let layer = ParallaxLayer {
-///     image: "background.png",
+///     image: "background.png".into(),
 ///     depth: 1.0.into(),
 ///     ..default()
 /// };
@@ -82,11 +89,39 @@ pub mod depth;
 /// components, and bitflags necessary for implementing parallax effects.
 pub mod prelude {
     pub use crate::{
-        commands::ParallaxDespawnCommands,
-        components::{ParallaxCamera, ParallaxLayer},
-        flags::ParallaxFlags,
-        plugin::ParallaxPlugin,
+        commands::{
+            ParallaxCameraCommands, ParallaxDespawnCommands, ParallaxLayerCommands,
+            ParallaxReprocessAll, ParallaxVisibilityCommands,
+        },
+        components::{
+            BlendMode, DepthHandle, MaskSpace, OffsetSpace, ParallaxAlpha, ParallaxAtlas,
+            ParallaxCamera, ParallaxCursorMask, ParallaxDissolve, ParallaxFactorBoost,
+            ParallaxFadeIn, ParallaxImageSource, ParallaxLayer, ParallaxLayerBounds,
+            ParallaxLayerBuilder, ParallaxLayerData, ParallaxLayerError, ParallaxLayerPending,
+            ParallaxLayerProcessed, ParallaxLod, ParallaxMaterialTime, ParallaxPixelScroll,
+            ParallaxRelativeDepth, ParallaxShimmer, ParallaxSolidLayer, ParallaxStreamRegion,
+            ParallaxTargetCamera, ParallaxUvRotation,
+        },
+        events::{ParallaxLayerLoadFailed, ParallaxLayerReady, ParallaxTeleport},
+        flags::{Anchor, Axis2, FlagConflictError, ParallaxFlags},
+        material::ParallaxMaterial,
+        plugin::{ParallaxPlugin, ParallaxSet},
+        queries::{
+            backmost_layer, debug_dump, find_layer_by_name, frontmost_layer, nearest_layer_behind,
+            parallax_textures_in_use, ParallaxLayers,
+        },
+        resources::{
+            GradientAxis, NeutralSemantics, ParallaxBeatPulse, ParallaxCameraVelocity,
+            ParallaxClearColorGradient, ParallaxClock, ParallaxColorGrading, ParallaxContext,
+            ParallaxDepthRegistry, ParallaxFocusDepth, ParallaxMesh, ParallaxNeutralDepthAnimation,
+            ParallaxProfile, ParallaxSceneDepth,
+        },
     };
+
+    #[cfg(feature = "diagnostics")]
+    pub use crate::diagnostics::ParallaxDiagnostics;
+    #[cfg(feature = "serialize")]
+    pub use crate::queries::export_parallax_ron;
 }
 
 /// Test the readme example
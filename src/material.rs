@@ -1,13 +1,65 @@
-use crate::plugin::PARALLAX_SHADER_HANDLE;
+use crate::{
+    components::{BlendMode, MaskSpace},
+    plugin::PARALLAX_SHADER_HANDLE,
+};
 use bevy::{
-    prelude::{Asset, Color, Handle, Image, TypePath, Vec2},
-    render::render_resource::{AsBindGroup, ShaderRef},
-    sprite::Material2d,
+    prelude::{Asset, Color, Handle, Image, TypePath, UVec2, Vec2, Vec4},
+    render::{
+        mesh::MeshVertexBufferLayout,
+        render_resource::{
+            AsBindGroup, BlendComponent, BlendFactor, BlendOperation, BlendState,
+            RenderPipelineDescriptor, ShaderRef, SpecializedMeshPipelineError,
+        },
+    },
+    sprite::{Material2d, Material2dKey},
 };
 
-/// Material for parallax rendering.
-#[derive(AsBindGroup, Clone, Asset, TypePath)]
-pub(crate) struct ParallaxMaterial {
+/// Material for parallax rendering, created for every parallax layer when it is spawned.
+///
+/// The material of a layer can be changed through its `Handle<ParallaxMaterial>`, such as for
+/// custom effects the crate does not provide. Most uniforms are however written by the parallax
+/// systems, so a change only sticks while nothing writes the uniform again:
+/// * Written when the layer is processed, which happens again once its [`ParallaxLayerData`]
+///   changes or its camera is resized: the texture sampler, repeat scale, depth, offset, tile
+///   phase, detile strength, sprite sheet grid and the tint of the atmosphere.
+/// * Written every frame: the shift and velocity, and the pixel snapping of a layer with
+///   [`ParallaxFlags::PIXEL_SNAP`].
+/// * Written by the components driving them: the opacity, dissolve threshold, shimmer, UV
+///   rotation, current frame, cursor mask, alpha and time, see for example [`ParallaxAlpha`].
+/// * Safe to change at any time: the color, blend mode, mask, dissolve image and whether the
+///   texture is a signed distance field. The color grading LUT is safe to change as long as no
+///   [`ParallaxColorGrading`] is inserted.
+///
+/// Swapping the texture with [`set_image_handle`](ParallaxMaterial::set_image_handle) shows the
+/// new image with its own sampler. Mark the [`ParallaxLayerData`] as changed to have the layer
+/// processed again, which sets up the sampler and dimensions for the new image.
+///
+/// [`ParallaxLayerData`]: crate::components::ParallaxLayerData
+/// [`ParallaxFlags::PIXEL_SNAP`]: crate::flags::ParallaxFlags::PIXEL_SNAP
+/// [`ParallaxAlpha`]: crate::components::ParallaxAlpha
+/// [`ParallaxColorGrading`]: crate::resources::ParallaxColorGrading
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{AssetServer, Assets, DetectChangesMut, Handle, Query, Res, ResMut};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn swap_to_night(
+///     mut layer_query: Query<(&Handle<ParallaxMaterial>, &mut ParallaxLayerData)>,
+///     mut materials: ResMut<Assets<ParallaxMaterial>>,
+///     asset_server: Res<AssetServer>,
+/// ) {
+///     for (material, mut parallax) in layer_query.iter_mut() {
+///         if let Some(material) = materials.get_mut(material) {
+///             material.set_image_handle(asset_server.load("night.png"));
+///             parallax.set_changed();
+///         }
+///     }
+/// }
+/// ```
+#[derive(AsBindGroup, Clone, Debug, Asset, TypePath)]
+#[bind_group_data(BlendMode)]
+pub struct ParallaxMaterial {
     #[uniform(0)]
     color: Color,
     #[uniform(0)]
@@ -16,12 +68,69 @@ pub(crate) struct ParallaxMaterial {
     offset: Vec2,
     #[uniform(0)]
     repeat_scale: Vec2,
+    #[uniform(0)]
+    tile_phase: Vec2,
+    #[uniform(0)]
+    shift: Vec2,
+    #[uniform(0)]
+    pixel_snap: Vec2,
+    #[uniform(0)]
+    dissolve_threshold: f32,
+    #[uniform(0)]
+    time: f32,
+    #[uniform(0)]
+    shimmer: Vec2,
+    #[uniform(0)]
+    opacity: f32,
+    #[uniform(0)]
+    velocity: Vec2,
+    #[uniform(0)]
+    mask_screen_space: u32,
+    #[uniform(0)]
+    detile_strength: Vec2,
+    #[uniform(0)]
+    alpha: f32,
+    #[uniform(0)]
+    cursor_position: Vec2,
+    #[uniform(0)]
+    cursor_radius: f32,
+    #[uniform(0)]
+    cursor_masked: u32,
+    #[uniform(0)]
+    sdf: u32,
+    #[uniform(0)]
+    color_graded: u32,
+    #[uniform(0)]
+    frame_size: Vec2,
+    #[uniform(0)]
+    frame_columns: u32,
+    #[uniform(0)]
+    current_frame: u32,
+    #[uniform(0)]
+    uv_rotation: f32,
+    #[uniform(0)]
+    aspect_ratio: f32,
     #[texture(1)]
     #[sampler(2)]
     texture: Handle<Image>,
+    #[texture(3)]
+    #[sampler(4)]
+    dissolve_texture: Option<Handle<Image>>,
+    #[texture(5)]
+    #[sampler(6)]
+    mask_texture: Option<Handle<Image>>,
+    #[texture(7)]
+    #[sampler(8)]
+    lut_texture: Option<Handle<Image>>,
+    blend_mode: BlendMode,
+    /// Color of the layer before the tint of the atmosphere.
+    base_color: Color,
+    /// Image set for the layer, of which the sampled texture is a processed copy.
+    source_image: Handle<Image>,
 }
 
 impl ParallaxMaterial {
+    /// Creates a material sampling the given image, tinted by the given color.
     #[inline]
     #[must_use]
     pub fn new(image: Handle<Image>, color: Color) -> Self {
@@ -30,10 +139,75 @@ impl ParallaxMaterial {
             depth: Vec2::ZERO,
             offset: Vec2::ZERO,
             repeat_scale: Vec2::ONE,
-            texture: image,
+            tile_phase: Vec2::ZERO,
+            shift: Vec2::ZERO,
+            pixel_snap: Vec2::ZERO,
+            dissolve_threshold: 1.0,
+            time: 0.0,
+            shimmer: Vec2::ZERO,
+            opacity: 1.0,
+            velocity: Vec2::ZERO,
+            mask_screen_space: 1,
+            detile_strength: Vec2::ZERO,
+            alpha: 1.0,
+            cursor_position: Vec2::ZERO,
+            cursor_radius: 0.0,
+            cursor_masked: 0,
+            sdf: 0,
+            color_graded: 0,
+            frame_size: Vec2::ZERO,
+            frame_columns: 1,
+            current_frame: 0,
+            uv_rotation: 0.0,
+            aspect_ratio: 1.0,
+            texture: image.clone(),
+            dissolve_texture: None,
+            mask_texture: None,
+            lut_texture: None,
+            blend_mode: BlendMode::Alpha,
+            base_color: color,
+            source_image: image,
         }
     }
 
+    #[inline]
+    #[must_use]
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Returns the color tinting the texture before the tint of the atmosphere.
+    #[inline]
+    #[must_use]
+    pub fn base_color(&self) -> Color {
+        self.base_color
+    }
+
+    /// Sets the color tinting the texture. The tint of the atmosphere is applied on top again
+    /// once the layer is processed.
+    #[inline]
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.color = color;
+        self.base_color = color;
+        self
+    }
+
+    /// Tints the color of the layer towards the fog color of the atmosphere by `amount`, where
+    /// `0.0` keeps the color of the layer. The alpha is kept.
+    #[inline]
+    pub(crate) fn set_atmosphere(&mut self, fog_color: Color, amount: f32) -> &mut Self {
+        if amount == 0.0 {
+            self.color = self.base_color;
+            return self;
+        }
+
+        let base_color = Vec4::from(self.base_color.as_linear_rgba_f32());
+        let fog_color = Vec4::from(fog_color.as_linear_rgba_f32());
+        let [red, green, blue, _] = base_color.lerp(fog_color, amount).to_array();
+        self.color = Color::rgba_linear(red, green, blue, base_color.w);
+        self
+    }
+
     #[inline]
     #[must_use]
     pub fn image_handle(&self) -> Handle<Image> {
@@ -42,27 +216,375 @@ impl ParallaxMaterial {
 
     #[inline]
     pub fn set_image_handle(&mut self, image: Handle<Image>) -> &mut Self {
+        self.texture = image.clone();
+        self.source_image = image;
+        self
+    }
+
+    /// Returns the image last set with [`set_image_handle`](ParallaxMaterial::set_image_handle),
+    /// which the sampled texture is a copy of once the layer is processed.
+    #[inline]
+    #[must_use]
+    pub(crate) fn source_image(&self) -> &Handle<Image> {
+        &self.source_image
+    }
+
+    /// Sets the texture sampled, a copy of the source image set up by the processing.
+    #[inline]
+    pub(crate) fn set_processed_image(&mut self, image: Handle<Image>) -> &mut Self {
         self.texture = image;
         self
     }
 
+    #[inline]
+    #[must_use]
+    pub fn repeat_scale(&self) -> Vec2 {
+        self.repeat_scale
+    }
+
     #[inline]
     pub fn set_repeat_scale(&mut self, repeat_scale: Vec2) -> &mut Self {
         self.repeat_scale = repeat_scale;
         self
     }
 
+    #[inline]
+    #[must_use]
+    pub fn depth(&self) -> Vec2 {
+        self.depth
+    }
+
     #[inline]
     pub fn set_depth(&mut self, depth: Vec2) -> &mut Self {
         self.depth = depth;
         self
     }
 
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> Vec2 {
+        self.offset
+    }
+
+    /// Sets the offset of the layer in world space, added to the camera translation before it
+    /// is scaled by the depth to scroll the texture.
     #[inline]
     pub fn set_offset(&mut self, offset: Vec2) -> &mut Self {
         self.offset = offset;
         self
     }
+
+    #[inline]
+    pub fn set_tile_phase(&mut self, tile_phase: Vec2) -> &mut Self {
+        self.tile_phase = tile_phase;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn shift(&self) -> Vec2 {
+        self.shift
+    }
+
+    #[inline]
+    pub fn set_shift(&mut self, shift: Vec2) -> &mut Self {
+        self.shift = shift;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn pixel_snap(&self) -> Vec2 {
+        self.pixel_snap
+    }
+
+    /// Sets the number of pixel grid cells across the layer, which the texture scrolling gets
+    /// snapped to. Zero disables snapping.
+    #[inline]
+    pub fn set_pixel_snap(&mut self, pixel_snap: Vec2) -> &mut Self {
+        self.pixel_snap = pixel_snap;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn dissolve_image(&self) -> Option<&Handle<Image>> {
+        self.dissolve_texture.as_ref()
+    }
+
+    #[inline]
+    pub fn set_dissolve_image(&mut self, image: Option<Handle<Image>>) -> &mut Self {
+        self.dissolve_texture = image;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn dissolve_threshold(&self) -> f32 {
+        self.dissolve_threshold
+    }
+
+    /// Sets the dissolve threshold, where `1.0` shows the full layer. Below it, only the
+    /// fragments where the dissolve texture lies below the threshold are shown.
+    #[inline]
+    pub fn set_dissolve_threshold(&mut self, dissolve_threshold: f32) -> &mut Self {
+        self.dissolve_threshold = dissolve_threshold;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Sets the time uniform, in seconds of the [`ParallaxClock`](crate::resources::ParallaxClock),
+    /// which animated shader effects read. Kept up to date for layers with a
+    /// [`ParallaxMaterialTime`](crate::components::ParallaxMaterialTime).
+    #[inline]
+    pub fn set_time(&mut self, time: f32) -> &mut Self {
+        self.time = time;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn shimmer(&self) -> Vec2 {
+        self.shimmer
+    }
+
+    /// Sets the UV perturbation of the shimmer, applied on top of the scrolling.
+    #[inline]
+    pub fn set_shimmer(&mut self, shimmer: Vec2) -> &mut Self {
+        self.shimmer = shimmer;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn uv_rotation(&self) -> f32 {
+        self.uv_rotation
+    }
+
+    /// Sets the counterclockwise rotation of the texture around the center of the layer, in
+    /// radians, applied before the tiling. The size of the layer keeps the rotated texture from
+    /// being skewed.
+    #[inline]
+    pub fn set_uv_rotation(&mut self, uv_rotation: f32, layer_size: Vec2) -> &mut Self {
+        self.uv_rotation = uv_rotation;
+        self.aspect_ratio = match layer_size.y {
+            0.0 => 1.0,
+            height => layer_size.x / height,
+        };
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    /// Sets the opacity, multiplied with the alpha of the tinted texture.
+    #[inline]
+    pub fn set_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Sets the alpha controlled by the user, multiplied with the alpha of the layer on top of
+    /// the opacity driven by the parallax effects.
+    #[inline]
+    pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
+        self.alpha = alpha;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn cursor_mask(&self) -> Option<(Vec2, f32)> {
+        (self.cursor_masked != 0).then_some((self.cursor_position, self.cursor_radius))
+    }
+
+    /// Sets the circle revealing the layer, given by its center and radius in physical pixels
+    /// of the render target. If `None`, the whole layer is revealed.
+    #[inline]
+    pub fn set_cursor_mask(&mut self, cursor_mask: Option<(Vec2, f32)>) -> &mut Self {
+        let (position, radius) = cursor_mask.unwrap_or_default();
+        self.cursor_position = position;
+        self.cursor_radius = radius;
+        self.cursor_masked = cursor_mask.is_some() as u32;
+        self
+    }
+
+    /// Sets whether the red channel of the texture is sampled as a signed distance field, see
+    /// [`ParallaxLayer::sdf`](crate::components::ParallaxLayer::sdf).
+    #[inline]
+    pub fn set_sdf(&mut self, sdf: bool) -> &mut Self {
+        self.sdf = sdf as u32;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn atlas_grid(&self) -> Option<UVec2> {
+        (self.frame_size != Vec2::ZERO).then(|| (1.0 / self.frame_size).round().as_uvec2())
+    }
+
+    /// Sets the number of frames along each axis of the sprite sheet of the texture. If `None`,
+    /// the whole texture is sampled.
+    #[inline]
+    pub fn set_atlas_grid(&mut self, grid: Option<UVec2>) -> &mut Self {
+        self.frame_size = grid.map_or(Vec2::ZERO, |grid| 1.0 / grid.as_vec2());
+        self.frame_columns = grid.map_or(1, |grid| grid.x);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn current_frame(&self) -> u32 {
+        self.current_frame
+    }
+
+    /// Sets the frame of the sprite sheet sampled, numbered row by row from the top left.
+    #[inline]
+    pub fn set_current_frame(&mut self, current_frame: u32) -> &mut Self {
+        self.current_frame = current_frame;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn color_lut(&self) -> Option<&Handle<Image>> {
+        self.lut_texture.as_ref()
+    }
+
+    /// Sets the color grading LUT remapping the final color of the layer, see
+    /// [`ParallaxColorGrading`](crate::resources::ParallaxColorGrading). If `None`, the color
+    /// is left as is.
+    #[inline]
+    pub fn set_color_lut(&mut self, lut: Option<Handle<Image>>) -> &mut Self {
+        self.color_graded = lut.is_some() as u32;
+        self.lut_texture = lut;
+        self
+    }
+
+    /// Sets how the layer is blended, specializing the render pipeline of the material.
+    #[inline]
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) -> &mut Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mask(&self) -> (Option<&Handle<Image>>, MaskSpace) {
+        let mask_space = match self.mask_screen_space {
+            0 => MaskSpace::Layer,
+            _ => MaskSpace::Screen,
+        };
+        (self.mask_texture.as_ref(), mask_space)
+    }
+
+    /// Sets the mask, whose alpha multiplies the alpha of the layer, sampled in the given space.
+    #[inline]
+    pub fn set_mask(&mut self, image: Option<Handle<Image>>, mask_space: MaskSpace) -> &mut Self {
+        self.mask_texture = image;
+        self.mask_screen_space = (mask_space == MaskSpace::Screen) as u32;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Sets the apparent velocity of the layer content on screen, in world units per second,
+    /// as a hint for motion blur.
+    #[inline]
+    pub fn set_velocity(&mut self, velocity: Vec2) -> &mut Self {
+        self.velocity = velocity;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn detile_strength(&self) -> Vec2 {
+        self.detile_strength
+    }
+
+    /// Sets the strength of the tile variation per axis, which should be zero on axes that
+    /// do not repeat.
+    #[inline]
+    pub fn set_detile_strength(&mut self, detile_strength: Vec2) -> &mut Self {
+        self.detile_strength = detile_strength;
+        self
+    }
+
+    /// Returns the texture coordinate, in tiles, actually sampled for the texture coordinate
+    /// `coordinate` after the tile variation. Mirrors the tile variation in
+    /// `parallax_material.wgsl`.
+    #[cfg(test)]
+    #[inline]
+    #[must_use]
+    pub(crate) fn detile_coordinate(&self, coordinate: Vec2) -> Vec2 {
+        let cell = coordinate.floor();
+        let cell_coordinate = coordinate - cell;
+        let flip = Vec2::new(
+            detile_hash(cell.x as i32 as u32),
+            detile_hash(cell.y as i32 as u32 ^ DETILE_ROW_SEED),
+        )
+        .cmplt(self.detile_strength * 0.5);
+        cell + Vec2::select(flip, 1.0 - cell_coordinate, cell_coordinate)
+    }
+
+    /// Converts a shift of the layer content in world space to a UV shift of the texture.
+    #[inline]
+    #[must_use]
+    pub(crate) fn uv_shift(shift: Vec2, layer_size: Vec2) -> Vec2 {
+        // The UV y-axis points down
+        shift / layer_size * Vec2::new(-1.0, 1.0)
+    }
+
+    /// Returns the texture coordinate, in tiles, sampled at `world_position` for the given
+    /// camera translation and layer placement. Mirrors the UV computation in
+    /// `parallax_material.wgsl`, where the UV y-axis points down, without the UV rotation.
+    #[inline]
+    #[must_use]
+    pub(crate) fn tile_coordinate(
+        &self,
+        camera_translation: Vec2,
+        layer_translation: Vec2,
+        layer_size: Vec2,
+        world_position: Vec2,
+    ) -> Vec2 {
+        let uv = (world_position - layer_translation) / layer_size * Vec2::new(1.0, -1.0) + 0.5;
+        ((camera_translation + self.offset) * self.depth + uv + self.shift) * self.repeat_scale
+            + self.tile_phase
+    }
+
+    /// Returns the texture offset, in tiles, for the given camera translation. Mirrors the
+    /// UV computation in `parallax_material.wgsl`.
+    #[cfg(test)]
+    #[inline]
+    #[must_use]
+    pub(crate) fn tiling_phase(&self, camera_translation: Vec2) -> Vec2 {
+        ((camera_translation + self.offset) * self.depth + self.shift) * self.repeat_scale
+    }
+}
+
+impl From<&ParallaxMaterial> for BlendMode {
+    #[inline]
+    fn from(material: &ParallaxMaterial) -> Self {
+        material.blend_mode
+    }
 }
 
 impl Material2d for ParallaxMaterial {
@@ -70,4 +592,146 @@ impl Material2d for ParallaxMaterial {
     fn fragment_shader() -> ShaderRef {
         PARALLAX_SHADER_HANDLE.into()
     }
+
+    fn specialize(
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayout,
+        key: Material2dKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        let targets = descriptor
+            .fragment
+            .iter_mut()
+            .flat_map(|fragment| fragment.targets.iter_mut().flatten());
+        for target in targets {
+            target.blend = blend_state(key.bind_group_data);
+        }
+
+        Ok(())
+    }
+}
+
+/// Blend state of the render pipeline of a material with the given blend mode.
+///
+/// Additive blending keeps the alpha of the layers behind, so the layer never makes the scene
+/// more opaque.
+#[inline]
+fn blend_state(blend_mode: BlendMode) -> Option<BlendState> {
+    match blend_mode {
+        BlendMode::Alpha => Some(BlendState::ALPHA_BLENDING),
+        BlendMode::Additive => Some(BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::SrcAlpha,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+            alpha: BlendComponent {
+                src_factor: BlendFactor::Zero,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+        }),
+        BlendMode::Opaque => None,
+    }
+}
+
+/// Seed separating the flips of the rows from the flips of the columns.
+#[cfg(test)]
+const DETILE_ROW_SEED: u32 = 0x9e37_79b9;
+
+/// Hashes a tile index to a value in `[0, 1]`. Mirrors `detile_hash` in `parallax_material.wgsl`.
+#[cfg(test)]
+fn detile_hash(index: u32) -> f32 {
+    let state = index.wrapping_mul(747_796_405).wrapping_add(2_891_336_453);
+    let word = ((state >> ((state >> 28) + 4)) ^ state).wrapping_mul(277_803_737);
+    ((word >> 22) ^ word) as f32 / u32::MAX as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn material_detile_seams() {
+        let mut material = ParallaxMaterial::new(Handle::default(), Color::WHITE);
+        let epsilon = 1e-3;
+        // Distance between two texture positions on a repeating texture
+        let wrapped_distance = |a: f32, b: f32| {
+            let distance = (a.rem_euclid(1.0) - b.rem_euclid(1.0)).abs();
+            distance.min(1.0 - distance)
+        };
+
+        // Without strength every tile is the same
+        for coordinate in [Vec2::new(0.25, 0.75), Vec2::new(-3.4, 7.1)] {
+            assert_eq!(material.detile_coordinate(coordinate), coordinate);
+        }
+
+        material.set_detile_strength(Vec2::ONE);
+        let mut flipped = 0;
+        for cell in (-8..8).flat_map(|x| (-8..8).map(move |y| Vec2::new(x as f32, y as f32))) {
+            let coordinate = cell + Vec2::new(0.3, 0.6);
+            let detiled = material.detile_coordinate(coordinate);
+            flipped += (detiled != coordinate) as u32;
+
+            // Flips stay within the tile
+            assert_eq!(detiled.floor(), cell);
+
+            // Both sides of the tile edges sample the same texture position
+            for (inside, outside) in [
+                (
+                    cell + Vec2::new(epsilon, 0.5),
+                    cell + Vec2::new(-epsilon, 0.5),
+                ),
+                (
+                    cell + Vec2::new(0.5, epsilon),
+                    cell + Vec2::new(0.5, -epsilon),
+                ),
+            ] {
+                let inside = material.detile_coordinate(inside);
+                let outside = material.detile_coordinate(outside);
+                assert!(wrapped_distance(inside.x, outside.x) < 2.0 * epsilon + 1e-5);
+                assert!(wrapped_distance(inside.y, outside.y) < 2.0 * epsilon + 1e-5);
+            }
+        }
+        assert!(flipped > 0);
+
+        // Axes without strength are never flipped
+        material.set_detile_strength(Vec2::new(1.0, 0.0));
+        for y in -8..8 {
+            let coordinate = Vec2::new(2.3, y as f32 + 0.6);
+            assert_eq!(material.detile_coordinate(coordinate).y, coordinate.y);
+        }
+    }
+
+    #[test]
+    fn material_blend_mode() {
+        let mut material = ParallaxMaterial::new(Handle::default(), Color::WHITE);
+        assert_eq!(BlendMode::from(&material), BlendMode::Alpha);
+        assert_eq!(
+            blend_state(BlendMode::from(&material)),
+            Some(BlendState::ALPHA_BLENDING)
+        );
+
+        material.set_blend_mode(BlendMode::Additive);
+        let additive = blend_state(BlendMode::from(&material)).unwrap();
+        assert_eq!(additive.color.src_factor, BlendFactor::SrcAlpha);
+        assert_eq!(additive.color.dst_factor, BlendFactor::One);
+        assert_eq!(additive.alpha.dst_factor, BlendFactor::One);
+
+        // Opaque layers replace the layers behind them
+        material.set_blend_mode(BlendMode::Opaque);
+        assert_eq!(blend_state(BlendMode::from(&material)), None);
+    }
+
+    #[test]
+    fn material_set_color() {
+        let mut material = ParallaxMaterial::new(Handle::default(), Color::WHITE);
+        material.set_atmosphere(Color::BLACK, 0.5);
+        assert_ne!(material.color(), Color::WHITE);
+
+        // The new color replaces the tinted color, and is kept once the tint is removed
+        material.set_color(Color::RED);
+        assert_eq!(material.color(), Color::RED);
+        material.set_atmosphere(Color::BLACK, 0.0);
+        assert_eq!(material.color(), Color::RED);
+    }
 }
@@ -7,6 +7,39 @@ struct ParallaxMaterial {
     depth: vec2<f32>,
     offset: vec2<f32>,
     repeat_scale: vec2<f32>,
+    tile_phase: vec2<f32>,
+    shift: vec2<f32>,
+    pixel_snap: vec2<f32>,
+    dissolve_threshold: f32,
+    // Elapsed seconds of the parallax clock
+    time: f32,
+    shimmer: vec2<f32>,
+    opacity: f32,
+    // Apparent velocity of the layer content on screen, in world units per second
+    velocity: vec2<f32>,
+    // Whether the mask is sampled in screen space instead of layer space
+    mask_screen_space: u32,
+    // Strength of the tile variation per axis, zero on axes that do not repeat
+    detile_strength: vec2<f32>,
+    // Alpha set by the user, on top of the opacity of the parallax effects
+    alpha: f32,
+    // Circle revealing the layer around the cursor, in physical pixels of the render target
+    cursor_position: vec2<f32>,
+    cursor_radius: f32,
+    // Whether the layer is only revealed around the cursor
+    cursor_masked: u32,
+    // Whether the red channel of the texture is a signed distance field
+    sdf: u32,
+    // Whether the final color is remapped through the color grading LUT
+    color_graded: u32,
+    // UV size of a frame of the sprite sheet, zero without a sprite sheet
+    frame_size: vec2<f32>,
+    frame_columns: u32,
+    current_frame: u32,
+    // Counterclockwise rotation of the texture around the center of the layer, in radians
+    uv_rotation: f32,
+    // Width over height of the layer, keeping the rotated texture from being skewed
+    aspect_ratio: f32,
 }
 
 // Camera view
@@ -16,15 +49,116 @@ struct ParallaxMaterial {
 @group(2) @binding(0) var<uniform> parallax_material: ParallaxMaterial;
 @group(2) @binding(1) var base_texture: texture_2d<f32>;
 @group(2) @binding(2) var base_sampler: sampler;
+@group(2) @binding(3) var dissolve_texture: texture_2d<f32>;
+@group(2) @binding(4) var dissolve_sampler: sampler;
+@group(2) @binding(5) var mask_texture: texture_2d<f32>;
+@group(2) @binding(6) var mask_sampler: sampler;
+@group(2) @binding(7) var lut_texture: texture_2d<f32>;
+@group(2) @binding(8) var lut_sampler: sampler;
+
+// Seed separating the flips of the rows from the flips of the columns
+const DETILE_ROW_SEED: u32 = 0x9e3779b9u;
+
+// Hashes a tile index to a value in [0, 1]
+fn detile_hash(index: u32) -> f32 {
+    let state = index * 747796405u + 2891336453u;
+    let word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+    return f32((word >> 22u) ^ word) / 4294967295.0;
+}
+
+// Remaps a color through the color grading LUT, a horizontal strip of square slices with red
+// along each slice, green down each slice and blue across the slices
+fn grade_color(color: vec3<f32>) -> vec3<f32> {
+    let size = f32(textureDimensions(lut_texture).y);
+    let lut_size = vec2<f32>(size * size, size);
+    let texel = saturate(color) * (size - 1.0);
+
+    // Interpolate between the two nearest blue slices, the sampler interpolates red and green
+    let slice = floor(texel.b);
+    let next_slice = min(slice + 1.0, size - 1.0);
+    let slice_uv = texel.rg + 0.5;
+    let low = textureSampleLevel(lut_texture, lut_sampler, (slice_uv + vec2<f32>(slice * size, 0.0)) / lut_size, 0.0);
+    let high = textureSampleLevel(lut_texture, lut_sampler, (slice_uv + vec2<f32>(next_slice * size, 0.0)) / lut_size, 0.0);
+    return mix(low.rgb, high.rgb, texel.b - slice);
+}
 
 @fragment
 fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
     // Calculate the camera offset with depth
-    let camera_offset = (view.world_position.xy + parallax_material.offset) * parallax_material.depth;
+    let unsnapped_offset = (view.world_position.xy + parallax_material.offset) * parallax_material.depth;
+
+    // Snap the texture scrolling to the pixel grid when rendering pixel-perfect
+    let snapped_offset = round(unsnapped_offset * parallax_material.pixel_snap) / parallax_material.pixel_snap;
+    let camera_offset = select(unsnapped_offset, snapped_offset, parallax_material.pixel_snap > vec2<f32>(0.0));
+
+    // Rotate the texture around the center of the layer, in units of the layer height, where the
+    // UV y-axis points down
+    let aspect = vec2<f32>(parallax_material.aspect_ratio, 1.0);
+    let centered_uv = (in.uv - 0.5) * aspect;
+    let rotation_cos = cos(parallax_material.uv_rotation);
+    let rotation_sin = sin(parallax_material.uv_rotation);
+    let rotated_uv = vec2<f32>(
+        centered_uv.x * rotation_cos - centered_uv.y * rotation_sin,
+        centered_uv.x * rotation_sin + centered_uv.y * rotation_cos,
+    ) / aspect + 0.5;
 
     // Get texture color with correct repeating of the texture
-    let base_color = textureSample(base_texture, base_sampler, (camera_offset + in.uv) * parallax_material.repeat_scale);
+    let uv = (camera_offset + rotated_uv + parallax_material.shift + parallax_material.shimmer) * parallax_material.repeat_scale + parallax_material.tile_phase;
+
+    // Break up the repetition by flipping tiles, per column horizontally and per row vertically,
+    // such that the edges of neighboring tiles still line up
+    let cell = floor(uv);
+    let cell_uv = uv - cell;
+    let cell_index = vec2<i32>(cell);
+    let flip = vec2<f32>(
+        detile_hash(u32(cell_index.x)),
+        detile_hash(u32(cell_index.y) ^ DETILE_ROW_SEED),
+    ) < parallax_material.detile_strength * 0.5;
+    let detiled_uv = cell + select(cell_uv, 1.0 - cell_uv, flip);
+
+    // Repeat the tiles within the current frame of a sprite sheet, instead of the whole texture
+    let has_frames = parallax_material.frame_size > vec2<f32>(0.0);
+    let frame = vec2<f32>(
+        f32(parallax_material.current_frame % parallax_material.frame_columns),
+        f32(parallax_material.current_frame / parallax_material.frame_columns),
+    );
+    let frame_uv = (frame + fract(detiled_uv)) * parallax_material.frame_size;
+    let texture_uv = select(detiled_uv, frame_uv, has_frames);
+    let gradient_scale = select(vec2<f32>(1.0), parallax_material.frame_size, has_frames);
+
+    // Sample with the gradients of the unflipped UV, which are continuous across the flips and
+    // the frame edges
+    let texture_color = textureSampleGrad(base_texture, base_sampler, texture_uv, dpdx(uv) * gradient_scale, dpdy(uv) * gradient_scale);
+
+    // A distance field has its edge at 0.5, smoothed over about a pixel at any scale, such that
+    // the edge stays crisp. The derivatives are taken outside of the branch on purpose
+    let sdf_distance = texture_color.r;
+    let smoothing = max(fwidth(sdf_distance), 1e-4);
+    let sdf_alpha = smoothstep(0.5 - smoothing, 0.5 + smoothing, sdf_distance);
+    let base_color = select(texture_color, vec4<f32>(1.0, 1.0, 1.0, sdf_alpha), parallax_material.sdf != 0u);
+
+    // Sample the mask over the viewport, or over the layer
+    let screen_uv = (in.position.xy - view.viewport.xy) / view.viewport.zw;
+    let mask_uv = select(in.uv, screen_uv, parallax_material.mask_screen_space != 0u);
+    let image_mask = textureSample(mask_texture, mask_sampler, mask_uv).a;
+
+    // Reveal the layer within the circle around the cursor, smoothing the edge over a pixel
+    let cursor_distance = distance(in.position.xy, parallax_material.cursor_position);
+    let cursor_mask = 1.0 - smoothstep(parallax_material.cursor_radius - 1.0, parallax_material.cursor_radius, cursor_distance);
+    let mask = image_mask * select(1.0, cursor_mask, parallax_material.cursor_masked != 0u);
+
+    // Discard the fragments not yet revealed by the dissolve
+    let dissolve = textureSample(dissolve_texture, dissolve_sampler, in.uv).r;
+    if parallax_material.dissolve_threshold < 1.0 && dissolve >= parallax_material.dissolve_threshold {
+        discard;
+    }
 
-    // Output the color tinted by the material color
-    return base_color * parallax_material.color;
+    // Output the color tinted by the material color and graded, faded by the opacity, the alpha
+    // and the mask
+    let color = base_color * parallax_material.color;
+    var graded_color = color.rgb;
+    if parallax_material.color_graded != 0u {
+        graded_color = grade_color(graded_color);
+    }
+    return vec4<f32>(graded_color, color.a * parallax_material.opacity * parallax_material.alpha * mask);
 }
@@ -1,9 +1,27 @@
-use crate::{material::*, resources::*, systems::*};
+#[cfg(feature = "diagnostics")]
+use crate::diagnostics::ParallaxDiagnostics;
+use crate::{
+    components::{
+        BlendMode, MaskSpace, OffsetSpace, ParallaxAtlas, ParallaxImageSource, ParallaxLayer,
+        ParallaxLod,
+    },
+    depth::Depth,
+    events::{ParallaxLayerLoadFailed, ParallaxLayerReady, ParallaxTeleport},
+    flags::ParallaxFlags,
+    material::*,
+    resources::*,
+    systems::*,
+};
 use bevy::{
     asset::load_internal_asset,
-    prelude::{default, App, Handle, Plugin, PostUpdate, Shader, Update},
+    prelude::{
+        default, resource_exists, App, Color, Entity, Handle, IntoSystemConfigs,
+        IntoSystemSetConfigs, Plugin, PostUpdate, PreUpdate, Shader, SystemSet, Vec2,
+    },
     sprite::Material2dPlugin,
+    transform::TransformSystem,
 };
+use std::borrow::Cow;
 
 /// A plugin for setting up and managing parallax layers in the application.
 ///
@@ -21,9 +39,50 @@ use bevy::{
 ///     ))
 ///     .run();
 /// ```
+///
+/// ## System ordering
+/// New parallax layers are loaded and changed layers are processed in `PreUpdate`. The resolved
+/// depth, offset and dimensions of a [`ParallaxLayerData`](crate::components::ParallaxLayerData)
+/// are thereby ready before `FixedUpdate` and `Update` run in the same frame. Changes made in
+/// `FixedUpdate` or `Update` are resolved in the `PreUpdate` of the next frame. The layers are
+/// moved with the camera in `PostUpdate`, before the transforms are propagated. See
+/// [`ParallaxSet`] for ordering systems around the parallax systems.
+///
+/// ## MSAA
+/// The parallax layers are drawn with the 2D material pipeline of Bevy, which is specialized
+/// for the sample count of the current [`Msaa`](bevy::prelude::Msaa) resource every frame.
+/// Changing `Msaa` at runtime is therefore supported, for every sample count supported by Bevy:
+/// `Off`, `Sample2`, `Sample4` and `Sample8`, as far as the graphics backend supports it. Note
+/// that WebGL2 only supports `Off` and `Sample4`.
 #[derive(Default)]
 pub struct ParallaxPlugin(ParallaxConfig);
 
+/// System sets of the parallax systems, for ordering systems of the application around them.
+///
+/// ## Examples
+/// ```no_run
+/// # use bevy::prelude::{App, DefaultPlugins, IntoSystemConfigs, PostUpdate};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn follow_player() {
+///     // Move the camera after the player ...
+/// }
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, ParallaxPlugin::default()))
+///     // The layers follow the camera in the same frame
+///     .add_systems(PostUpdate, follow_player.before(ParallaxSet::Move))
+///     .run();
+/// ```
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParallaxSet {
+    /// Loads the new parallax layers and processes the changed layers, in `PreUpdate`.
+    Process,
+    /// Moves the parallax layers with the cameras, in `PostUpdate`. Runs before the transforms
+    /// are propagated, and after any system moving a camera should run.
+    Move,
+}
+
 pub(crate) const PARALLAX_SHADER_HANDLE: Handle<Shader> =
     Handle::weak_from_u128(15425869855826893231);
 
@@ -38,20 +97,128 @@ impl Plugin for ParallaxPlugin {
         );
         app.add_plugins(Material2dPlugin::<ParallaxMaterial>::default());
 
+        app.add_event::<ParallaxTeleport>()
+            .add_event::<ParallaxLayerReady>()
+            .add_event::<ParallaxLayerLoadFailed>();
+
+        Self::register_types(app);
+
         app.insert_resource(ParallaxContext::new(self.0))
             .init_resource::<ParallaxMesh>()
-            .add_systems(
-                Update,
+            .init_resource::<ParallaxSolidImage>()
+            .init_resource::<ParallaxSceneDepth>()
+            .init_resource::<ParallaxClock>()
+            .init_resource::<ParallaxBeatPulse>()
+            .init_resource::<ParallaxCameraVelocity>()
+            .init_resource::<ParallaxDepthRegistry>()
+            .init_resource::<ParallaxMaterialCache>();
+
+        Self::add_systems(app);
+
+        #[cfg(feature = "diagnostics")]
+        ParallaxDiagnostics::register(app);
+
+        #[cfg(feature = "debug_gizmos")]
+        if self.0.debug_gizmos {
+            app.add_systems(
+                PostUpdate,
+                draw_parallax_debug_gizmos.after(update_parallax_scene_depth),
+            );
+        }
+    }
+}
+
+impl ParallaxPlugin {
+    /// Registers the reflected parallax types, such that layers can be saved in a scene.
+    fn register_types(app: &mut App) {
+        app.register_type::<ParallaxLayer>()
+            .register_type::<ParallaxImageSource>()
+            .register_type::<Depth>()
+            .register_type::<ParallaxFlags>()
+            .register_type::<OffsetSpace>()
+            .register_type::<MaskSpace>()
+            .register_type::<BlendMode>()
+            .register_type::<ParallaxAtlas>()
+            .register_type::<ParallaxLod>()
+            // Field types of the layer which are not registered by Bevy
+            .register_type::<Cow<'static, str>>()
+            .register_type::<Option<Cow<'static, str>>>()
+            .register_type::<Vec<ParallaxLod>>()
+            .register_type::<Option<Vec2>>()
+            .register_type::<Option<f32>>()
+            .register_type::<Option<Entity>>()
+            .register_type::<Option<ParallaxAtlas>>()
+            // The list of layers exported to RON
+            .register_type::<Vec<ParallaxLayer>>();
+    }
+
+    /// Adds the parallax systems to their schedules.
+    ///
+    /// Layers are loaded and processed in `PreUpdate`, so the resolved values of
+    /// [`ParallaxLayerData`](crate::components::ParallaxLayerData) can be read in `FixedUpdate`.
+    fn add_systems(app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            (
+                (advance_parallax_clock, update_parallax_material_time).chain(),
+                animate_parallax_atlases.after(advance_parallax_clock),
+                advance_parallax_beat_pulse.after(advance_parallax_clock),
                 (
+                    stream_parallax_regions.before(initial_load_parallax_layers),
+                    reload_modified_parallax_images.before(process_new_parallax_layer_data),
                     initial_load_parallax_layers,
-                    process_new_parallax_layer_data,
-                ),
+                    initial_load_parallax_solid_layers,
+                    (
+                        animate_parallax_neutral_depth
+                            .run_if(resource_exists::<ParallaxNeutralDepthAnimation>),
+                        sync_shared_parallax_depths,
+                        apply_parallax_relative_depths,
+                        mark_parallax_layers_on_context_change,
+                        mark_parallax_layers_on_camera_change,
+                        process_new_parallax_layer_data,
+                        select_parallax_layer_lod,
+                    )
+                        .chain(),
+                    unshare_parallax_materials
+                        .after(initial_load_parallax_layers)
+                        .before(animate_parallax_dissolve),
+                    animate_parallax_dissolve.after(initial_load_parallax_layers),
+                    fade_in_parallax_layers.after(process_new_parallax_layer_data),
+                )
+                    .in_set(ParallaxSet::Process)
+                    .after(advance_parallax_clock),
+            ),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                track_parallax_camera_velocities,
+                boost_parallax_layers_with_camera_speed,
+                move_parallax_layers,
+                update_parallax_layer_bounds,
+                shimmer_parallax_layers,
+                rotate_parallax_layer_uvs,
+                update_parallax_scene_depth,
             )
-            .add_systems(PostUpdate, move_parallax_layers);
+                .chain()
+                .in_set(ParallaxSet::Move),
+        )
+        .configure_sets(
+            PostUpdate,
+            ParallaxSet::Move.before(TransformSystem::TransformPropagate),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                update_parallax_clear_color.run_if(resource_exists::<ParallaxClearColorGradient>),
+                fade_parallax_layers_by_focus.run_if(resource_exists::<ParallaxFocusDepth>),
+                apply_parallax_alpha,
+                apply_parallax_color_grading,
+                update_parallax_cursor_masks,
+            ),
+        );
     }
-}
 
-impl ParallaxPlugin {
     /// Creates a new `ParallaxPlugin` with the specified near and far depths.
     ///
     /// The neutral depth gets set at the mid-point between the `near_depth` and `far_depth`.
@@ -78,7 +245,11 @@ impl ParallaxPlugin {
     /// Sets the neutral depth for the parallax effect.
     ///
     /// At this depth there is no parallax effect for a layer, meaning the layer seem to be
-    /// stationary with respect to the world space.
+    /// stationary with respect to the world space. See [`NeutralSemantics`] for keeping the
+    /// layer stationary on screen instead.
+    ///
+    /// A neutral depth in front of the near depth or behind the far depth is clamped to it,
+    /// with a warning, once the plugin is built.
     #[inline]
     #[must_use]
     pub fn set_neutral_depth(mut self, neutral_depth: f32) -> Self {
@@ -95,11 +266,225 @@ impl ParallaxPlugin {
         self.0.scale = scale;
         Self(self.0)
     }
+
+    /// Sets the speed boost for the parallax effect.
+    ///
+    /// When the camera moves, the depth factors get multiplied by
+    /// `1.0 + speed_boost * normalized_speed`, where the normalized speed is the camera speed
+    /// relative to `reference_speed`, clamped to at most `1.0`. This increases the parallax
+    /// separation when the camera moves fast. At rest the parallax effect is unchanged. The boost
+    /// eases in and out by the [`ParallaxClock`](crate::resources::ParallaxClock).
+    #[inline]
+    #[must_use]
+    pub fn set_speed_boost(mut self, speed_boost: f32, reference_speed: f32) -> Self {
+        self.0.speed_boost = speed_boost;
+        self.0.speed_boost_reference_speed = reference_speed;
+        Self(self.0)
+    }
+
+    /// Enables pixel-perfect rendering for an integer scaled camera.
+    ///
+    /// `pixels_per_unit` is the number of screen pixels per world unit at a camera scale of
+    /// `1`, and `scale` is the integer scale of the camera. The position of every layer, and
+    /// the scrolling of the textures, is snapped to the screen pixel grid relative to the
+    /// camera, and the textures are sampled without filtering.
+    ///
+    /// ## Panics
+    /// Panics if `pixels_per_unit` is not positive or `scale` is `0`.
+    #[inline]
+    #[must_use]
+    pub fn set_pixel_perfect(mut self, pixels_per_unit: f32, scale: u32) -> Self {
+        if pixels_per_unit <= 0.0 || scale == 0 {
+            panic!("Parallax pixel-perfect rendering needs a positive pixel density and scale.");
+        }
+
+        self.0.pixel_grid = Some(ParallaxConfig::pixel_grid((pixels_per_unit, scale)));
+        Self(self.0)
+    }
+
+    /// Sets the meaning of the neutral depth, see [`NeutralSemantics`].
+    ///
+    /// By default layers at the neutral depth keep their place in world space.
+    #[inline]
+    #[must_use]
+    pub fn set_neutral_semantics(mut self, neutral_semantics: NeutralSemantics) -> Self {
+        self.0.neutral_semantics = neutral_semantics;
+        Self(self.0)
+    }
+
+    /// Sets whether identical parallax layers share one material.
+    ///
+    /// Layers spawned with the same image, color, depth, offset, flags, tile origin,
+    /// [`DepthHandle`](crate::components::DepthHandle) and
+    /// [`ParallaxPixelScroll`](crate::components::ParallaxPixelScroll) render identically, so
+    /// reusing a single material reduces the material count when many such layers are spawned.
+    ///
+    /// Sharing is meant for layers which are not changed individually at runtime. A layer gets
+    /// a material of its own once it gets state of its own, being a
+    /// [`ParallaxAlpha`](crate::components::ParallaxAlpha),
+    /// [`ParallaxCursorMask`](crate::components::ParallaxCursorMask),
+    /// [`ParallaxDissolve`](crate::components::ParallaxDissolve),
+    /// [`ParallaxFactorBoost`](crate::components::ParallaxFactorBoost),
+    /// [`ParallaxRelativeDepth`](crate::components::ParallaxRelativeDepth),
+    /// [`ParallaxShimmer`](crate::components::ParallaxShimmer) or
+    /// [`ParallaxUvRotation`](crate::components::ParallaxUvRotation). A layer fading in never
+    /// shares its material.
+    ///
+    /// ## Note
+    /// Modifying the [`ParallaxLayerData`](crate::components::ParallaxLayerData) of a layer, or
+    /// its material directly, updates the material shared with the other layers. Layers sharing
+    /// a material should therefore be modified together, or not be spawned with sharing enabled.
+    #[inline]
+    #[must_use]
+    pub fn set_share_materials(mut self, share_materials: bool) -> Self {
+        self.0.share_materials = share_materials;
+        Self(self.0)
+    }
+
+    /// Sets whether parallax layers are validated when loaded.
+    ///
+    /// In strict mode every new [`ParallaxLayer`](crate::components::ParallaxLayer) is checked
+    /// with [`validate`](crate::components::ParallaxLayer::validate). An invalid layer is
+    /// logged as an error and skipped, instead of panicking or rendering wrong later on.
+    #[inline]
+    #[must_use]
+    pub fn set_strict(mut self, strict: bool) -> Self {
+        self.0.strict = strict;
+        Self(self.0)
+    }
+
+    /// Sets whether the depth ordering of the parallax layers is inverted, such as for the
+    /// perceived height of layers in a top-down game.
+    ///
+    /// By default a layer at parallax depth `depth` gets the world depth, its z-value,
+    /// `neutral_depth - depth`, meaning greater depths render behind and move slower. Inverted,
+    /// the depth of a layer is negated first, giving the world depth `neutral_depth + depth`.
+    /// Greater depths then render in front and move faster, with the depth factor of the
+    /// negated depth. The near and far depths are not negated, meaning the greatest height is
+    /// at `-near_depth` and the lowest at `-far_depth`. Depths in world space are unaffected.
+    #[inline]
+    #[must_use]
+    pub fn set_invert_depth_ordering(mut self, invert_depth_ordering: bool) -> Self {
+        self.0.invert_depth_ordering = invert_depth_ordering;
+        Self(self.0)
+    }
+
+    /// Sets the number of tiles per layer per axis above which a warning is logged when a
+    /// layer is processed, such as for a tiny texture repeating over a huge view. Many tiles
+    /// thrash the texture cache and lose precision in the texture coordinates.
+    ///
+    /// If `clamp` is set, the number of tiles is also clamped to `max_repeat_scale`, stretching
+    /// the tiles to cover the layer. The default is a warning above `1024.0` tiles without
+    /// clamping.
+    ///
+    /// ## Panics
+    /// Panics if `max_repeat_scale` is not positive.
+    #[inline]
+    #[must_use]
+    pub fn set_max_repeat_scale(mut self, max_repeat_scale: f32, clamp: bool) -> Self {
+        if max_repeat_scale <= 0.0 || max_repeat_scale.is_nan() {
+            panic!("Parallax maximum repeat scale should be positive.");
+        }
+
+        self.0.max_repeat_scale = max_repeat_scale;
+        self.0.clamp_repeat_scale = clamp;
+        Self(self.0)
+    }
+
+    /// Sets the distance in world depth between consecutive orders of parallax layers at the
+    /// same depth, see [`ParallaxLayer::order_in_tier`](crate::components::ParallaxLayer::order_in_tier).
+    ///
+    /// Keep the epsilon small enough for the layers of one tier not to pass the layers of the
+    /// next tier. The default is `0.001`.
+    ///
+    /// ## Panics
+    /// Panics if `tier_epsilon` is negative or NaN.
+    #[inline]
+    #[must_use]
+    pub fn set_tier_epsilon(mut self, tier_epsilon: f32) -> Self {
+        if tier_epsilon < 0.0 || tier_epsilon.is_nan() {
+            panic!("Parallax tier epsilon should not be negative.");
+        }
+
+        self.0.tier_epsilon = tier_epsilon;
+        Self(self.0)
+    }
+
+    /// Sets the smallest and largest depth factor of a parallax layer, before the scale is
+    /// applied.
+    ///
+    /// The depth factor of a layer is clamped to these bounds, such as for keeping the far
+    /// layers moving slightly instead of standing still, or for calming down the layers close
+    /// to the near depth. Layers at and beyond the far depth get `min`, and layers at and
+    /// before the near depth get `max`. The default bounds are `0.0` and `100.0`.
+    ///
+    /// ## Panics
+    /// Panics if `min` is greater than `max`, or either is NaN.
+    #[inline]
+    #[must_use]
+    pub fn set_factor_bounds(mut self, min: f32, max: f32) -> Self {
+        if min > max || min.is_nan() || max.is_nan() {
+            panic!("Parallax depth factor bounds should satisfy min <= max.");
+        }
+
+        self.0.depth_factor_min = min;
+        self.0.depth_factor_max = max;
+        Self(self.0)
+    }
+
+    /// Tints the parallax layers behind the neutral depth towards a fog color, for an aerial
+    /// perspective without setting the color of each layer.
+    ///
+    /// The color of a layer gets blended towards `color` by an amount growing with the distance
+    /// of the layer behind the neutral depth, up to `strength` at the far depth. Layers at or in
+    /// front of the neutral depth keep their color, and so do layers with the
+    /// [`NO_ATMOSPHERE`](ParallaxFlags::NO_ATMOSPHERE) flag. The alpha of the layers is kept.
+    ///
+    /// ## Panics
+    /// Panics if `strength` is not within `0.0..=1.0`.
+    #[inline]
+    #[must_use]
+    pub fn set_atmosphere(mut self, color: Color, strength: f32) -> Self {
+        if !(0.0..=1.0).contains(&strength) {
+            panic!("Parallax atmosphere strength should be within 0.0 and 1.0.");
+        }
+
+        self.0.atmosphere = Some((color, strength));
+        Self(self.0)
+    }
+
+    /// Sets whether the parallax layers are drawn with gizmos for debugging, requiring the
+    /// `debug_gizmos` feature.
+    ///
+    /// Every processed layer gets the outline of its bounds drawn at its current position,
+    /// together with a circle at its center. Both are colored by the depth factor of the layer,
+    /// from red for layers moving with the world to blue for layers moving with the camera.
+    /// Without the feature, the gizmo system is not compiled at all.
+    #[cfg(feature = "debug_gizmos")]
+    #[inline]
+    #[must_use]
+    pub fn set_debug_gizmos(mut self, debug_gizmos: bool) -> Self {
+        self.0.debug_gizmos = debug_gizmos;
+        Self(self.0)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::{ParallaxCamera, ParallaxLayerData, ParallaxLayerProcessed};
+    use bevy::{
+        asset::{AssetApp, AssetPath, AssetPlugin},
+        core::TaskPoolPlugin,
+        ecs::system::RunSystemOnce,
+        prelude::{
+            AssetServer, Assets, Color, Entity, FixedUpdate, Image, Mesh, OrthographicProjection,
+            Query, Rect, Rectangle, ResMut, Resource, Transform, Vec2, With,
+        },
+        sprite::Mesh2dHandle,
+        time::{TimePlugin, TimeUpdateStrategy},
+        utils::Duration,
+    };
 
     #[test]
     fn plugin_new() {
@@ -113,9 +498,616 @@ mod tests {
         assert_eq!(plugin.0.far_depth, 1.0);
     }
 
+    #[test]
+    fn plugin_set_pixel_perfect() {
+        let plugin = ParallaxPlugin::default().set_pixel_perfect(2.0, 4);
+        assert_eq!(plugin.0.pixel_grid, Some(0.125));
+    }
+
+    #[test]
+    #[should_panic(expected = "Parallax pixel-perfect")]
+    fn plugin_set_pixel_perfect_panic() {
+        let _ = ParallaxPlugin::default().set_pixel_perfect(1.0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Parallax depth factor bounds")]
+    fn plugin_set_factor_bounds_panic() {
+        let _ = ParallaxPlugin::default().set_factor_bounds(2.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Parallax atmosphere strength")]
+    fn plugin_set_atmosphere_panic() {
+        let _ = ParallaxPlugin::default().set_atmosphere(Color::WHITE, 1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Parallax maximum repeat scale")]
+    fn plugin_set_max_repeat_scale_panic() {
+        let _ = ParallaxPlugin::default().set_max_repeat_scale(0.0, true);
+    }
+
+    #[test]
+    #[should_panic(expected = "Parallax tier epsilon")]
+    fn plugin_set_tier_epsilon_panic() {
+        let _ = ParallaxPlugin::default().set_tier_epsilon(-0.1);
+    }
+
     #[test]
     #[should_panic(expected = "Parallax near depth")]
     fn plugin_new_panic() {
         let _ = ParallaxPlugin::new(1.0, -1.0);
     }
+
+    /// App with the parallax systems, without rendering
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            TimePlugin,
+            AssetPlugin::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            20,
+        )))
+        .init_asset::<Image>()
+        .init_asset::<Mesh>()
+        .init_asset::<ParallaxMaterial>()
+        .add_event::<ParallaxTeleport>()
+        .add_event::<ParallaxLayerReady>()
+        .add_event::<ParallaxLayerLoadFailed>()
+        .insert_resource(ParallaxContext::new(ParallaxConfig::default()))
+        .init_resource::<ParallaxMesh>()
+        .init_resource::<ParallaxSolidImage>()
+        .init_resource::<ParallaxSceneDepth>()
+        .init_resource::<ParallaxClock>()
+        .init_resource::<ParallaxBeatPulse>()
+        .init_resource::<ParallaxCameraVelocity>()
+        .init_resource::<ParallaxDepthRegistry>()
+        .init_resource::<ParallaxMaterialCache>();
+        ParallaxPlugin::add_systems(&mut app);
+        #[cfg(feature = "diagnostics")]
+        ParallaxDiagnostics::register(&mut app);
+
+        app
+    }
+
+    #[test]
+    fn plugin_move_set_ordering() {
+        let mut app = test_app();
+        app.add_systems(
+            PostUpdate,
+            (|mut camera_query: Query<&mut Transform, With<ParallaxCamera>>| {
+                camera_query.single_mut().translation.x += 10.0;
+            })
+            .before(ParallaxSet::Move),
+        );
+
+        let world = &mut app.world;
+        let camera = world
+            .spawn((
+                ParallaxCamera::default(),
+                Transform::default(),
+                OrthographicProjection {
+                    area: Rect::from_center_size(Vec2::ZERO, Vec2::splat(2.0)),
+                    ..default()
+                },
+            ))
+            .id();
+        let image = world.resource_mut::<Assets<Image>>().add(Image::default());
+        let material = world
+            .resource_mut::<Assets<ParallaxMaterial>>()
+            .add(ParallaxMaterial::new(image, Color::WHITE));
+        let layer = world
+            .spawn((
+                Transform::default(),
+                ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+                material,
+            ))
+            .id();
+
+        // The layer follows the camera moved in the same frame, at half the camera movement
+        for _ in 0..3 {
+            app.update();
+            let camera_x = app.world.get::<Transform>(camera).unwrap().translation.x;
+            let layer_x = app.world.get::<Transform>(layer).unwrap().translation.x;
+            assert_eq!(layer_x, camera_x * 0.5);
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct FixedUpdateDepths(Vec<(f32, Option<f32>)>);
+
+    #[test]
+    fn plugin_resolved_depths_in_fixed_update() {
+        let mut app = test_app();
+        app.init_resource::<FixedUpdateDepths>().add_systems(
+            FixedUpdate,
+            |query: Query<&ParallaxLayerData, With<ParallaxLayerProcessed>>,
+             mut depths: ResMut<FixedUpdateDepths>| {
+                for parallax in query.iter() {
+                    let depth = parallax.depth();
+                    depths.0.push((depth.depth(), depth.depth_factor()));
+                }
+            },
+        );
+        app.world.spawn((
+            ParallaxCamera::default(),
+            Transform::default(),
+            OrthographicProjection {
+                area: Rect::from_center_size(Vec2::ZERO, Vec2::splat(2.0)),
+                ..default()
+            },
+        ));
+
+        // The first update only starts the clock, without any fixed steps
+        app.update();
+
+        let world = &mut app.world;
+        let image = world.resource_mut::<Assets<Image>>().add(Image::default());
+        let material = world
+            .resource_mut::<Assets<ParallaxMaterial>>()
+            .add(ParallaxMaterial::new(image, Color::WHITE));
+        world.spawn((
+            Transform::default(),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+            material,
+        ));
+
+        // The layer is processed before the fixed steps of the frame it is first seen in
+        app.update();
+        let depths = &app.world.resource::<FixedUpdateDepths>().0;
+        assert!(!depths.is_empty());
+        assert!(depths.iter().all(|depth| *depth == (-10.0, Some(0.5))));
+    }
+
+    #[test]
+    fn plugin_mask_binding() {
+        let mut app = test_app();
+        let [screen, layer, unmasked] = [
+            (Some("window_mask.png"), MaskSpace::Screen),
+            (Some("window_mask.png"), MaskSpace::Layer),
+            (None, MaskSpace::Screen),
+        ]
+        .map(|(mask_image, mask_space)| {
+            app.world
+                .spawn(ParallaxLayer {
+                    image: "mountains_background.png".into(),
+                    mask_image: mask_image.map(Into::into),
+                    mask_space,
+                    ..default()
+                })
+                .id()
+        });
+        app.world.run_system_once(initial_load_parallax_layers);
+
+        let world = &app.world;
+        let mask = |entity: Entity| {
+            let material = world.get::<Handle<ParallaxMaterial>>(entity).unwrap();
+            let (mask_image, mask_space) = world
+                .resource::<Assets<ParallaxMaterial>>()
+                .get(material)
+                .unwrap()
+                .mask();
+            let mask_path =
+                mask_image.and_then(|image| world.resource::<AssetServer>().get_path(image.id()));
+            (mask_path, mask_space)
+        };
+
+        let mask_path = Some(AssetPath::from("window_mask.png"));
+        assert_eq!(mask(screen), (mask_path.clone(), MaskSpace::Screen));
+        assert_eq!(mask(layer), (mask_path, MaskSpace::Layer));
+        assert_eq!(mask(unmasked), (None, MaskSpace::Screen));
+    }
+
+    #[test]
+    fn plugin_mesh_override() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<Mesh>();
+        let mesh = app
+            .world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Rectangle::new(1.0, 0.5));
+
+        // A mesh inserted before the plugin initializes the resource is kept
+        app.insert_resource(ParallaxMesh::new(mesh.clone()))
+            .init_resource::<ParallaxMesh>();
+        assert_eq!(app.world.resource::<ParallaxMesh>().handle().0, mesh);
+
+        // Layers spawn with the overridden mesh
+        let mut app = test_app();
+        let mesh = app
+            .world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Rectangle::new(1.0, 0.5));
+        app.world
+            .resource_mut::<ParallaxMesh>()
+            .set_handle(mesh.clone());
+        let layer = app
+            .world
+            .spawn(ParallaxLayer {
+                image: "mountains_background.png".into(),
+                ..default()
+            })
+            .id();
+        app.world.run_system_once(initial_load_parallax_layers);
+        assert_eq!(app.world.get::<Mesh2dHandle>(layer).unwrap().0, mesh);
+    }
+
+    #[test]
+    fn plugin_msaa_switch() {
+        use bevy::{
+            prelude::{Msaa, Shader},
+            render::render_resource::{
+                ColorTargetState, ColorWrites, FragmentState, MultisampleState,
+                RenderPipelineDescriptor, TextureFormat, VertexState,
+            },
+            sprite::{Material2d, Material2dKey, Mesh2dPipelineKey},
+        };
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            TimePlugin,
+            AssetPlugin::default(),
+        ))
+        .init_asset::<Image>()
+        .init_asset::<Mesh>()
+        .init_asset::<Shader>()
+        .insert_resource(Msaa::Off)
+        // Registers the material along with `Material2dPlugin::<ParallaxMaterial>`
+        .add_plugins(ParallaxPlugin::default());
+
+        let world = &mut app.world;
+        world.spawn((
+            ParallaxCamera::default(),
+            Transform::default(),
+            OrthographicProjection {
+                area: Rect::from_center_size(Vec2::ZERO, Vec2::splat(2.0)),
+                ..default()
+            },
+        ));
+        let image = world.resource_mut::<Assets<Image>>().add(Image::default());
+        let layer = world
+            .spawn(ParallaxLayer {
+                image: image.into(),
+                ..default()
+            })
+            .id();
+        let layout = Mesh::from(Rectangle::default()).get_mesh_vertex_buffer_layout();
+
+        for msaa in [Msaa::Off, Msaa::Sample4, Msaa::Off, Msaa::Sample4] {
+            app.insert_resource(msaa);
+            app.update();
+
+            // The layer stays processed with its material
+            let world = &app.world;
+            assert!(world.get::<ParallaxLayerProcessed>(layer).is_some());
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let material = world.resource::<Assets<ParallaxMaterial>>().get(material);
+
+            // The pipeline specializes for the sample count, which the material leaves as is
+            let mut descriptor = RenderPipelineDescriptor {
+                label: None,
+                layout: Vec::new(),
+                push_constant_ranges: Vec::new(),
+                vertex: VertexState {
+                    shader: Handle::default(),
+                    shader_defs: Vec::new(),
+                    entry_point: "vertex".into(),
+                    buffers: Vec::new(),
+                },
+                primitive: default(),
+                depth_stencil: None,
+                multisample: MultisampleState {
+                    count: msaa.samples(),
+                    ..default()
+                },
+                fragment: Some(FragmentState {
+                    shader: PARALLAX_SHADER_HANDLE,
+                    shader_defs: Vec::new(),
+                    entry_point: "fragment".into(),
+                    targets: vec![Some(ColorTargetState {
+                        format: TextureFormat::Rgba8UnormSrgb,
+                        blend: None,
+                        write_mask: ColorWrites::ALL,
+                    })],
+                }),
+            };
+            let key = Material2dKey {
+                mesh_key: Mesh2dPipelineKey::from_msaa_samples(msaa.samples()),
+                bind_group_data: BlendMode::from(material.unwrap()),
+            };
+            assert!(ParallaxMaterial::specialize(&mut descriptor, &layout, key).is_ok());
+            assert_eq!(descriptor.multisample.count, msaa.samples());
+        }
+    }
+
+    #[test]
+    fn plugin_image_sources() {
+        let mut app = test_app();
+        let loaded = app
+            .world
+            .resource_mut::<Assets<Image>>()
+            .add(Image::default());
+        let level = 2;
+        let [borrowed, owned, handle] = [
+            ParallaxImageSource::from("mountains_background.png"),
+            format!("level_{level}/trees_background.png").into(),
+            loaded.clone().into(),
+        ]
+        .map(|image| app.world.spawn(ParallaxLayer { image, ..default() }).id());
+        app.world.run_system_once(initial_load_parallax_layers);
+
+        let world = &app.world;
+        let image = |entity: Entity| {
+            let material = world.get::<Handle<ParallaxMaterial>>(entity).unwrap();
+            world
+                .resource::<Assets<ParallaxMaterial>>()
+                .get(material)
+                .unwrap()
+                .image_handle()
+        };
+        let path = |entity: Entity| world.resource::<AssetServer>().get_path(image(entity).id());
+
+        assert_eq!(
+            path(borrowed),
+            Some(AssetPath::from("mountains_background.png"))
+        );
+        assert_eq!(
+            path(owned),
+            Some(AssetPath::from("level_2/trees_background.png"))
+        );
+        // Handles are used as is
+        assert_eq!(image(handle), loaded);
+    }
+
+    #[test]
+    fn plugin_context_from_parts() {
+        let plugin = ParallaxPlugin::new(-5.0, 20.0)
+            .set_neutral_depth(2.0)
+            .set_scale(1.5);
+        let plugin_context = ParallaxContext::new(plugin.0);
+        let context = ParallaxContext::from_parts(-5.0, 2.0, 20.0, 1.5);
+
+        for depth in [-10.0, -5.0, 0.0, 2.0, 7.5, 20.0, 30.0] {
+            assert_eq!(
+                context.resolve_depth(depth.into()),
+                plugin_context.resolve_depth(depth.into())
+            );
+        }
+        assert_eq!(context.resolve_depth(2.0.into()), (0.0, 1.5));
+
+        let default_context = ParallaxContext::new(ParallaxPlugin::default().0);
+        assert_eq!(
+            ParallaxContext::default().resolve_depth(50.0.into()),
+            default_context.resolve_depth(50.0.into())
+        );
+        assert_eq!(
+            ParallaxContext::default().resolve_depth(Depth::from_world(3.0, 0.25)),
+            (3.0, 0.25)
+        );
+    }
+
+    #[cfg(feature = "diagnostics")]
+    #[test]
+    fn plugin_diagnostics() {
+        use bevy::diagnostic::DiagnosticsStore;
+
+        let mut app = test_app();
+        app.world.spawn((
+            ParallaxCamera::default(),
+            Transform::default(),
+            OrthographicProjection::default(),
+        ));
+        app.update();
+        app.update();
+
+        let diagnostics = app.world.resource::<DiagnosticsStore>();
+        for path in [
+            ParallaxDiagnostics::INITIAL_LOAD_LAYERS,
+            ParallaxDiagnostics::PROCESS_LAYERS,
+            ParallaxDiagnostics::MOVE_LAYERS,
+        ] {
+            let diagnostic = diagnostics.get(&path).unwrap();
+            assert_eq!(diagnostic.suffix, "ms");
+            assert!(diagnostic
+                .measurement()
+                .is_some_and(|measurement| measurement.value >= 0.0));
+        }
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn plugin_layer_ron_round_trip() {
+        use bevy::{
+            prelude::AppTypeRegistry,
+            reflect::{
+                serde::{ReflectSerializer, UntypedReflectDeserializer},
+                FromReflect,
+            },
+        };
+        use serde::de::DeserializeSeed;
+
+        let mut app = App::new();
+        ParallaxPlugin::register_types(&mut app);
+        app.register_type::<Color>().register_type::<Vec2>();
+
+        let layer = ParallaxLayer {
+            image: "mountains.png".into(),
+            name: Some("mountains".into()),
+            depth: Depth::from_world_xy(-10.0, 0.75, 0.25),
+            offset: Vec2::new(1.0, -2.0),
+            flags: ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::OFFSET_CAMERA_TOP,
+            tile_scale: Some(Vec2::splat(2.0)),
+            dissolve_image: Some("dissolve_noise.png".into()),
+            lods: vec![ParallaxLod {
+                image: "mountains_half.png".into(),
+                max_depth_factor: 0.5,
+            }],
+            mask_image: Some("window_mask.png".into()),
+            mask_space: MaskSpace::Layer,
+            blend_mode: BlendMode::Additive,
+            order_in_tier: 3,
+            z_bias: 0.5,
+            sdf: true,
+            atlas: Some(ParallaxAtlas::new(4, 2, 12.0)),
+            ..default()
+        };
+
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+        let serialized = ron::to_string(&ReflectSerializer::new(&layer, &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let reflected = UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let deserialized = ParallaxLayer::from_reflect(&*reflected).unwrap();
+
+        assert_eq!(deserialized.image, layer.image);
+        assert_eq!(deserialized.name, layer.name);
+        // The kind of depth round-trips along with its factors
+        assert_eq!(deserialized.depth, layer.depth);
+        assert_eq!(
+            deserialized.depth.depth_factor_xy(),
+            layer.depth.depth_factor_xy()
+        );
+        assert_eq!(deserialized.offset, layer.offset);
+        assert_eq!(deserialized.flags, layer.flags);
+        assert_eq!(deserialized.tile_scale, layer.tile_scale);
+        assert_eq!(deserialized.dissolve_image, layer.dissolve_image);
+        assert_eq!(deserialized.lods, layer.lods);
+        assert_eq!(deserialized.mask_image, layer.mask_image);
+        assert_eq!(deserialized.mask_space, layer.mask_space);
+        assert_eq!(deserialized.blend_mode, layer.blend_mode);
+        assert_eq!(deserialized.order_in_tier, layer.order_in_tier);
+        assert_eq!(deserialized.z_bias, layer.z_bias);
+        assert!(deserialized.sdf);
+        assert_eq!(deserialized.atlas, layer.atlas);
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn plugin_export_ron_round_trip() {
+        use crate::{components::ParallaxSolidLayer, queries::export_parallax_ron};
+        use bevy::{
+            prelude::{AppTypeRegistry, Shader},
+            reflect::{
+                serde::{TypedReflectDeserializer, TypedReflectSerializer},
+                FromReflect,
+            },
+        };
+        use serde::de::DeserializeSeed;
+        use std::any::TypeId;
+
+        let parallax_app = || {
+            let mut app = App::new();
+            app.add_plugins((
+                TaskPoolPlugin::default(),
+                TimePlugin,
+                AssetPlugin::default(),
+            ))
+            .init_asset::<Image>()
+            .init_asset::<Mesh>()
+            .init_asset::<Shader>()
+            .add_plugins(ParallaxPlugin::default())
+            .register_type::<Color>()
+            .register_type::<Vec2>();
+            app.world.spawn((
+                ParallaxCamera::default(),
+                Transform::default(),
+                OrthographicProjection::default(),
+            ));
+            app
+        };
+        let load = |app: &mut App, ron: &str| {
+            let registry = app.world.resource::<AppTypeRegistry>().clone();
+            let registry = registry.read();
+            let registration = registry.get(TypeId::of::<Vec<ParallaxLayer>>()).unwrap();
+            let mut deserializer = ron::Deserializer::from_str(ron).unwrap();
+            let reflected = TypedReflectDeserializer::new(registration, &registry)
+                .deserialize(&mut deserializer)
+                .unwrap();
+            let layers = Vec::<ParallaxLayer>::from_reflect(&*reflected).unwrap();
+
+            app.world.spawn_batch(layers.clone());
+            app.update();
+            layers
+        };
+
+        let layers = vec![
+            ParallaxLayer {
+                image: "sky.png".into(),
+                name: Some("sky".into()),
+                color: Color::rgba(0.5, 0.25, 1.0, 0.75),
+                depth: 80.0.into(),
+                flags: ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS,
+                tile_scale: Some(Vec2::splat(2.0)),
+                ..default()
+            },
+            ParallaxLayer {
+                image: "mountains.png".into(),
+                // Named the same as unnamed layers in debug builds
+                name: Some("Parallax Layer".into()),
+                depth: Depth::from_world_xy(-10.0, 0.75, 0.25),
+                offset: Vec2::new(1.0, -2.0),
+                flags: ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::OFFSET_CAMERA_TOP,
+                dissolve_image: Some("dissolve_noise.png".into()),
+                lods: vec![ParallaxLod {
+                    image: "mountains_half.png".into(),
+                    max_depth_factor: 0.5,
+                }],
+                mask_image: Some("window_mask.png".into()),
+                mask_space: MaskSpace::Layer,
+                blend_mode: BlendMode::Additive,
+                order_in_tier: 3,
+                atlas: Some(ParallaxAtlas::new(4, 2, 12.0)),
+                ..default()
+            },
+            ParallaxLayer {
+                image: "hills.png".into(),
+                ..default()
+            },
+        ];
+        let mut app = parallax_app();
+        let scene = {
+            let registry = app.world.resource::<AppTypeRegistry>().read();
+            let serializer = TypedReflectSerializer::new(&layers, &registry);
+            ron::ser::to_string_pretty(&serializer, default()).unwrap()
+        };
+        load(&mut app, &scene);
+        // Layers without an image path are not exported
+        app.world.spawn(ParallaxSolidLayer::default());
+        app.update();
+
+        // Reloading the exported RON yields the same layers, which export the same RON again
+        let exported = export_parallax_ron(&app.world);
+        let mut reloaded_app = parallax_app();
+        let reloaded = load(&mut reloaded_app, &exported);
+        assert_eq!(format!("{reloaded:?}"), format!("{layers:?}"));
+        assert_eq!(export_parallax_ron(&reloaded_app.world), exported);
+
+        // Changes made to the loaded layers are exported, with the color before the atmosphere
+        let mut layer_query = app.world.query::<&mut ParallaxLayerData>();
+        for mut parallax in layer_query.iter_mut(&mut app.world) {
+            parallax.set_depth(40.0).set_offset(Vec2::X);
+        }
+        for (_, material) in app
+            .world
+            .resource_mut::<Assets<ParallaxMaterial>>()
+            .iter_mut()
+        {
+            material.set_color(Color::RED);
+        }
+        app.world
+            .resource_mut::<ParallaxContext>()
+            .set_atmosphere(Some((Color::BLACK, 1.0)));
+        app.update();
+        let exported = export_parallax_ron(&app.world);
+        let reloaded = load(&mut parallax_app(), &exported);
+        assert!(reloaded.iter().all(|layer| layer.depth == 40.0.into()
+            && layer.offset == Vec2::X
+            && layer.color == Color::RED));
+    }
 }
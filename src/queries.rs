@@ -0,0 +1,739 @@
+#[cfg(feature = "serialize")]
+use crate::components::{
+    BlendMode, ParallaxDefaultName, ParallaxImageSource, ParallaxLayer, ParallaxLod,
+};
+use crate::{
+    components::{ParallaxLayerData, ParallaxLodImages},
+    depth::Depth,
+    material::ParallaxMaterial,
+    resources::ParallaxContext,
+};
+use bevy::{
+    ecs::{query::QueryFilter, system::SystemParam},
+    prelude::{Assets, Entity, Handle, Image, Name, Query, Transform, With, World},
+    utils::HashSet,
+};
+#[cfg(feature = "serialize")]
+use bevy::{
+    prelude::{default, AppTypeRegistry},
+    reflect::serde::TypedReflectSerializer,
+};
+#[cfg(feature = "serialize")]
+use ron::ser::PrettyConfig;
+#[cfg(feature = "serialize")]
+use std::borrow::Cow;
+use std::{cmp::Ordering, fmt::Write};
+
+/// System parameter listing the parallax layers sorted by their resolved depth.
+///
+/// Only processed layers are listed, since the depth of a layer is resolved to a world depth
+/// during processing. Layers at the same depth keep the order in which they are queried.
+///
+/// ## Examples
+/// ```
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn log_draw_order(layers: ParallaxLayers) {
+///     for (layer, depth) in layers.back_to_front() {
+///         bevy::log::info!("{layer:?} at {depth:?}");
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct ParallaxLayers<'w, 's> {
+    query: Query<'w, 's, (Entity, &'static ParallaxLayerData)>,
+}
+
+impl ParallaxLayers<'_, '_> {
+    /// Returns the layers and their resolved depths, from the front most to the back most.
+    pub fn front_to_back(&self) -> impl DoubleEndedIterator<Item = (Entity, Depth)> {
+        sorted_front_to_back(self.query.iter()).into_iter()
+    }
+
+    /// Returns the layers and their resolved depths, from the back most to the front most.
+    pub fn back_to_front(&self) -> impl DoubleEndedIterator<Item = (Entity, Depth)> {
+        self.front_to_back().rev()
+    }
+}
+
+/// Returns the parallax layer with the given name, see [`ParallaxLayer::name`](crate::components::ParallaxLayer::name).
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Entity, Name, Query, With};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn hide_sky(
+///     mut layer_query: Query<&mut ParallaxLayerData>,
+///     named_query: Query<(Entity, &Name), With<ParallaxLayerData>>,
+/// ) {
+///     if let Some(sky) = find_layer_by_name(&named_query, "sky") {
+///         // ...
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn find_layer_by_name(
+    query: &Query<(Entity, &Name), With<ParallaxLayerData>>,
+    name: &str,
+) -> Option<Entity> {
+    query
+        .iter()
+        .find_map(|(entity, layer_name)| (layer_name.as_str() == name).then_some(entity))
+}
+
+/// Returns the front most parallax layer and its resolved depth.
+///
+/// Only processed layers are considered, since the depth of a layer is resolved to a world
+/// depth during processing. Of layers at the same depth, the first one found is returned.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Entity, Query, With};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn highlight_foreground(
+///     layer_query: Query<(Entity, &ParallaxLayerData), With<ParallaxTargetCamera>>,
+/// ) {
+///     if let Some((foreground, depth)) = frontmost_layer(&layer_query) {
+///         // ...
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn frontmost_layer<F: QueryFilter>(
+    query: &Query<(Entity, &ParallaxLayerData), F>,
+) -> Option<(Entity, Depth)> {
+    front_layer_of(query.iter())
+}
+
+/// Returns the back most parallax layer and its resolved depth.
+///
+/// Only processed layers are considered, since the depth of a layer is resolved to a world
+/// depth during processing. Of layers at the same depth, the first one found is returned.
+#[must_use]
+pub fn backmost_layer<F: QueryFilter>(
+    query: &Query<(Entity, &ParallaxLayerData), F>,
+) -> Option<(Entity, Depth)> {
+    back_layer_of(query.iter())
+}
+
+/// Returns the nearest parallax layer at or behind the given depth, such as the layer a player
+/// at that depth stands in front of.
+///
+/// A depth in parallax space is resolved to a world depth with the `context`, like the depth of a
+/// layer. Only processed layers are considered, of which the layer with the greatest world depth
+/// not in front of the given depth is returned. Returns `None` if every layer is in front.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Entity, Query, Res};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn show_prompt(layer_query: Query<(Entity, &ParallaxLayerData)>, context: Res<ParallaxContext>) {
+///     let player_depth = 20.0.into();
+///     if let Some(layer) = nearest_layer_behind(player_depth, &layer_query, &context) {
+///         // ...
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn nearest_layer_behind<F: QueryFilter>(
+    depth: Depth,
+    query: &Query<(Entity, &ParallaxLayerData), F>,
+    context: &ParallaxContext,
+) -> Option<Entity> {
+    let depth = depth.to_world_with_factor(context);
+    let behind = query.iter().filter(|(_, parallax)| parallax.depth <= depth);
+    front_layer_of(behind).map(|(entity, _)| entity)
+}
+
+/// Returns everything computed for a parallax layer as a readable string, for diagnosing why a
+/// layer renders wrong.
+///
+/// Includes the authored and resolved depth and offset, the transform, the sampler of the
+/// texture and the uniforms of the material. Returns `None` if the entity is not a parallax
+/// layer.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Entity, World};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn report_layer(world: &World, layer: Entity) {
+///     if let Some(dump) = debug_dump(world, layer) {
+///         bevy::log::info!("{dump}");
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn debug_dump(world: &World, entity: Entity) -> Option<String> {
+    let parallax = world.get::<ParallaxLayerData>(entity)?;
+    let depth = parallax.depth();
+
+    // Writing to a string cannot fail
+    let mut dump = String::new();
+    let _ = writeln!(dump, "Parallax layer {entity:?}");
+    if let Some(name) = world.get::<Name>(entity) {
+        let _ = writeln!(dump, "  name: {name}");
+    }
+    let _ = writeln!(dump, "  flags: {:?}", parallax.flags());
+    let _ = writeln!(
+        dump,
+        "  depth: authored {:?}, resolved {}, factor {:?}",
+        parallax.authored_depth,
+        depth.depth(),
+        depth.depth_factor()
+    );
+    let _ = writeln!(
+        dump,
+        "  offset: authored {}, resolved {}",
+        parallax.authored_offset, parallax.offset
+    );
+    let _ = writeln!(
+        dump,
+        "  effective factor: {}, boost {}",
+        parallax.effective_factor(),
+        parallax.factor_boost
+    );
+
+    if let Some(transform) = world.get::<Transform>(entity) {
+        let _ = writeln!(
+            dump,
+            "  transform: translation {}, scale {}",
+            transform.translation, transform.scale
+        );
+    }
+
+    let material = world
+        .get::<Handle<ParallaxMaterial>>(entity)
+        .and_then(|material| {
+            world
+                .get_resource::<Assets<ParallaxMaterial>>()?
+                .get(material)
+        });
+    if let Some(material) = material {
+        let image = world
+            .get_resource::<Assets<Image>>()
+            .and_then(|images| images.get(material.image_handle()));
+        if let Some(image) = image {
+            let _ = writeln!(dump, "  image size: {}", image.size());
+            let _ = writeln!(dump, "  sampler: {:?}", image.sampler);
+        }
+        let _ = writeln!(dump, "  material: {material:?}");
+    }
+
+    Some(dump)
+}
+
+/// Returns every distinct texture used by the parallax layers, for deciding which images to
+/// keep loaded.
+///
+/// Includes the image, the dissolve texture and the mask of the material of every layer, along
+/// with the full resolution image and every level of detail of a layer with
+/// [`ParallaxLod`](crate::components::ParallaxLod)s. The images are the ones the layers were
+/// given, not the copies sampled once the layers are processed. Layers still waiting for their
+/// material, see [`ParallaxLayer`](crate::components::ParallaxLayer), are not included.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Handle, Image, World};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn is_unused(world: &World, image: &Handle<Image>) -> bool {
+///     !parallax_textures_in_use(world).contains(image)
+/// }
+/// ```
+#[must_use]
+pub fn parallax_textures_in_use(world: &World) -> HashSet<Handle<Image>> {
+    let Some(materials) = world.get_resource::<Assets<ParallaxMaterial>>() else {
+        return HashSet::default();
+    };
+
+    let mut textures = HashSet::default();
+    for entity in world.iter_entities() {
+        if !entity.contains::<ParallaxLayerData>() {
+            continue;
+        }
+
+        let material = entity
+            .get::<Handle<ParallaxMaterial>>()
+            .and_then(|material| materials.get(material));
+        let Some(material) = material else {
+            continue;
+        };
+        // The source image, since processing samples a copy of it
+        textures.extend(
+            [
+                Some(material.source_image()),
+                material.dissolve_image(),
+                material.mask().0,
+            ]
+            .into_iter()
+            .flatten()
+            .cloned(),
+        );
+        if let Some(lod_images) = entity.get::<ParallaxLodImages>() {
+            textures.insert(lod_images.base.clone());
+            textures.extend(lod_images.levels.iter().map(|(_, image)| image.clone()));
+        }
+    }
+
+    textures
+}
+
+/// Returns the parallax layers of the world as RON, such as for an in-game editor saving the
+/// changes made to the layers.
+///
+/// Every layer is exported as the [`ParallaxLayer`] it was spawned from, with the image, color,
+/// depth and offset as authored, or as last set on the [`ParallaxLayerData`], and the images,
+/// mask and blend mode of its material. The RON holds a list of layers, which deserializes as a
+/// `Vec<ParallaxLayer>` with the types registered by the
+/// [`ParallaxPlugin`](crate::plugin::ParallaxPlugin), and lists the layers in the order of their
+/// entities.
+///
+/// Layers without an image path, such as solid layers or layers spawned with an image handle
+/// not loaded from a path, are skipped. The camera a layer is bound to is not exported, as the
+/// entity differs between worlds.
+///
+/// Requires the `serialize` feature.
+///
+/// ## Panics
+/// Panics if the types of the [`ParallaxPlugin`](crate::plugin::ParallaxPlugin) are not
+/// registered in the [`AppTypeRegistry`] of the world.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::World;
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn save_level(world: &World) {
+///     let ron = export_parallax_ron(world);
+///     // std::fs::write("level.ron", ron)
+/// }
+/// ```
+#[cfg(feature = "serialize")]
+#[must_use]
+pub fn export_parallax_ron(world: &World) -> String {
+    let image_path = |image: &Handle<Image>| Some(Cow::Owned(image.path()?.to_string()));
+    let materials = world.get_resource::<Assets<ParallaxMaterial>>();
+
+    let mut entities: Vec<_> = world
+        .iter_entities()
+        .filter(|entity| entity.contains::<ParallaxLayerData>())
+        .collect();
+    entities.sort_by_key(|entity| entity.id());
+    let layers: Vec<_> = entities
+        .into_iter()
+        .filter_map(|entity| {
+            let parallax = entity.get::<ParallaxLayerData>()?;
+            let image = match &parallax.image {
+                ParallaxImageSource::Path(path) if !path.is_empty() => path.clone(),
+                ParallaxImageSource::Path(_) => return None,
+                ParallaxImageSource::Handle(image) => image_path(image)?,
+            };
+
+            // Unnamed layers are named in debug builds, which is not part of the layer
+            let name = entity
+                .get::<Name>()
+                .filter(|_| !entity.contains::<ParallaxDefaultName>())
+                .map(|name| Cow::Owned(name.as_str().to_owned()));
+            let lods = entity
+                .get::<ParallaxLodImages>()
+                .map(|lod_images| {
+                    lod_images
+                        .levels
+                        .iter()
+                        .filter_map(|(max_depth_factor, image)| {
+                            Some(ParallaxLod {
+                                image: image_path(image)?,
+                                max_depth_factor: *max_depth_factor,
+                            })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let mut layer = ParallaxLayer {
+                image: ParallaxImageSource::Path(image),
+                name,
+                color: parallax.color,
+                depth: parallax.authored_depth,
+                offset: parallax.authored_offset,
+                flags: parallax.flags,
+                tile_origin: parallax.tile_origin,
+                tile_scale: parallax.tile_scale,
+                pivot: parallax.pivot,
+                lods,
+                offset_space: parallax.offset_space,
+                fade_in: parallax.fade_in,
+                detile_strength: parallax.detile_strength,
+                order_in_tier: parallax.order_in_tier,
+                z_bias: parallax.z_bias,
+                anisotropy: parallax.anisotropy,
+                ignore_global_scale: parallax.ignore_global_scale,
+                sdf: parallax.sdf,
+                atlas: parallax.atlas,
+                ..default()
+            };
+            let material = entity
+                .get::<Handle<ParallaxMaterial>>()
+                .and_then(|material| materials?.get(material));
+            if let Some(material) = material {
+                let (mask_image, mask_space) = material.mask();
+                layer.dissolve_image = material.dissolve_image().and_then(image_path);
+                layer.mask_image = mask_image.and_then(image_path);
+                layer.mask_space = mask_space;
+                layer.blend_mode = BlendMode::from(material);
+                layer.color = material.base_color();
+            }
+
+            Some(layer)
+        })
+        .collect();
+
+    let registry = world.resource::<AppTypeRegistry>().read();
+    ron::ser::to_string_pretty(
+        &TypedReflectSerializer::new(&layers, &registry),
+        PrettyConfig::default(),
+    )
+    .expect("Parallax layers should serialize with the registered parallax types")
+}
+
+/// Returns the front most of the given layers, see [`frontmost_layer`].
+pub(crate) fn front_layer_of<'a>(
+    layers: impl IntoIterator<Item = (Entity, &'a ParallaxLayerData)>,
+) -> Option<(Entity, Depth)> {
+    resolved_depths(layers)
+        .into_iter()
+        .reduce(|front, layer| match layer.1 > front.1 {
+            true => layer,
+            false => front,
+        })
+}
+
+/// Returns the back most of the given layers, see [`backmost_layer`].
+pub(crate) fn back_layer_of<'a>(
+    layers: impl IntoIterator<Item = (Entity, &'a ParallaxLayerData)>,
+) -> Option<(Entity, Depth)> {
+    resolved_depths(layers)
+        .into_iter()
+        .reduce(|back, layer| match layer.1 < back.1 {
+            true => layer,
+            false => back,
+        })
+}
+
+/// Returns the given layers sorted from the front most to the back most, see [`ParallaxLayers`].
+fn sorted_front_to_back<'a>(
+    layers: impl IntoIterator<Item = (Entity, &'a ParallaxLayerData)>,
+) -> Vec<(Entity, Depth)> {
+    let mut sorted = resolved_depths(layers);
+    sorted.sort_by(|lhs, rhs| rhs.1.partial_cmp(&lhs.1).unwrap_or(Ordering::Equal));
+    sorted
+}
+
+/// Depths in parallax space are not comparable to resolved world depths, so they are skipped
+fn resolved_depths<'a>(
+    layers: impl IntoIterator<Item = (Entity, &'a ParallaxLayerData)>,
+) -> Vec<(Entity, Depth)> {
+    layers
+        .into_iter()
+        .map(|(entity, parallax)| (entity, parallax.depth))
+        .filter(|(_, depth)| depth.depth_factor().is_some())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::MaskSpace, flags::ParallaxFlags, resources::ParallaxConfig};
+    use bevy::{
+        ecs::system::SystemState,
+        prelude::{Color, Vec2, Vec3},
+    };
+
+    #[test]
+    fn queries_find_layer_by_name() {
+        let mut world = World::default();
+        let [sky, hills] = ["sky", "hills"].map(|name| {
+            world
+                .spawn((
+                    ParallaxLayerData::new(1.0.into(), Default::default(), ParallaxFlags::NONE),
+                    Name::new(name),
+                ))
+                .id()
+        });
+        // Named entities which are not layers are ignored
+        world.spawn(Name::new("clouds"));
+
+        let mut system_state =
+            SystemState::<Query<(Entity, &Name), With<ParallaxLayerData>>>::new(&mut world);
+        let query = system_state.get(&world);
+
+        assert_eq!(find_layer_by_name(&query, "sky"), Some(sky));
+        assert_eq!(find_layer_by_name(&query, "hills"), Some(hills));
+        assert_eq!(find_layer_by_name(&query, "clouds"), None);
+        assert_eq!(find_layer_by_name(&query, "ground"), None);
+    }
+
+    #[test]
+    fn queries_frontmost_and_backmost_layer() {
+        let mut world = World::default();
+        let mut system_state = SystemState::<Query<(Entity, &ParallaxLayerData)>>::new(&mut world);
+        let layer = |depth: Depth| ParallaxLayerData::new(depth, Vec2::ZERO, ParallaxFlags::NONE);
+
+        // Empty
+        let query = system_state.get(&world);
+        assert_eq!(frontmost_layer(&query), None);
+        assert_eq!(backmost_layer(&query), None);
+
+        // Single
+        let single = world.spawn(layer(Depth::from_world(2.0, 1.0))).id();
+        let query = system_state.get(&world);
+        assert_eq!(
+            frontmost_layer(&query).map(|(entity, _)| entity),
+            Some(single)
+        );
+        assert_eq!(
+            backmost_layer(&query).map(|(entity, _)| entity),
+            Some(single)
+        );
+
+        // Multiple, where layers without a resolved depth are ignored
+        let front = world.spawn(layer(Depth::from_world(10.0, 1.0))).id();
+        let back = world.spawn(layer(Depth::from_world(-12.0, 1.0))).id();
+        world.spawn(layer(Depth::from_parallax(-50.0)));
+        world.spawn(layer(Depth::from_parallax(50.0)));
+        let query = system_state.get(&world);
+        let (entity, depth) = frontmost_layer(&query).unwrap();
+        assert_eq!((entity, depth.depth()), (front, 10.0));
+        let (entity, depth) = backmost_layer(&query).unwrap();
+        assert_eq!((entity, depth.depth()), (back, -12.0));
+    }
+
+    #[test]
+    fn queries_debug_dump() {
+        let mut world = World::default();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<Assets<ParallaxMaterial>>();
+        let image = world.resource_mut::<Assets<Image>>().add(Image::default());
+        let mut material = ParallaxMaterial::new(image, Color::WHITE);
+        material.set_repeat_scale(Vec2::new(3.0, 1.0));
+        let material = world
+            .resource_mut::<Assets<ParallaxMaterial>>()
+            .add(material);
+        let layer = world
+            .spawn((
+                ParallaxLayerData::new(
+                    Depth::from_world(-10.0, 0.5),
+                    Vec2::new(4.0, 2.0),
+                    ParallaxFlags::REPEAT_X_AXIS,
+                ),
+                Transform::from_xyz(4.0, 2.0, -10.0).with_scale(Vec3::new(64.0, 16.0, 1.0)),
+                Name::new("hills"),
+                material,
+            ))
+            .id();
+
+        let dump = debug_dump(&world, layer).unwrap();
+        for expected in [
+            "name: hills",
+            "REPEAT_X_AXIS",
+            "resolved -10, factor Some(0.5)",
+            "translation [4, 2, -10], scale [64, 16, 1]",
+            "sampler: Default",
+            "repeat_scale: Vec2(3.0, 1.0)",
+            "opacity: 1.0",
+        ] {
+            assert!(dump.contains(expected), "{expected:?} not in {dump}");
+        }
+
+        // Entities which are not layers have nothing to dump
+        let other = world.spawn_empty().id();
+        assert_eq!(debug_dump(&world, other), None);
+    }
+
+    #[test]
+    fn queries_nearest_layer_behind() {
+        let mut world = World::default();
+        let mut system_state = SystemState::<Query<(Entity, &ParallaxLayerData)>>::new(&mut world);
+        let context = ParallaxContext::new(ParallaxConfig::default());
+        let layer = |depth: Depth| ParallaxLayerData::new(depth, Vec2::ZERO, ParallaxFlags::NONE);
+
+        // Empty
+        let query = system_state.get(&world);
+        assert_eq!(nearest_layer_behind(0.0.into(), &query, &context), None);
+
+        let front = world.spawn(layer(Depth::from_world(5.0, 2.0))).id();
+        let middle = world.spawn(layer(Depth::from_world(-10.0, 0.5))).id();
+        let back = world.spawn(layer(Depth::from_world(-40.0, 0.2))).id();
+        world.spawn(layer(Depth::from_parallax(50.0)));
+        let query = system_state.get(&world);
+
+        // Between two layers
+        let player = Depth::from_world(0.0, 1.0);
+        assert_eq!(nearest_layer_behind(player, &query, &context), Some(middle));
+        let player = Depth::from_world(-20.0, 1.0);
+        assert_eq!(nearest_layer_behind(player, &query, &context), Some(back));
+
+        // A layer at the same depth counts as behind
+        let player = Depth::from_world(5.0, 1.0);
+        assert_eq!(nearest_layer_behind(player, &query, &context), Some(front));
+
+        // Depths in parallax space are resolved first
+        let player = Depth::from_parallax(0.0).to_world_with_factor(&context);
+        let expected = match player.depth() {
+            depth if depth >= 5.0 => front,
+            depth if depth >= -10.0 => middle,
+            _ => back,
+        };
+        assert_eq!(
+            nearest_layer_behind(0.0.into(), &query, &context),
+            Some(expected)
+        );
+
+        // Every layer in front
+        let player = Depth::from_world(-50.0, 1.0);
+        assert_eq!(nearest_layer_behind(player, &query, &context), None);
+    }
+
+    #[test]
+    fn queries_parallax_textures_in_use() {
+        let mut world = World::default();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<Assets<ParallaxMaterial>>();
+
+        // Nothing in use without layers
+        assert!(parallax_textures_in_use(&world).is_empty());
+
+        let [sky, hills, dissolve, mask, unused] =
+            [(); 5].map(|_| world.resource_mut::<Assets<Image>>().add(Image::default()));
+        let spawn_layer = |world: &mut World, material: ParallaxMaterial| {
+            let material = world
+                .resource_mut::<Assets<ParallaxMaterial>>()
+                .add(material);
+            world.spawn((
+                ParallaxLayerData::new(1.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+                material,
+            ));
+        };
+        spawn_layer(&mut world, ParallaxMaterial::new(sky.clone(), Color::WHITE));
+        spawn_layer(
+            &mut world,
+            ParallaxMaterial::new(hills.clone(), Color::WHITE),
+        );
+        spawn_layer(&mut world, ParallaxMaterial::new(hills.clone(), Color::RED));
+        let mut material = ParallaxMaterial::new(sky.clone(), Color::WHITE);
+        material
+            .set_dissolve_image(Some(dissolve.clone()))
+            .set_mask(Some(mask.clone()), MaskSpace::Screen);
+        spawn_layer(&mut world, material);
+
+        // Materials of entities which are not layers are ignored
+        let material = world
+            .resource_mut::<Assets<ParallaxMaterial>>()
+            .add(ParallaxMaterial::new(unused, Color::WHITE));
+        world.spawn(material);
+
+        let textures = parallax_textures_in_use(&world);
+        assert_eq!(textures, HashSet::from_iter([sky, hills, dissolve, mask]));
+    }
+
+    #[test]
+    fn queries_parallax_textures_in_use_processed() {
+        use crate::{
+            components::{ParallaxCamera, ParallaxLayer, ParallaxLayerProcessed, ParallaxLod},
+            plugin::ParallaxPlugin,
+        };
+        use bevy::{
+            asset::{AssetApp, AssetPlugin},
+            core::TaskPoolPlugin,
+            prelude::{default, App, AssetServer, Mesh, OrthographicProjection, Rect, Shader},
+            time::TimePlugin,
+        };
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            TimePlugin,
+            AssetPlugin::default(),
+        ))
+        .init_asset::<Image>()
+        .init_asset::<Mesh>()
+        .init_asset::<Shader>()
+        .add_plugins(ParallaxPlugin::default());
+
+        let world = &mut app.world;
+        world.spawn((
+            ParallaxCamera::default(),
+            Transform::default(),
+            OrthographicProjection {
+                area: Rect::from_center_size(Vec2::ZERO, Vec2::splat(2.0)),
+                ..default()
+            },
+        ));
+        // Two layers of the same image, and a layer with a level of detail
+        let sky = world.resource_mut::<Assets<Image>>().add(Image::default());
+        let layers = [Color::WHITE, Color::RED].map(|color| {
+            world
+                .spawn(ParallaxLayer {
+                    image: sky.clone().into(),
+                    color,
+                    ..default()
+                })
+                .id()
+        });
+        world.spawn(ParallaxLayer {
+            image: "hills.png".into(),
+            lods: vec![ParallaxLod {
+                image: "hills_half.png".into(),
+                max_depth_factor: 0.5,
+            }],
+            ..default()
+        });
+        app.update();
+
+        let world = &app.world;
+        assert!(layers
+            .iter()
+            .all(|&layer| world.get::<ParallaxLayerProcessed>(layer).is_some()));
+        // The images the layers were given, rather than the copies sampled once processed
+        let asset_server = world.resource::<AssetServer>();
+        let hills = asset_server.load("hills.png");
+        let hills_half = asset_server.load("hills_half.png");
+        assert_eq!(
+            parallax_textures_in_use(world),
+            HashSet::from_iter([sky, hills, hills_half])
+        );
+    }
+
+    #[test]
+    fn queries_parallax_layers() {
+        let mut world = World::default();
+        let mut system_state = SystemState::<ParallaxLayers>::new(&mut world);
+        let layer = |depth: Depth| ParallaxLayerData::new(depth, Vec2::ZERO, ParallaxFlags::NONE);
+
+        // Empty
+        let layers = system_state.get(&world);
+        assert_eq!(layers.front_to_back().count(), 0);
+
+        // Sorted by world depth, where layers without a resolved depth are ignored
+        let middle = world.spawn(layer(Depth::from_world(2.0, 1.0))).id();
+        let back = world.spawn(layer(Depth::from_world(-12.0, 1.0))).id();
+        world.spawn(layer(Depth::from_parallax(50.0)));
+        let front = world.spawn(layer(Depth::from_world(10.0, 1.0))).id();
+        let layers = system_state.get(&world);
+        let front_to_back: Vec<_> = layers.front_to_back().map(|(entity, _)| entity).collect();
+        assert_eq!(front_to_back, [front, middle, back]);
+        let back_to_front: Vec<_> = layers.back_to_front().map(|(entity, _)| entity).collect();
+        assert_eq!(back_to_front, [back, middle, front]);
+        assert_eq!(
+            layers
+                .front_to_back()
+                .next()
+                .map(|(_, depth)| depth.depth()),
+            Some(10.0)
+        );
+    }
+}
@@ -1,7 +1,50 @@
+use crate::{
+    components::{
+        BlendMode, DepthHandle, MaskSpace, OffsetSpace, ParallaxImageSource, ParallaxLayer,
+        ParallaxPixelScroll,
+    },
+    depth::Depth,
+    material::ParallaxMaterial,
+};
 use bevy::{
-    prelude::{Assets, FromWorld, Mesh, Rectangle, Resource, World},
+    log::warn,
+    prelude::{
+        Assets, Color, Entity, FromWorld, Handle, Image, Mesh, Rectangle, Resource, Vec2, Vec4,
+        World,
+    },
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
     sprite::Mesh2dHandle,
+    utils::HashMap,
 };
+use std::{borrow::Cow, sync::Arc};
+
+/// Meaning of the neutral depth, where a layer keeps its place.
+///
+/// ## Examples
+/// ```no_run
+/// # use bevy::prelude::{App, DefaultPlugins};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// App::new().add_plugins((
+///     DefaultPlugins,
+///     ParallaxPlugin::default().set_neutral_semantics(NeutralSemantics::StationaryOnScreen),
+/// ));
+/// ```
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NeutralSemantics {
+    /// Layers at the neutral depth keep their place in world space, moving on screen along
+    /// with the world. Layers behind move slower than the world, and layers in front faster.
+    #[default]
+    StationaryInWorld,
+    /// Layers at the neutral depth keep their place on screen, as if the camera followed the
+    /// neutral plane. The depth factors get reduced by `1.0` before scaling, meaning layers
+    /// move on screen relative to the neutral plane: layers in front move against the camera,
+    /// while layers behind move along with it.
+    StationaryOnScreen,
+}
 
 /// Configuration for parallax effects.
 #[derive(Clone, Copy)]
@@ -11,6 +54,35 @@ pub(crate) struct ParallaxConfig {
     /// The neutral depth plane, interpreted as the 0-plane.
     pub neutral_depth: f32,
     pub far_depth: f32,
+    /// Amount the depth factors get boosted by at the speed boost reference speed.
+    pub speed_boost: f32,
+    /// Camera speed at which the full speed boost is applied.
+    pub speed_boost_reference_speed: f32,
+    /// Size of a screen pixel in world units, when rendering pixel-perfect.
+    pub pixel_grid: Option<f32>,
+    /// Whether identical parallax layers share one material.
+    pub share_materials: bool,
+    pub neutral_semantics: NeutralSemantics,
+    /// Whether invalid parallax layers are skipped when loaded.
+    pub strict: bool,
+    /// Number of tiles per layer per axis above which a warning is logged.
+    pub max_repeat_scale: f32,
+    /// Whether the number of tiles gets clamped to `max_repeat_scale`.
+    pub clamp_repeat_scale: bool,
+    /// Whether the parallax depths of layers are negated, such as for height in a top-down view.
+    pub invert_depth_ordering: bool,
+    /// Distance in world depth between consecutive orders of layers at the same depth.
+    pub tier_epsilon: f32,
+    /// Smallest depth factor, at and beyond the far depth.
+    pub depth_factor_min: f32,
+    /// Largest depth factor, at and before the near depth.
+    pub depth_factor_max: f32,
+    /// Fog color the layers behind the neutral depth get tinted towards, along with the amount
+    /// of tint at the far depth.
+    pub atmosphere: Option<(Color, f32)>,
+    /// Whether the bounds and depth factors of the layers are drawn with gizmos.
+    #[cfg(feature = "debug_gizmos")]
+    pub debug_gizmos: bool,
 }
 
 impl ParallaxConfig {
@@ -24,6 +96,13 @@ impl ParallaxConfig {
     fn convert_depth(config: Self, depth: f32) -> f32 {
         config.neutral_depth - depth
     }
+
+    /// Returns the size of a screen pixel in world units, from the number of screen pixels per
+    /// world unit and the integer scale of the camera.
+    #[inline]
+    pub(crate) fn pixel_grid((pixels_per_unit, scale): (f32, u32)) -> f32 {
+        1.0 / (pixels_per_unit * scale as f32)
+    }
 }
 
 impl Default for ParallaxConfig {
@@ -34,13 +113,97 @@ impl Default for ParallaxConfig {
             near_depth: -10.0,
             neutral_depth: 0.0,
             far_depth: 100.0,
+            speed_boost: 0.0,
+            speed_boost_reference_speed: 1.0,
+            pixel_grid: None,
+            share_materials: false,
+            neutral_semantics: NeutralSemantics::StationaryInWorld,
+            strict: false,
+            max_repeat_scale: 1024.0,
+            clamp_repeat_scale: false,
+            invert_depth_ordering: false,
+            tier_epsilon: 0.001,
+            depth_factor_min: ParallaxContext::DEPTH_FACTOR_MIN,
+            depth_factor_max: ParallaxContext::DEPTH_FACTOR_MAX,
+            atmosphere: None,
+            #[cfg(feature = "debug_gizmos")]
+            debug_gizmos: false,
+        }
+    }
+}
+
+/// Near, neutral and far depths in parallax depth and the scale of the parallax effect, which
+/// can be registered on the [`ParallaxContext`] under a name and selected at runtime.
+///
+/// Defaults to the same values as the default [`ParallaxPlugin`](crate::plugin::ParallaxPlugin).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParallaxProfile {
+    /// Depth of the nearest layers, see [`ParallaxPlugin::new`](crate::plugin::ParallaxPlugin::new).
+    pub near_depth: f32,
+    /// Depth without a parallax effect, see
+    /// [`ParallaxPlugin::set_neutral_depth`](crate::plugin::ParallaxPlugin::set_neutral_depth).
+    pub neutral_depth: f32,
+    /// Depth of the farthest layers, see [`ParallaxPlugin::new`](crate::plugin::ParallaxPlugin::new).
+    pub far_depth: f32,
+    /// Scale of the parallax effect, see
+    /// [`ParallaxPlugin::set_scale`](crate::plugin::ParallaxPlugin::set_scale).
+    pub scale: f32,
+}
+
+impl Default for ParallaxProfile {
+    #[inline]
+    fn default() -> Self {
+        let config = ParallaxConfig::default();
+        Self {
+            near_depth: config.near_depth,
+            neutral_depth: config.neutral_depth,
+            far_depth: config.far_depth,
+            scale: config.scale,
         }
     }
 }
 
+/// Named profiles of a [`ParallaxContext`] and the name of the one last selected.
+#[derive(Clone, Default)]
+struct ParallaxProfiles {
+    profiles: HashMap<Cow<'static, str>, ParallaxProfile>,
+    active: Option<Cow<'static, str>>,
+}
+
 /// Context holding the parallax configuration.
+///
+/// The configuration can be modified at runtime, which causes every parallax layer to be
+/// processed again.
+///
+/// ## Profiles
+/// Different parallax feels, such as per zone of a game, can be registered as named
+/// [`ParallaxProfile`]s with [`add_profile`](ParallaxContext::add_profile). Selecting a profile
+/// with [`select_profile`](ParallaxContext::select_profile) applies its depths and scale to the
+/// context, which recomputes the depth factors of the layers without recreating them. The
+/// profile applies to every parallax camera.
+///
+/// ```
+/// # use bevy::prelude::ResMut;
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn enter_cave(mut context: ResMut<ParallaxContext>) {
+///     if context.active_profile() != Some("cave") {
+///         context.select_profile("cave");
+///     }
+/// }
+/// ```
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::ResMut;
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn exaggerate_parallax(mut context: ResMut<ParallaxContext>) {
+///     context.set_scale(2.0).set_neutral_depth(10.0);
+/// }
+/// ```
 #[derive(Resource)]
-pub(crate) struct ParallaxContext(ParallaxConfig);
+pub struct ParallaxContext(ParallaxConfig, ParallaxProfiles);
 
 impl ParallaxContext {
     pub(crate) const DEPTH_FACTOR_MIN: f32 = 0.0;
@@ -48,48 +211,1061 @@ impl ParallaxContext {
 
     #[inline]
     #[must_use]
-    pub fn new(mut config: ParallaxConfig) -> Self {
-        // Adjust the near and far depths relative to the neutral depth if needed.
-        if config.near_depth < config.far_depth {
-            config.near_depth = ParallaxConfig::convert_depth(config, config.near_depth);
-            config.far_depth = ParallaxConfig::convert_depth(config, config.far_depth);
+    pub(crate) fn new(mut config: ParallaxConfig) -> Self {
+        // The near depth lies in front of the far depth, with a smaller parallax depth
+        if config.near_depth > config.far_depth {
+            warn!(
+                "Parallax near depth {} lies behind far depth {}, swapping them.",
+                config.near_depth, config.far_depth
+            );
+            std::mem::swap(&mut config.near_depth, &mut config.far_depth);
+        }
+        config.neutral_depth =
+            clamp_neutral_depth(config.neutral_depth, config.near_depth, config.far_depth);
+
+        // Adjust the near and far depths relative to the neutral depth
+        config.near_depth = ParallaxConfig::convert_depth(config, config.near_depth);
+        config.far_depth = ParallaxConfig::convert_depth(config, config.far_depth);
+
+        Self(config, ParallaxProfiles::default())
+    }
+
+    /// Creates a new `ParallaxContext` from the near, neutral and far depths in parallax depth
+    /// and the scale, like a [`ParallaxPlugin`](crate::plugin::ParallaxPlugin) with these
+    /// settings. Useful for reproducing the depth math without an app, see
+    /// [`resolve_depth`](ParallaxContext::resolve_depth).
+    ///
+    /// ## Panics
+    /// Panics if `near_depth` is not less than `far_depth`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use bevy_parallaxation2d::prelude::*;
+    ///
+    /// let context = ParallaxContext::from_parts(-10.0, 0.0, 100.0, 1.0);
+    /// let (world_depth, depth_factor) = context.resolve_depth(50.0.into());
+    /// assert_eq!(world_depth, -50.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_parts(near_depth: f32, neutral_depth: f32, far_depth: f32, scale: f32) -> Self {
+        if near_depth >= far_depth {
+            panic!("Parallax near depth should be less than far depth.");
+        }
+
+        Self::new(ParallaxConfig {
+            scale,
+            near_depth,
+            neutral_depth,
+            far_depth,
+            ..Default::default()
+        })
+    }
+
+    /// Resolves a depth to the world depth and the depth factor of a layer at that depth.
+    ///
+    /// A depth in world space is returned as is.
+    #[inline]
+    #[must_use]
+    pub fn resolve_depth(&self, depth: Depth) -> (f32, f32) {
+        let depth = depth.to_world_with_factor(self);
+        // The factor is always set after resolving to world space
+        (depth.depth(), depth.depth_factor().unwrap_or_default())
+    }
+
+    /// Returns the scale of the parallax effect.
+    #[inline]
+    #[must_use]
+    pub fn scale(&self) -> f32 {
+        self.0.scale
+    }
+
+    /// Sets the scale of the parallax effect.
+    ///
+    /// See [`ParallaxPlugin::set_scale`](crate::plugin::ParallaxPlugin::set_scale).
+    #[inline]
+    pub fn set_scale(&mut self, scale: f32) -> &mut Self {
+        self.0.scale = scale;
+        self
+    }
+
+    /// Returns the neutral depth of the parallax effect.
+    #[inline]
+    #[must_use]
+    pub fn neutral_depth(&self) -> f32 {
+        self.0.neutral_depth
+    }
+
+    /// Sets the neutral depth of the parallax effect.
+    ///
+    /// The near and far depths keep their parallax depth values, meaning they move along with
+    /// the neutral depth in world space. A neutral depth outside of the near and far depths is
+    /// clamped to them.
+    ///
+    /// See [`ParallaxPlugin::set_neutral_depth`](crate::plugin::ParallaxPlugin::set_neutral_depth).
+    #[inline]
+    pub fn set_neutral_depth(&mut self, neutral_depth: f32) -> &mut Self {
+        // The parallax depths of the near and far depths, from their world depths
+        let near_depth = self.0.neutral_depth - self.0.near_depth;
+        let far_depth = self.0.neutral_depth - self.0.far_depth;
+        let neutral_depth = clamp_neutral_depth(neutral_depth, near_depth, far_depth);
+
+        let shift = neutral_depth - self.0.neutral_depth;
+        self.0.near_depth += shift;
+        self.0.far_depth += shift;
+        self.0.neutral_depth = neutral_depth;
+        self
+    }
+
+    /// Returns the speed boost of the parallax effect and the camera speed it is fully applied at.
+    #[inline]
+    #[must_use]
+    pub fn speed_boost(&self) -> (f32, f32) {
+        (self.0.speed_boost, self.0.speed_boost_reference_speed)
+    }
+
+    /// Sets the speed boost of the parallax effect.
+    ///
+    /// See [`ParallaxPlugin::set_speed_boost`](crate::plugin::ParallaxPlugin::set_speed_boost).
+    #[inline]
+    pub fn set_speed_boost(&mut self, speed_boost: f32, reference_speed: f32) -> &mut Self {
+        self.0.speed_boost = speed_boost;
+        self.0.speed_boost_reference_speed = reference_speed;
+        self
+    }
+
+    /// Registers a named profile, replacing any profile with the same name.
+    ///
+    /// ## Panics
+    /// Panics if the near depth of the profile is not less than its far depth.
+    #[inline]
+    pub fn add_profile(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        profile: ParallaxProfile,
+    ) -> &mut Self {
+        if profile.near_depth >= profile.far_depth {
+            panic!("Parallax near depth should be less than far depth.");
         }
 
-        Self(config)
+        self.1.profiles.insert(name.into(), profile);
+        self
     }
 
-    /// Converts a given depth between parallax depth and world depth
+    /// Returns the profile registered under the given name.
     #[inline]
     #[must_use]
-    pub fn convert_depth(&self, depth: f32) -> f32 {
+    pub fn profile(&self, name: &str) -> Option<ParallaxProfile> {
+        self.1.profiles.get(name).copied()
+    }
+
+    /// Returns the name of the profile selected last, if any.
+    #[inline]
+    #[must_use]
+    pub fn active_profile(&self) -> Option<&str> {
+        self.1.active.as_deref()
+    }
+
+    /// Applies the depths and scale of the profile registered under the given name, which
+    /// causes every parallax layer to be processed again. Other settings are kept.
+    ///
+    /// Returns `false`, leaving the context unchanged, if no such profile is registered.
+    #[inline]
+    pub fn select_profile(&mut self, name: &str) -> bool {
+        let Some((name, &profile)) = self.1.profiles.get_key_value(name) else {
+            return false;
+        };
+
+        self.0 = Self::new(ParallaxConfig {
+            scale: profile.scale,
+            near_depth: profile.near_depth,
+            neutral_depth: profile.neutral_depth,
+            far_depth: profile.far_depth,
+            ..self.0
+        })
+        .0;
+        self.1.active = Some(name.clone());
+        true
+    }
+
+    /// Returns the size of a screen pixel in world units, if rendering pixel-perfect.
+    #[inline]
+    #[must_use]
+    pub fn pixel_grid(&self) -> Option<f32> {
+        self.0.pixel_grid
+    }
+
+    /// Sets the pixel-perfect rendering mode, or disables it with `None`.
+    ///
+    /// See [`ParallaxPlugin::set_pixel_perfect`](crate::plugin::ParallaxPlugin::set_pixel_perfect).
+    #[inline]
+    pub fn set_pixel_perfect(&mut self, pixel_perfect: Option<(f32, u32)>) -> &mut Self {
+        self.0.pixel_grid = pixel_perfect.map(ParallaxConfig::pixel_grid);
+        self
+    }
+
+    /// Returns the meaning of the neutral depth.
+    #[inline]
+    #[must_use]
+    pub fn neutral_semantics(&self) -> NeutralSemantics {
+        self.0.neutral_semantics
+    }
+
+    /// Sets the meaning of the neutral depth.
+    ///
+    /// See [`ParallaxPlugin::set_neutral_semantics`](crate::plugin::ParallaxPlugin::set_neutral_semantics).
+    #[inline]
+    pub fn set_neutral_semantics(&mut self, neutral_semantics: NeutralSemantics) -> &mut Self {
+        self.0.neutral_semantics = neutral_semantics;
+        self
+    }
+
+    /// Returns whether identical parallax layers share one material.
+    ///
+    /// See [`ParallaxPlugin::set_share_materials`](crate::plugin::ParallaxPlugin::set_share_materials).
+    #[inline]
+    #[must_use]
+    pub fn share_materials(&self) -> bool {
+        self.0.share_materials
+    }
+
+    /// Returns whether invalid parallax layers are skipped when loaded.
+    ///
+    /// See [`ParallaxPlugin::set_strict`](crate::plugin::ParallaxPlugin::set_strict).
+    #[inline]
+    #[must_use]
+    pub fn strict(&self) -> bool {
+        self.0.strict
+    }
+
+    /// Returns the number of tiles per layer per axis above which a warning is logged, and
+    /// whether the number of tiles gets clamped to it.
+    ///
+    /// See [`ParallaxPlugin::set_max_repeat_scale`](crate::plugin::ParallaxPlugin::set_max_repeat_scale).
+    #[inline]
+    #[must_use]
+    pub fn max_repeat_scale(&self) -> (f32, bool) {
+        (self.0.max_repeat_scale, self.0.clamp_repeat_scale)
+    }
+
+    /// Sets the number of tiles per layer per axis above which a warning is logged, and
+    /// whether the number of tiles gets clamped to it.
+    ///
+    /// See [`ParallaxPlugin::set_max_repeat_scale`](crate::plugin::ParallaxPlugin::set_max_repeat_scale).
+    #[inline]
+    pub fn set_max_repeat_scale(&mut self, max_repeat_scale: f32, clamp: bool) -> &mut Self {
+        self.0.max_repeat_scale = max_repeat_scale;
+        self.0.clamp_repeat_scale = clamp;
+        self
+    }
+
+    /// Returns the repeat scale to use, if the given repeat scale exceeds the maximum. The
+    /// returned repeat scale is clamped to the maximum when clamping is enabled.
+    #[inline]
+    #[must_use]
+    pub(crate) fn limit_repeat_scale(&self, repeat_scale: Vec2) -> Option<Vec2> {
+        if repeat_scale
+            .cmple(Vec2::splat(self.0.max_repeat_scale))
+            .all()
+        {
+            return None;
+        }
+
+        Some(match self.0.clamp_repeat_scale {
+            true => repeat_scale.min(Vec2::splat(self.0.max_repeat_scale)),
+            false => repeat_scale,
+        })
+    }
+
+    /// Returns the multiplier applied to the depth factors at the given camera speed.
+    ///
+    /// The multiplier is `1.0 + speed_boost * normalized_speed`, where the normalized speed is
+    /// the camera speed relative to the reference speed, clamped to at most `1.0`.
+    #[inline]
+    #[must_use]
+    pub(crate) fn speed_boost_multiplier(&self, camera_speed: f32) -> f32 {
+        if self.0.speed_boost_reference_speed <= 0.0 {
+            return 1.0;
+        }
+
+        let normalized_speed = (camera_speed / self.0.speed_boost_reference_speed).min(1.0);
+        1.0 + self.0.speed_boost * normalized_speed
+    }
+
+    /// Returns whether the depth ordering of the layers is inverted.
+    ///
+    /// See [`ParallaxPlugin::set_invert_depth_ordering`](crate::plugin::ParallaxPlugin::set_invert_depth_ordering).
+    #[inline]
+    #[must_use]
+    pub fn invert_depth_ordering(&self) -> bool {
+        self.0.invert_depth_ordering
+    }
+
+    /// Sets whether the depth ordering of the layers is inverted.
+    ///
+    /// See [`ParallaxPlugin::set_invert_depth_ordering`](crate::plugin::ParallaxPlugin::set_invert_depth_ordering).
+    #[inline]
+    pub fn set_invert_depth_ordering(&mut self, invert_depth_ordering: bool) -> &mut Self {
+        self.0.invert_depth_ordering = invert_depth_ordering;
+        self
+    }
+
+    /// Returns the distance in world depth between consecutive orders of layers at the same
+    /// depth.
+    ///
+    /// See [`ParallaxPlugin::set_tier_epsilon`](crate::plugin::ParallaxPlugin::set_tier_epsilon).
+    #[inline]
+    #[must_use]
+    pub fn tier_epsilon(&self) -> f32 {
+        self.0.tier_epsilon
+    }
+
+    /// Sets the distance in world depth between consecutive orders of layers at the same
+    /// depth.
+    ///
+    /// See [`ParallaxPlugin::set_tier_epsilon`](crate::plugin::ParallaxPlugin::set_tier_epsilon).
+    #[inline]
+    pub fn set_tier_epsilon(&mut self, tier_epsilon: f32) -> &mut Self {
+        self.0.tier_epsilon = tier_epsilon;
+        self
+    }
+
+    /// Returns the smallest and largest depth factor of a layer, before scaling.
+    ///
+    /// See [`ParallaxPlugin::set_factor_bounds`](crate::plugin::ParallaxPlugin::set_factor_bounds).
+    #[inline]
+    #[must_use]
+    pub fn factor_bounds(&self) -> (f32, f32) {
+        (self.0.depth_factor_min, self.0.depth_factor_max)
+    }
+
+    /// Sets the smallest and largest depth factor of a layer, before scaling.
+    ///
+    /// See [`ParallaxPlugin::set_factor_bounds`](crate::plugin::ParallaxPlugin::set_factor_bounds).
+    #[inline]
+    pub fn set_factor_bounds(&mut self, min: f32, max: f32) -> &mut Self {
+        self.0.depth_factor_min = min;
+        self.0.depth_factor_max = max;
+        self
+    }
+
+    /// Returns the fog color the layers get tinted towards, along with the amount of tint at the
+    /// far depth.
+    ///
+    /// See [`ParallaxPlugin::set_atmosphere`](crate::plugin::ParallaxPlugin::set_atmosphere).
+    #[inline]
+    #[must_use]
+    pub fn atmosphere(&self) -> Option<(Color, f32)> {
+        self.0.atmosphere
+    }
+
+    /// Sets the fog color the layers get tinted towards, along with the amount of tint at the
+    /// far depth. If `None`, the layers are not tinted.
+    ///
+    /// See [`ParallaxPlugin::set_atmosphere`](crate::plugin::ParallaxPlugin::set_atmosphere).
+    #[inline]
+    pub fn set_atmosphere(&mut self, atmosphere: Option<(Color, f32)>) -> &mut Self {
+        self.0.atmosphere = atmosphere;
+        self
+    }
+
+    /// Returns the fog color and the amount a layer at the given world depth gets tinted
+    /// towards it, growing from nothing at the neutral depth to the full strength at the far
+    /// depth. Layers in front of the neutral depth are not tinted.
+    #[inline]
+    #[must_use]
+    pub(crate) fn atmosphere_at(&self, world_depth: f32) -> Option<(Color, f32)> {
+        let (fog_color, strength) = self.0.atmosphere?;
+        if self.0.far_depth >= 0.0 {
+            return None;
+        }
+
+        // The world depth is the distance to the neutral depth, negative behind it
+        let amount = (world_depth / self.0.far_depth).clamp(0.0, 1.0) * strength;
+        Some((fog_color, amount))
+    }
+
+    /// Converts a given depth of a layer between parallax depth and world depth, negating the
+    /// depth first when the depth ordering is inverted.
+    #[inline]
+    #[must_use]
+    pub(crate) fn convert_depth(&self, depth: f32) -> f32 {
+        let depth = match self.0.invert_depth_ordering {
+            true => -depth,
+            false => depth,
+        };
         ParallaxConfig::convert_depth(self.0, depth)
     }
 
     #[inline]
     #[must_use]
-    pub fn calculate_depth_factor(&self, world_depth: f32) -> f32 {
+    pub(crate) fn calculate_depth_factor(&self, world_depth: f32) -> f32 {
+        self.calculate_unscaled_depth_factor(world_depth) * self.0.scale
+    }
+
+    /// Returns the depth factor at a world depth as if the scale were `1.0`.
+    #[inline]
+    #[must_use]
+    pub(crate) fn calculate_unscaled_depth_factor(&self, world_depth: f32) -> f32 {
         let factor = if world_depth <= self.0.far_depth {
-            Self::DEPTH_FACTOR_MIN
+            self.0.depth_factor_min
         } else if world_depth >= self.0.near_depth {
-            Self::DEPTH_FACTOR_MAX
+            self.0.depth_factor_max
         } else {
-            self.0.near_depth / (self.0.near_depth - world_depth)
+            (self.0.near_depth / (self.0.near_depth - world_depth))
+                .clamp(self.0.depth_factor_min, self.0.depth_factor_max)
         };
 
-        factor * self.0.scale
+        match self.0.neutral_semantics {
+            NeutralSemantics::StationaryInWorld => factor,
+            NeutralSemantics::StationaryOnScreen => factor - 1.0,
+        }
+    }
+}
+
+/// Clamps a neutral depth to the near and far depths, all in parallax depth. A neutral depth
+/// outside of them would give layers between them negative or unbounded depth factors.
+#[inline]
+#[must_use]
+fn clamp_neutral_depth(neutral_depth: f32, near_depth: f32, far_depth: f32) -> f32 {
+    let clamped_depth = neutral_depth.clamp(near_depth, far_depth);
+    if clamped_depth != neutral_depth {
+        warn!(
+            "Parallax neutral depth {neutral_depth} lies outside of the near depth {near_depth} \
+            and far depth {far_depth}, clamping it to {clamped_depth}."
+        );
+    }
+
+    clamped_depth
+}
+
+impl Default for ParallaxContext {
+    /// The same configuration as the default [`ParallaxPlugin`](crate::plugin::ParallaxPlugin).
+    #[inline]
+    fn default() -> Self {
+        Self::new(ParallaxConfig::default())
+    }
+}
+
+/// Depth composition of the visible parallax layers, updated each frame.
+///
+/// Holds the average depth factor of the parallax layers, where each layer is weighted by its
+/// area visible on screen. A value close to `0.0` means the view is dominated by far layers,
+/// while larger values mean near layers take up more of the view. Useful for driving effects
+/// outside of the parallax system, such as the spatial audio or a low-pass filter.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::Res;
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn muffle_audio(scene_depth: Res<ParallaxSceneDepth>) {
+///     let cutoff = 500.0 + 4000.0 * scene_depth.average_depth_factor().min(1.0);
+///     // ...
+/// }
+/// ```
+#[derive(Resource, Default, Clone, Copy, Debug)]
+pub struct ParallaxSceneDepth(pub(crate) f32);
+
+impl ParallaxSceneDepth {
+    /// Returns the on-screen area weighted average depth factor of the visible layers.
+    ///
+    /// Is `0.0` when no layer is visible.
+    #[inline]
+    #[must_use]
+    pub fn average_depth_factor(&self) -> f32 {
+        self.0
+    }
+}
+
+/// Velocity of the parallax cameras as seen by the parallax systems, in world units per second.
+///
+/// Updated once per frame in `PostUpdate`, before the layers get moved, from the movement of
+/// each [`ParallaxCamera`](crate::components::ParallaxCamera) since the last frame. The velocity
+/// is zero on the first frame of a camera and when the camera got teleported, see
+/// [`ParallaxTeleport`](crate::events::ParallaxTeleport).
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::Res;
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn speed_lines(camera_velocity: Res<ParallaxCameraVelocity>) {
+///     if camera_velocity.velocity().length() > 500.0 {
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Resource, Default, Clone, Debug)]
+pub struct ParallaxCameraVelocity {
+    primary: Vec2,
+    cameras: HashMap<Entity, Vec2>,
+}
+
+impl ParallaxCameraVelocity {
+    /// Returns the velocity of the primary parallax camera.
+    #[inline]
+    #[must_use]
+    pub fn velocity(&self) -> Vec2 {
+        self.primary
+    }
+
+    /// Returns the velocity of the given parallax camera, if it is a parallax camera.
+    #[inline]
+    #[must_use]
+    pub fn camera_velocity(&self, camera: Entity) -> Option<Vec2> {
+        self.cameras.get(&camera).copied()
+    }
+
+    /// Sets the velocities of all parallax cameras, and which of them is the primary camera.
+    #[inline]
+    pub(crate) fn set(&mut self, primary: Entity, cameras: HashMap<Entity, Vec2>) {
+        self.primary = cameras.get(&primary).copied().unwrap_or(Vec2::ZERO);
+        self.cameras = cameras;
+    }
+}
+
+/// Clock driving the time-based parallax effects, advanced each frame from [`Time`](bevy::prelude::Time).
+///
+/// All time-based systems of the crate read this clock instead of `Time`, such that parallax
+/// effects can be paused or time-scaled together, independently of the game clock.
+///
+/// By default the clock also stops advancing while the primary window is unfocused or minimized,
+/// see [`pause_when_unfocused`](ParallaxClock::pause_when_unfocused).
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::ResMut;
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn slow_motion(mut clock: ResMut<ParallaxClock>) {
+///     clock.speed = 0.25;
+/// }
+///
+/// fn freeze(mut clock: ResMut<ParallaxClock>) {
+///     clock.paused = true;
+/// }
+/// ```
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ParallaxClock {
+    elapsed: f32,
+    delta: f32,
+    /// Multiplier applied to the time advanced each frame.
+    pub speed: f32,
+    /// Whether the clock is paused.
+    pub paused: bool,
+    /// Whether the clock stops advancing while the primary window is unfocused.
+    pub pause_when_unfocused: bool,
+}
+
+impl ParallaxClock {
+    /// Returns the total time advanced by the clock, in seconds.
+    #[inline]
+    #[must_use]
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Returns the time advanced by the clock during the last frame, in seconds.
+    #[inline]
+    #[must_use]
+    pub fn delta(&self) -> f32 {
+        self.delta
+    }
+
+    /// Advances the clock by `delta` seconds of real time.
+    #[inline]
+    pub(crate) fn advance(&mut self, delta: f32) {
+        self.delta = match self.paused {
+            true => 0.0,
+            false => delta * self.speed,
+        };
+        self.elapsed += self.delta;
+    }
+}
+
+impl Default for ParallaxClock {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            elapsed: 0.0,
+            delta: 0.0,
+            speed: 1.0,
+            paused: false,
+            pause_when_unfocused: true,
+        }
+    }
+}
+
+/// Decaying impulse offsetting the parallax layers, such as to pulse the scene on the beat of the
+/// music.
+///
+/// An impulse set by [`fire`](ParallaxBeatPulse::fire), such as from beat detection, offsets
+/// every layer by the [`offset`](ParallaxBeatPulse::offset) of the pulse scaled by its depth
+/// factor, such that near layers pulse more than distant ones. The offset starts at the full
+/// strength of the impulse and decays quadratically to zero over the decay time, as
+/// `strength * (1 - t / decay_time)²`, following the [`ParallaxClock`]. Locked and camera-local
+/// axes are not affected.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Event, EventReader, ResMut, Vec2};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// # #[derive(Event)]
+/// # struct Beat;
+/// fn pulse_on_beat(mut beats: EventReader<Beat>, mut pulse: ResMut<ParallaxBeatPulse>) {
+///     if beats.read().last().is_some() {
+///         pulse.fire(Vec2::new(0.0, -6.0));
+///     }
+/// }
+/// ```
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ParallaxBeatPulse {
+    /// Offset at depth factor `1.0` at the start of the last impulse, in world units.
+    pub strength: Vec2,
+    /// Time for the offset to decay to zero, in seconds.
+    pub decay_time: f32,
+    elapsed: f32,
+}
+
+impl ParallaxBeatPulse {
+    /// Fires a new impulse of the given strength, replacing any decaying impulse.
+    #[inline]
+    pub fn fire(&mut self, strength: Vec2) -> &mut Self {
+        self.strength = strength;
+        self.elapsed = 0.0;
+        self
+    }
+
+    /// Returns the current offset of the pulse at depth factor `1.0`, in world units.
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> Vec2 {
+        if self.decay_time <= 0.0 {
+            return Vec2::ZERO;
+        }
+
+        let remaining = (1.0 - self.elapsed / self.decay_time).max(0.0);
+        self.strength * remaining * remaining
+    }
+
+    /// Advances the decay of the pulse by `delta` seconds.
+    #[inline]
+    pub(crate) fn advance(&mut self, delta: f32) {
+        self.elapsed += delta;
+    }
+}
+
+impl Default for ParallaxBeatPulse {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            strength: Vec2::ZERO,
+            decay_time: 0.25,
+            elapsed: 0.0,
+        }
+    }
+}
+
+/// Animation of the neutral depth of the [`ParallaxContext`], shifting the relative depth and
+/// the depth factor of every layer over time.
+///
+/// The neutral depth is interpolated linearly from its value when the animation starts to the
+/// target, over the duration in seconds of the [`ParallaxClock`]. The near and far depths move
+/// along with the neutral depth, like with [`ParallaxContext::set_neutral_depth`]. Every layer
+/// is laid out again each frame while animating, reusing its processed image, and the resource
+/// is removed once finished. The animation does not mark the context as changed.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::Commands;
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn recede_world(mut commands: Commands) {
+///     commands.insert_resource(ParallaxNeutralDepthAnimation::new(20.0, 3.0));
+/// }
+/// ```
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ParallaxNeutralDepthAnimation {
+    from: Option<f32>,
+    to: f32,
+    elapsed: f32,
+    duration: f32,
+}
+
+impl ParallaxNeutralDepthAnimation {
+    /// Creates an animation towards the given neutral depth over `duration` seconds.
+    #[inline]
+    #[must_use]
+    pub fn new(neutral_depth: f32, duration: f32) -> Self {
+        Self {
+            from: None,
+            to: neutral_depth,
+            elapsed: 0.0,
+            duration,
+        }
+    }
+
+    /// Returns whether the animation has reached the target neutral depth.
+    #[inline]
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Advances the animation by `delta` seconds, returning the current neutral depth.
+    ///
+    /// The animation starts from `neutral_depth` the first time it is advanced.
+    #[inline]
+    pub(crate) fn advance(&mut self, delta: f32, neutral_depth: f32) -> f32 {
+        let from = *self.from.get_or_insert(neutral_depth);
+        self.elapsed = (self.elapsed + delta).min(self.duration.max(0.0));
+
+        let progress = match self.duration > 0.0 {
+            true => self.elapsed / self.duration,
+            false => 1.0,
+        };
+        from + (self.to - from) * progress
+    }
+}
+
+/// Registry of depths shared between parallax layers, see [`DepthHandle`].
+#[derive(Resource, Default, Debug)]
+pub struct ParallaxDepthRegistry(Vec<Depth>);
+
+impl ParallaxDepthRegistry {
+    /// Adds a shared depth, returning the handle layers can reference it with.
+    #[inline]
+    pub fn add(&mut self, depth: impl Into<Depth>) -> DepthHandle {
+        self.0.push(depth.into());
+        DepthHandle(self.0.len() - 1)
+    }
+
+    /// Returns the shared depth of the handle.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, handle: DepthHandle) -> Option<Depth> {
+        self.0.get(handle.0).copied()
+    }
+
+    /// Sets the shared depth of the handle, updating every layer referencing it.
+    ///
+    /// ## Panics
+    /// Panics if the handle is not from this registry.
+    #[inline]
+    pub fn set(&mut self, handle: DepthHandle, depth: impl Into<Depth>) {
+        self.0[handle.0] = depth.into();
+    }
+}
+
+/// Parameters of a parallax layer determining the state of its material.
+///
+/// Layers with equal keys render identically, meaning they can share a material. Floats are
+/// compared by their bits.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct ParallaxMaterialKey {
+    image: ParallaxImageSource,
+    color: [u32; 4],
+    depth: (u32, Option<[u32; 2]>),
+    depth_handle: Option<DepthHandle>,
+    offset: [u32; 2],
+    flags: u32,
+    tile_origin: Option<[u32; 2]>,
+    tile_scale: Option<[u32; 2]>,
+    pixel_scroll: Option<[u32; 2]>,
+    dissolve_image: Option<Cow<'static, str>>,
+    lods: Vec<(Cow<'static, str>, u32)>,
+    offset_space: OffsetSpace,
+    mask: (Option<Cow<'static, str>>, MaskSpace),
+    detile_strength: u32,
+    order_in_tier: i32,
+    z_bias: u32,
+    anisotropy: u16,
+    ignore_global_scale: bool,
+    sdf: bool,
+    atlas: Option<(u32, u32, u32, u32)>,
+    target_camera: Option<Entity>,
+    blend_mode: BlendMode,
+}
+
+impl ParallaxMaterialKey {
+    #[inline]
+    #[must_use]
+    pub fn new(
+        layer: &ParallaxLayer,
+        depth_handle: Option<DepthHandle>,
+        pixel_scroll: Option<&ParallaxPixelScroll>,
+    ) -> Self {
+        Self {
+            image: layer.image.clone(),
+            color: layer.color.as_rgba_f32().map(f32::to_bits),
+            depth: (
+                layer.depth.depth().to_bits(),
+                layer
+                    .depth
+                    .depth_factor_xy()
+                    .map(|factor| factor.to_array().map(f32::to_bits)),
+            ),
+            depth_handle,
+            offset: layer.offset.to_array().map(f32::to_bits),
+            flags: layer.flags.bits(),
+            tile_origin: layer
+                .tile_origin
+                .map(|tile_origin| tile_origin.to_array().map(f32::to_bits)),
+            tile_scale: layer
+                .tile_scale
+                .map(|tile_scale| tile_scale.to_array().map(f32::to_bits)),
+            pixel_scroll: pixel_scroll
+                .map(|pixel_scroll| pixel_scroll.pixels_per_unit.to_array().map(f32::to_bits)),
+            dissolve_image: layer.dissolve_image.clone(),
+            lods: layer
+                .lods
+                .iter()
+                .map(|lod| (lod.image.clone(), lod.max_depth_factor.to_bits()))
+                .collect(),
+            offset_space: layer.offset_space,
+            mask: (layer.mask_image.clone(), layer.mask_space),
+            detile_strength: layer.detile_strength.to_bits(),
+            order_in_tier: layer.order_in_tier,
+            z_bias: layer.z_bias.to_bits(),
+            anisotropy: layer.anisotropy,
+            ignore_global_scale: layer.ignore_global_scale,
+            sdf: layer.sdf,
+            atlas: layer.atlas.map(|atlas| {
+                (
+                    atlas.columns,
+                    atlas.rows,
+                    atlas.frame_count,
+                    atlas.fps.to_bits(),
+                )
+            }),
+            target_camera: layer.target_camera,
+            blend_mode: layer.blend_mode,
+        }
+    }
+}
+
+/// Materials shared between identical parallax layers, used when material sharing is enabled.
+///
+/// A material no layer uses anymore is dropped from the cache the next time a layer is loaded.
+#[derive(Resource, Default)]
+pub(crate) struct ParallaxMaterialCache(HashMap<ParallaxMaterialKey, Handle<ParallaxMaterial>>);
+
+impl ParallaxMaterialCache {
+    /// Returns the material shared by layers with the given key, creating it if needed.
+    pub fn get_or_insert_with(
+        &mut self,
+        key: ParallaxMaterialKey,
+        materials: &mut Assets<ParallaxMaterial>,
+        create: impl FnOnce() -> ParallaxMaterial,
+    ) -> Handle<ParallaxMaterial> {
+        // Drop the materials only kept alive by the cache
+        self.0.retain(|_, handle| match handle {
+            Handle::Strong(handle) => Arc::strong_count(handle) > 1,
+            Handle::Weak(_) => false,
+        });
+
+        if let Some(handle) = self.0.get(&key) {
+            return handle.clone();
+        }
+
+        let handle = materials.add(create());
+        self.0.insert(key, handle.clone());
+        handle
+    }
+}
+
+/// Depth in focus, fading the parallax layers by their distance to it.
+///
+/// Layers at the focus depth are fully opaque, while the opacity of other layers decreases by
+/// `falloff` per unit of world depth between the layer and the focus depth, down to `0.0`.
+/// This simulates a depth of field blur, such as for a tilt-shift effect.
+///
+/// Without this resource the opacity of the layers is left as is.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::Commands;
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Layers 10 world units away from the focus are invisible
+///     commands.insert_resource(ParallaxFocusDepth {
+///         depth: 2.0.into(),
+///         falloff: 0.1,
+///     });
+/// }
+/// ```
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct ParallaxFocusDepth {
+    pub depth: Depth,
+    /// Opacity lost per unit of world depth away from the focus depth.
+    pub falloff: f32,
+}
+
+impl ParallaxFocusDepth {
+    /// Returns the opacity of a layer at the given world depth.
+    #[inline]
+    #[must_use]
+    pub(crate) fn opacity(&self, world_depth: f32, context: &ParallaxContext) -> f32 {
+        let focus_depth = self.depth.to_world_with_factor(context).depth();
+        (1.0 - (world_depth - focus_depth).abs() * self.falloff).clamp(0.0, 1.0)
+    }
+}
+
+/// Color grading LUT remapping the final color of every parallax layer, such as for a uniform
+/// color grade across the scene.
+///
+/// The LUT is a horizontal strip of `size` square slices of `size` by `size` pixels, where red
+/// increases to the right within each slice, green increases downwards, and blue increases
+/// from slice to slice. An identity LUT thereby maps every color to itself. The LUT should be
+/// sampled with linear filtering, and be in a linear color format such as `Rgba8Unorm`, for
+/// smooth gradients between its colors. A size of `16` or `32` is common.
+///
+/// Without this resource the colors of the layers are left as is, at no cost.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{AssetServer, Commands, Res};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.insert_resource(ParallaxColorGrading {
+///         lut: asset_server.load("warm_lut.png"),
+///     });
+/// }
+/// ```
+#[derive(Resource, Clone, Debug)]
+pub struct ParallaxColorGrading {
+    /// Strip of slices making up the LUT.
+    pub lut: Handle<Image>,
+}
+
+/// Axis of the camera position a [`ParallaxClearColorGradient`] follows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GradientAxis {
+    /// The X axis.
+    #[default]
+    X,
+    /// The Y axis.
+    Y,
+}
+
+/// Gradient of the [`ClearColor`](bevy::prelude::ClearColor), following the position of the
+/// parallax camera.
+///
+/// The camera position along the axis is mapped from `range` to `0.0..=1.0`, at which the
+/// gradient gets sampled. The `stops` pair such a position with a color, and should be sorted
+/// by position. Between two stops the color is interpolated in linear space, and beyond the
+/// outer stops their color is kept.
+///
+/// ## Examples
+/// ```
+/// # use bevy::prelude::{Color, Commands};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Dawn at the start of the level, dusk at the end
+///     commands.insert_resource(ParallaxClearColorGradient {
+///         stops: vec![
+///             (0.0, Color::rgb(1.0, 0.7, 0.5)),
+///             (0.5, Color::rgb(0.4, 0.7, 1.0)),
+///             (1.0, Color::rgb(0.5, 0.2, 0.4)),
+///         ],
+///         along: GradientAxis::X,
+///         range: (0.0, 4000.0),
+///     });
+/// }
+/// ```
+#[derive(Resource, Clone, Debug, Default)]
+pub struct ParallaxClearColorGradient {
+    pub stops: Vec<(f32, Color)>,
+    pub along: GradientAxis,
+    /// Camera positions at the start and the end of the gradient.
+    pub range: (f32, f32),
+}
+
+impl ParallaxClearColorGradient {
+    /// Returns the color of the gradient at the given camera position, or `None` without stops.
+    #[must_use]
+    pub fn color_at(&self, camera_translation: Vec2) -> Option<Color> {
+        let position = match self.along {
+            GradientAxis::X => camera_translation.x,
+            GradientAxis::Y => camera_translation.y,
+        };
+        let (start, end) = self.range;
+        let t = match start == end {
+            true => 0.0,
+            false => ((position - start) / (end - start)).clamp(0.0, 1.0),
+        };
+
+        let next = self.stops.iter().position(|(stop, _)| *stop > t);
+        let (lhs, rhs) = match next {
+            Some(0) => return self.stops.first().map(|(_, color)| *color),
+            Some(index) => (self.stops[index - 1], self.stops[index]),
+            None => return self.stops.last().map(|(_, color)| *color),
+        };
+
+        let s = (t - lhs.0) / (rhs.0 - lhs.0);
+        let lhs = Vec4::from_array(lhs.1.as_linear_rgba_f32());
+        let rhs = Vec4::from_array(rhs.1.as_linear_rgba_f32());
+        let [r, g, b, a] = lhs.lerp(rhs, s).to_array();
+        Some(Color::rgba_linear(r, g, b, a))
     }
 }
 
 /// Mesh resource used for parallax layers.
+///
+/// By default every layer is drawn on a shared quad. The quad can be replaced with a custom mesh,
+/// such as a subtly curved quad, by inserting this resource before adding the
+/// [`ParallaxPlugin`](crate::plugin::ParallaxPlugin), or by setting the handle at runtime. Only
+/// layers loaded afterwards use the new mesh.
+///
+/// The mesh is scaled to the size of each layer, so it should span from `-0.5` to `0.5` on the
+/// x and y axes. Its UVs should span from `0.0` to `1.0`, with `(0.0, 0.0)` at the top left,
+/// since the texture offsets, the repeating and the mask in layer space are computed from them.
+///
+/// ## Examples
+/// ```no_run
+/// # use bevy::prelude::{App, Assets, DefaultPlugins, Mesh, Rectangle};
+/// use bevy_parallaxation2d::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(DefaultPlugins);
+///
+/// let mesh = app
+///     .world
+///     .resource_mut::<Assets<Mesh>>()
+///     .add(Rectangle::default());
+/// app.insert_resource(ParallaxMesh::new(mesh))
+///     .add_plugins(ParallaxPlugin::default())
+///     .run();
+/// ```
 #[derive(Resource)]
-pub(crate) struct ParallaxMesh(Mesh2dHandle);
+pub struct ParallaxMesh(Mesh2dHandle);
 
 impl ParallaxMesh {
+    /// Creates the resource from a custom mesh.
+    #[inline]
+    #[must_use]
+    pub fn new(mesh: Handle<Mesh>) -> Self {
+        Self(mesh.into())
+    }
+
+    /// Returns the mesh used for newly loaded parallax layers.
     #[inline]
     #[must_use]
     pub fn handle(&self) -> Mesh2dHandle {
         self.0.clone()
     }
+
+    /// Sets the mesh used for newly loaded parallax layers.
+    #[inline]
+    pub fn set_handle(&mut self, mesh: Handle<Mesh>) -> &mut Self {
+        self.0 = mesh.into();
+        self
+    }
 }
 
 impl FromWorld for ParallaxMesh {
@@ -99,6 +1275,32 @@ impl FromWorld for ParallaxMesh {
     }
 }
 
+/// Blank image resource used for the texture of solid color parallax layers, which get their
+/// color from the tint of the material.
+#[derive(Resource)]
+pub(crate) struct ParallaxSolidImage(Handle<Image>);
+
+impl ParallaxSolidImage {
+    #[inline]
+    #[must_use]
+    pub fn handle(&self) -> Handle<Image> {
+        self.0.clone()
+    }
+}
+
+impl FromWorld for ParallaxSolidImage {
+    fn from_world(world: &mut World) -> Self {
+        let mut images = world.get_resource_mut::<Assets<Image>>().unwrap();
+        Self(images.add(Image::new_fill(
+            Extent3d::default(),
+            TextureDimension::D2,
+            &[255; 4],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,14 +1319,64 @@ mod tests {
         let context = ParallaxContext::new(ParallaxConfig {
             scale: -5.0,
             near_depth: 0.0,
-            neutral_depth: -1.0,
+            neutral_depth: 0.5,
             far_depth: 1.0,
+            ..Default::default()
         });
 
         assert_eq!(context.0.scale, -5.0);
-        assert_eq!(context.0.near_depth, -1.0);
-        assert_eq!(context.0.neutral_depth, -1.0);
-        assert_eq!(context.0.far_depth, -2.0);
+        assert_eq!(context.0.near_depth, 0.5);
+        assert_eq!(context.0.neutral_depth, 0.5);
+        assert_eq!(context.0.far_depth, -0.5);
+    }
+
+    #[test]
+    fn resources_new_context_neutral_outside() {
+        let context = |near_depth: f32, neutral_depth: f32, far_depth: f32| {
+            let context = ParallaxContext::new(ParallaxConfig {
+                near_depth,
+                neutral_depth,
+                far_depth,
+                ..Default::default()
+            });
+            (
+                context.0.near_depth,
+                context.0.neutral_depth,
+                context.0.far_depth,
+            )
+        };
+
+        // In front of the near depth, clamped to it
+        assert_eq!(context(0.0, -1.0, 10.0), (0.0, 0.0, -10.0));
+        // Behind the far depth, clamped to it
+        assert_eq!(context(-10.0, 20.0, 10.0), (20.0, 10.0, 0.0));
+        // Inverted near and far depths are swapped
+        assert_eq!(context(10.0, 0.0, -10.0), context(-10.0, 0.0, 10.0));
+
+        // The factors stay within the bounds either way
+        for neutral_depth in [-50.0, 50.0] {
+            let context = ParallaxContext::new(ParallaxConfig {
+                near_depth: -10.0,
+                neutral_depth,
+                far_depth: 10.0,
+                ..Default::default()
+            });
+            for world_depth in [-30.0, -10.0, -5.0, 0.0, 5.0, 10.0, 30.0] {
+                let factor = context.calculate_depth_factor(world_depth);
+                assert!((0.0..=ParallaxContext::DEPTH_FACTOR_MAX).contains(&factor));
+            }
+        }
+
+        // Setting the neutral depth clamps it as well
+        let mut context = ParallaxContext::new(ParallaxConfig::default());
+        context.set_neutral_depth(150.0);
+        assert_eq!(context.neutral_depth(), 100.0);
+        assert_eq!(context.0.near_depth, 110.0);
+        assert_eq!(context.0.far_depth, 0.0);
+        context.set_neutral_depth(-20.0);
+        assert_eq!(context.neutral_depth(), -10.0);
+        assert_eq!(context.0.near_depth, 0.0);
+        assert_eq!(context.0.far_depth, -110.0);
     }
 
     #[test]
@@ -145,4 +1397,181 @@ mod tests {
         assert_eq!(twice, 0.5);
         assert_eq!(half, 2.0);
     }
+
+    #[test]
+    fn resources_calculate_depth_factor_bounds() {
+        let mut context = ParallaxContext::new(ParallaxConfig {
+            neutral_depth: 5.0,
+            scale: 2.0,
+            ..Default::default()
+        });
+        context.set_factor_bounds(0.2, 1.5);
+
+        // Beyond the near and far depths
+        assert_eq!(context.calculate_depth_factor(15.0), 3.0);
+        assert_eq!(context.calculate_depth_factor(-95.0), 0.4);
+        // Between them
+        assert_eq!(context.calculate_depth_factor(7.5), 3.0);
+        assert_eq!(context.calculate_depth_factor(-15.0), 1.0);
+        assert_eq!(context.calculate_unscaled_depth_factor(-90.0), 0.2);
+        assert_eq!(context.factor_bounds(), (0.2, 1.5));
+    }
+
+    #[test]
+    fn resources_speed_boost_multiplier() {
+        let mut context = ParallaxContext::new(ParallaxConfig::default());
+        assert_eq!(context.speed_boost_multiplier(0.0), 1.0);
+        assert_eq!(context.speed_boost_multiplier(50.0), 1.0);
+
+        context.set_speed_boost(0.4, 100.0);
+        assert_eq!(context.speed_boost(), (0.4, 100.0));
+        assert_eq!(context.speed_boost_multiplier(0.0), 1.0);
+        assert_eq!(context.speed_boost_multiplier(50.0), 1.2);
+        assert_eq!(context.speed_boost_multiplier(100.0), 1.4);
+        assert_eq!(context.speed_boost_multiplier(400.0), 1.4);
+    }
+
+    #[test]
+    fn resources_set_context() {
+        let mut context = ParallaxContext::new(ParallaxConfig::default());
+        context.set_scale(2.0).set_neutral_depth(5.0);
+
+        assert_eq!(context.scale(), 2.0);
+        assert_eq!(context.neutral_depth(), 5.0);
+        assert_eq!(context.0.near_depth, 15.0);
+        assert_eq!(context.0.far_depth, -95.0);
+
+        let expected = ParallaxContext::new(ParallaxConfig {
+            scale: 2.0,
+            neutral_depth: 5.0,
+            ..Default::default()
+        });
+        assert_eq!(context.convert_depth(20.0), expected.convert_depth(20.0));
+        assert_eq!(
+            context.calculate_depth_factor(-15.0),
+            expected.calculate_depth_factor(-15.0)
+        );
+    }
+
+    #[test]
+    fn resources_material_cache() {
+        let mut materials = Assets::<ParallaxMaterial>::default();
+        let mut cache = ParallaxMaterialCache::default();
+        let layer = ParallaxLayer {
+            image: "background.png".into(),
+            depth: 40.0.into(),
+            ..Default::default()
+        };
+        let other = ParallaxLayer {
+            image: "background.png".into(),
+            depth: 20.0.into(),
+            ..Default::default()
+        };
+
+        let mut get_material = |key| {
+            cache.get_or_insert_with(key, &mut materials, || {
+                ParallaxMaterial::new(Handle::default(), Color::WHITE)
+            })
+        };
+        let first = get_material(ParallaxMaterialKey::new(&layer, None, None));
+        let second = get_material(ParallaxMaterialKey::new(&layer, None, None));
+        let different = get_material(ParallaxMaterialKey::new(&other, None, None));
+        let handled = get_material(ParallaxMaterialKey::new(&layer, Some(DepthHandle(0)), None));
+
+        assert_eq!(first, second);
+        assert_ne!(first, different);
+        assert_ne!(first, handled);
+        assert_eq!(materials.len(), 3);
+
+        // A material no layer uses anymore is not shared again
+        let first_id = first.id();
+        drop((first, second));
+        let recreated = cache.get_or_insert_with(
+            ParallaxMaterialKey::new(&layer, None, None),
+            &mut materials,
+            || ParallaxMaterial::new(Handle::default(), Color::WHITE),
+        );
+        assert_ne!(recreated.id(), first_id);
+    }
+
+    #[test]
+    fn resources_neutral_semantics() {
+        let mut context = ParallaxContext::new(ParallaxConfig {
+            scale: 2.0,
+            ..Default::default()
+        });
+        assert_eq!(
+            context.neutral_semantics(),
+            NeutralSemantics::StationaryInWorld
+        );
+        assert_eq!(context.calculate_depth_factor(0.0), 2.0);
+        assert_eq!(context.calculate_depth_factor(-10.0), 1.0);
+
+        context.set_neutral_semantics(NeutralSemantics::StationaryOnScreen);
+        assert_eq!(context.calculate_depth_factor(0.0), 0.0);
+        assert_eq!(context.calculate_depth_factor(-10.0), -1.0);
+        assert_eq!(context.calculate_depth_factor(5.0), 2.0);
+    }
+
+    #[test]
+    fn resources_limit_repeat_scale() {
+        let mut context = ParallaxContext::new(ParallaxConfig::default());
+        assert_eq!(context.max_repeat_scale(), (1024.0, false));
+
+        // At most the maximum is fine
+        context.set_max_repeat_scale(100.0, false);
+        assert_eq!(context.limit_repeat_scale(Vec2::new(100.0, 1.0)), None);
+
+        // Past the maximum is reported, and only clamped when enabled
+        let repeat_scale = Vec2::new(250.0, 40.0);
+        assert_eq!(context.limit_repeat_scale(repeat_scale), Some(repeat_scale));
+        context.set_max_repeat_scale(100.0, true);
+        assert_eq!(
+            context.limit_repeat_scale(repeat_scale),
+            Some(Vec2::new(100.0, 40.0))
+        );
+    }
+
+    #[test]
+    fn resources_invert_depth_ordering() {
+        let mut context = ParallaxContext::new(ParallaxConfig::default());
+        assert!(!context.invert_depth_ordering());
+        let [low, high] = [-10.0, 5.0].map(|depth| context.resolve_depth(depth.into()));
+        assert!(high.0 < low.0);
+        assert!(high.1 < low.1);
+
+        // Higher layers render in front and move faster, with the factors of the negated depths
+        context.set_invert_depth_ordering(true);
+        let [low, high] = [-10.0, 5.0].map(|depth| context.resolve_depth(depth.into()));
+        assert_eq!(low, (-10.0, 0.5));
+        assert_eq!(high, (5.0, 2.0));
+
+        // The near and far depths keep their meaning, clamping the factors of the extreme heights
+        assert_eq!(
+            context.resolve_depth(10.0.into()).1,
+            ParallaxContext::DEPTH_FACTOR_MAX
+        );
+        assert_eq!(
+            context.resolve_depth((-100.0).into()).1,
+            ParallaxContext::DEPTH_FACTOR_MIN
+        );
+    }
+
+    #[test]
+    fn resources_atmosphere_at() {
+        let fog = Color::rgb(0.5, 0.6, 0.7);
+        let mut context = ParallaxContext::new(ParallaxConfig {
+            neutral_depth: 5.0,
+            ..Default::default()
+        });
+        assert_eq!(context.atmosphere_at(-50.0), None);
+
+        context.set_atmosphere(Some((fog, 0.8)));
+        // The far depth lies at a world depth of -95
+        assert_eq!(context.atmosphere_at(10.0), Some((fog, 0.0)));
+        assert_eq!(context.atmosphere_at(0.0), Some((fog, 0.0)));
+        assert_eq!(context.atmosphere_at(-47.5), Some((fog, 0.4)));
+        assert_eq!(context.atmosphere_at(-95.0), Some((fog, 0.8)));
+        assert_eq!(context.atmosphere_at(-200.0), Some((fog, 0.8)));
+    }
 }
@@ -1,129 +1,800 @@
+#[cfg(feature = "diagnostics")]
+use crate::diagnostics::ParallaxDiagnostics;
 use crate::{
     components::*,
     depth::Depth,
+    events::{ParallaxLayerLoadFailed, ParallaxLayerReady, ParallaxTeleport},
     flags::ParallaxFlags,
     material::ParallaxMaterial,
-    resources::{ParallaxContext, ParallaxMesh},
+    resources::{
+        ParallaxBeatPulse, ParallaxCameraVelocity, ParallaxClearColorGradient, ParallaxClock,
+        ParallaxColorGrading, ParallaxContext, ParallaxDepthRegistry, ParallaxFocusDepth,
+        ParallaxMaterialCache, ParallaxMaterialKey, ParallaxMesh, ParallaxNeutralDepthAnimation,
+        ParallaxSceneDepth, ParallaxSolidImage,
+    },
 };
+#[cfg(feature = "debug_gizmos")]
+use bevy::prelude::Gizmos;
 use bevy::{
+    asset::{AssetEvent, LoadState},
+    ecs::{query::QueryItem, system::SystemParam},
+    log::{error, warn},
     prelude::{
-        default, Added, AssetServer, Assets, Commands, Entity, Handle, Image,
-        OrthographicProjection, Query, Res, ResMut, Transform, Vec2, With, Without,
+        default, Added, AssetServer, Assets, BVec2, Camera, Changed, ClearColor, Color, Commands,
+        DetectChanges, DetectChangesMut, Entity, EulerRot, EventReader, EventWriter, Handle, Has,
+        Image, Local, Mesh, Name, Or, OrthographicProjection, Quat, Query, Rect, Rectangle, Ref,
+        RemovedComponents, Res, ResMut, Time, Transform, Vec2, Vec3, Visibility, Window, With,
+        Without,
+    },
+    render::{
+        render_resource::TextureDimension,
+        texture::{ImageAddressMode, ImageSampler, ImageSamplerDescriptor},
     },
-    render::texture::{ImageAddressMode, ImageSampler, ImageSamplerDescriptor},
-    sprite::MaterialMesh2dBundle,
+    sprite::{MaterialMesh2dBundle, Mesh2dHandle},
+    utils::{HashMap, HashSet},
+    window::PrimaryWindow,
 };
+#[cfg(feature = "diagnostics")]
+use bevy::{diagnostic::Diagnostics, utils::Instant};
 
+#[allow(clippy::type_complexity)]
+#[cfg_attr(feature = "diagnostics", allow(clippy::too_many_arguments))]
 pub(crate) fn initial_load_parallax_layers(
     mut commands: Commands,
     mut materials: ResMut<Assets<ParallaxMaterial>>,
+    mut material_cache: ResMut<ParallaxMaterialCache>,
     new_parallax_layers_query: Query<
-        (Entity, &ParallaxLayer, Option<&Transform>),
+        (
+            Entity,
+            &ParallaxLayer,
+            Option<&Transform>,
+            Option<&DepthHandle>,
+            Option<&ParallaxPixelScroll>,
+        ),
         Added<ParallaxLayer>,
     >,
     asset_server: Res<AssetServer>,
     parallax_mesh: Res<ParallaxMesh>,
+    context: Res<ParallaxContext>,
+    #[cfg(feature = "diagnostics")] mut diagnostics: Diagnostics,
 ) {
-    for (entity, parallax, transform) in new_parallax_layers_query.iter() {
+    #[cfg(feature = "diagnostics")]
+    let start = Instant::now();
+
+    for (entity, parallax, transform, depth_handle, pixel_scroll) in
+        new_parallax_layers_query.iter()
+    {
+        if context.strict() {
+            if let Err(error) = parallax.validate() {
+                error!("Skipping invalid parallax layer {entity:?}: {error}");
+                continue;
+            }
+        }
+
         let transform = match transform {
             Some(transform) => *transform,
             None => Transform::default(),
         };
 
+        let mut parallax_data =
+            ParallaxLayerData::new(parallax.depth, parallax.offset, parallax.flags);
+        parallax_data.image = parallax.image.clone();
+        parallax_data.color = parallax.color;
+        parallax_data.z_offset = transform.translation.z;
+        parallax_data.tile_origin = parallax.tile_origin;
+        parallax_data.tile_scale = parallax.tile_scale;
+        parallax_data.pivot = parallax.pivot;
+        parallax_data.offset_space = parallax.offset_space;
+        parallax_data.fade_in = parallax.fade_in;
+        parallax_data.detile_strength = parallax.detile_strength;
+        parallax_data.order_in_tier = parallax.order_in_tier;
+        parallax_data.z_bias = parallax.z_bias;
+        parallax_data.anisotropy = parallax.anisotropy;
+        parallax_data.ignore_global_scale = parallax.ignore_global_scale;
+        parallax_data.sdf = parallax.sdf;
+        parallax_data.atlas = parallax.atlas;
+
+        if let Some(camera) = parallax.target_camera {
+            commands.entity(entity).insert(ParallaxTargetCamera(camera));
+        }
+
+        let create_material = || {
+            let mut material =
+                ParallaxMaterial::new(parallax.image.load(&asset_server), parallax.color);
+            material
+                .set_dissolve_image(
+                    parallax
+                        .dissolve_image
+                        .as_deref()
+                        .map(|image| asset_server.load(image.to_owned())),
+                )
+                .set_mask(
+                    parallax
+                        .mask_image
+                        .as_deref()
+                        .map(|image| asset_server.load(image.to_owned())),
+                    parallax.mask_space,
+                )
+                .set_blend_mode(parallax.blend_mode)
+                .set_sdf(parallax.sdf);
+            material
+        };
+        let material = match context.share_materials() && parallax.fade_in.is_none() {
+            true => {
+                commands.entity(entity).insert(ParallaxSharedMaterial);
+                material_cache.get_or_insert_with(
+                    ParallaxMaterialKey::new(parallax, depth_handle.copied(), pixel_scroll),
+                    &mut materials,
+                    create_material,
+                )
+            }
+            false => materials.add(create_material()),
+        };
+
+        if !parallax.lods.is_empty() {
+            commands.entity(entity).insert(ParallaxLodImages {
+                base: parallax.image.load(&asset_server),
+                levels: parallax
+                    .lods
+                    .iter()
+                    .map(|lod| {
+                        (
+                            lod.max_depth_factor,
+                            asset_server.load(lod.image.to_string()),
+                        )
+                    })
+                    .collect(),
+                current: None,
+            });
+        }
+
         commands
             .entity(entity)
             .insert((
-                ParallaxLayerData {
-                    depth: parallax.depth,
-                    offset: parallax.offset,
-                    flags: parallax.flags,
-                },
+                parallax_data,
                 MaterialMesh2dBundle {
                     mesh: parallax_mesh.handle(),
-                    material: materials.add(ParallaxMaterial::new(
-                        asset_server.load(parallax.image),
-                        parallax.color,
-                    )),
+                    material,
                     transform,
                     ..default()
                 },
             ))
             .remove::<ParallaxLayer>();
 
+        match &parallax.name {
+            Some(name) => {
+                commands.entity(entity).insert(Name::new(name.clone()));
+            }
+            #[cfg(debug_assertions)]
+            None => {
+                commands
+                    .entity(entity)
+                    .insert((Name::new("Parallax Layer"), ParallaxDefaultName));
+            }
+            #[cfg(not(debug_assertions))]
+            None => {}
+        }
+    }
+
+    #[cfg(feature = "diagnostics")]
+    ParallaxDiagnostics::measure(
+        &mut diagnostics,
+        &ParallaxDiagnostics::INITIAL_LOAD_LAYERS,
+        start,
+    );
+}
+
+/// Layers which got a component giving them state of their own, which the parallax systems
+/// write to their material.
+type ParallaxLayerStateAdded = Or<(
+    Added<ParallaxAlpha>,
+    Added<ParallaxCursorMask>,
+    Added<ParallaxDissolve>,
+    Added<ParallaxFactorBoost>,
+    Added<ParallaxRelativeDepth>,
+    Added<ParallaxShimmer>,
+    Added<ParallaxUvRotation>,
+)>;
+
+/// Gives a layer sharing its material a copy of its own, once it gets state of its own which
+/// would otherwise change every layer sharing the material, see
+/// [`ParallaxPlugin::set_share_materials`](crate::plugin::ParallaxPlugin::set_share_materials).
+#[allow(clippy::type_complexity)]
+pub(crate) fn unshare_parallax_materials(
+    mut commands: Commands,
+    mut parallax_layer_query: Query<
+        (Entity, &mut Handle<ParallaxMaterial>),
+        (With<ParallaxSharedMaterial>, ParallaxLayerStateAdded),
+    >,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+) {
+    for (entity, mut material) in parallax_layer_query.iter_mut() {
+        if let Some(shared_material) = materials.get(&*material).cloned() {
+            *material = materials.add(shared_material);
+        }
+        commands.entity(entity).remove::<ParallaxSharedMaterial>();
+    }
+}
+
+/// Loads the new solid color parallax layers, as layers repeating on both axes.
+pub(crate) fn initial_load_parallax_solid_layers(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+    new_solid_layers_query: Query<
+        (Entity, &ParallaxSolidLayer, Option<&Transform>),
+        Added<ParallaxSolidLayer>,
+    >,
+    solid_image: Res<ParallaxSolidImage>,
+    parallax_mesh: Res<ParallaxMesh>,
+) {
+    for (entity, solid, transform) in new_solid_layers_query.iter() {
+        let transform = match transform {
+            Some(transform) => *transform,
+            None => Transform::default(),
+        };
+
+        let mut parallax_data = ParallaxLayerData::new(
+            solid.depth,
+            Vec2::ZERO,
+            ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS,
+        );
+        parallax_data.color = solid.color;
+        parallax_data.z_offset = transform.translation.z;
+
+        commands
+            .entity(entity)
+            .insert((
+                parallax_data,
+                MaterialMesh2dBundle {
+                    mesh: parallax_mesh.handle(),
+                    material: materials
+                        .add(ParallaxMaterial::new(solid_image.handle(), solid.color)),
+                    transform,
+                    ..default()
+                },
+            ))
+            .remove::<ParallaxSolidLayer>();
+
         #[cfg(debug_assertions)]
-        {
-            use bevy::prelude::Name;
-            commands.entity(entity).insert(Name::new("Parallax Layer"));
+        commands
+            .entity(entity)
+            .insert((Name::new("Parallax Solid Layer"), ParallaxDefaultName));
+    }
+}
+
+/// Calls `set` on the material behind `handle` only when `changed` holds for it, since every
+/// mutable access prepares the material again for rendering.
+fn set_material_if_changed(
+    materials: &mut Assets<ParallaxMaterial>,
+    handle: &Handle<ParallaxMaterial>,
+    changed: impl Fn(&ParallaxMaterial) -> bool,
+    set: impl FnOnce(&mut ParallaxMaterial),
+) {
+    if materials.get(handle).is_some_and(changed) {
+        if let Some(material) = materials.get_mut(handle) {
+            set(material);
         }
     }
 }
 
-pub(crate) fn process_new_parallax_layer_data(
-    mut new_parallax_layers_query: Query<
+/// Returns the item of the primary parallax camera, see [`ParallaxCamera::primary`].
+///
+/// ## Panics
+/// Panics if there is not exactly one primary parallax camera.
+pub(crate) fn primary_camera<'a, T>(
+    cameras: impl IntoIterator<Item = (T, &'a ParallaxCamera)>,
+) -> T {
+    let mut primary_cameras = cameras
+        .into_iter()
+        .filter_map(|(item, camera)| camera.primary.then_some(item));
+    let primary_camera = primary_cameras
+        .next()
+        .expect("There should be exactly one primary parallax camera");
+    assert!(
+        primary_cameras.next().is_none(),
+        "There should be exactly one primary parallax camera"
+    );
+
+    primary_camera
+}
+
+/// Returns the parallax camera of a layer, which is the camera the layer is bound to with a
+/// [`ParallaxTargetCamera`], or else the `primary` camera.
+#[inline]
+#[must_use]
+fn layer_camera(target_camera: Option<&ParallaxTargetCamera>, primary: Entity) -> Entity {
+    target_camera.map_or(primary, |target_camera| target_camera.0)
+}
+
+pub(crate) fn advance_parallax_clock(
+    mut clock: ResMut<ParallaxClock>,
+    time: Res<Time>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+) {
+    let unfocused = clock.pause_when_unfocused
+        && window_query
+            .get_single()
+            .is_ok_and(|window| !window.focused);
+
+    clock.advance(match unfocused {
+        true => 0.0,
+        false => time.delta_seconds(),
+    });
+}
+
+/// Decays the [`ParallaxBeatPulse`] with the parallax clock.
+pub(crate) fn advance_parallax_beat_pulse(
+    mut beat_pulse: ResMut<ParallaxBeatPulse>,
+    clock: Res<ParallaxClock>,
+) {
+    beat_pulse.advance(clock.delta());
+}
+
+/// Sets the time uniform of the materials of the parallax layers with a
+/// [`ParallaxMaterialTime`] to the elapsed time of the parallax clock.
+pub(crate) fn update_parallax_material_time(
+    parallax_layer_query: Query<&Handle<ParallaxMaterial>, With<ParallaxMaterialTime>>,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+    clock: Res<ParallaxClock>,
+) {
+    let elapsed = clock.elapsed();
+    for material in parallax_layer_query.iter() {
+        set_material_if_changed(
+            &mut materials,
+            material,
+            |material| material.time() != elapsed,
+            |material| {
+                material.set_time(elapsed);
+            },
+        );
+    }
+}
+
+/// Advances the frames of the parallax layers with a sprite sheet, see [`ParallaxAtlas`].
+pub(crate) fn animate_parallax_atlases(
+    parallax_layer_query: Query<(&ParallaxLayerData, &Handle<ParallaxMaterial>)>,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+    clock: Res<ParallaxClock>,
+) {
+    for (parallax, material) in parallax_layer_query.iter() {
+        let Some(atlas) = parallax.atlas else {
+            continue;
+        };
+        let frame = atlas.frame_at(clock.elapsed());
+
+        set_material_if_changed(
+            &mut materials,
+            material,
+            |material| material.current_frame() != frame,
+            |material| {
+                material.set_current_frame(frame);
+            },
+        );
+    }
+}
+
+/// Sets the depth of the layers referencing a shared depth, when the shared depth or the
+/// reference has changed.
+pub(crate) fn sync_shared_parallax_depths(
+    mut parallax_layers_query: Query<(Ref<DepthHandle>, &mut ParallaxLayerData)>,
+    depth_registry: Res<ParallaxDepthRegistry>,
+) {
+    let registry_changed = depth_registry.is_changed();
+    for (handle, mut parallax) in parallax_layers_query.iter_mut() {
+        if !(registry_changed || handle.is_changed() || parallax.is_added()) {
+            continue;
+        }
+
+        if let Some(depth) = depth_registry.get(*handle) {
+            parallax.set_depth(depth);
+        }
+    }
+}
+
+/// Sets the depth of the layers with a [`ParallaxRelativeDepth`] from their reference layer.
+pub(crate) fn apply_parallax_relative_depths(
+    mut commands: Commands,
+    relative_depth_query: Query<(Entity, &ParallaxRelativeDepth)>,
+    mut parallax_layers_query: Query<&mut ParallaxLayerData>,
+) {
+    for (entity, relative_depth) in relative_depth_query.iter() {
+        let Ok(reference) = parallax_layers_query.get(relative_depth.reference) else {
+            warn!(
+                "Relative depth reference {:?} of {entity:?} is not a parallax layer, keeping the current depth",
+                relative_depth.reference,
+            );
+            commands.entity(entity).remove::<ParallaxRelativeDepth>();
+            continue;
+        };
+
+        let depth = reference.authored_depth.with_offset(relative_depth.delta);
+        if let Ok(mut parallax) = parallax_layers_query.get_mut(entity) {
+            // Only set a new depth, since setting it causes the layer to be processed again
+            let current = parallax.authored_depth;
+            if current != depth || current.depth_factor_xy() != depth.depth_factor_xy() {
+                parallax.set_depth(depth);
+            }
+        }
+    }
+}
+
+/// Advances the [`ParallaxNeutralDepthAnimation`], moving the neutral depth of the
+/// [`ParallaxContext`] and removing the animation once finished.
+///
+/// Only the layout of the layers follows the neutral depth, so the layers are marked as
+/// [`ParallaxLayerResized`] instead of being processed again as for any other change of the
+/// context.
+pub(crate) fn animate_parallax_neutral_depth(
+    mut commands: Commands,
+    mut animation: ResMut<ParallaxNeutralDepthAnimation>,
+    mut parallax_context: ResMut<ParallaxContext>,
+    parallax_layers_query: Query<Entity, With<ParallaxLayerProcessed>>,
+    clock: Res<ParallaxClock>,
+) {
+    let neutral_depth = animation.advance(clock.delta(), parallax_context.neutral_depth());
+    if parallax_context.neutral_depth() != neutral_depth {
+        parallax_context
+            .bypass_change_detection()
+            .set_neutral_depth(neutral_depth);
+        for entity in parallax_layers_query.iter() {
+            commands.entity(entity).insert(ParallaxLayerResized);
+        }
+    }
+
+    if animation.is_finished() {
+        commands.remove_resource::<ParallaxNeutralDepthAnimation>();
+    }
+}
+
+/// Marks every parallax layer as changed when the [`ParallaxContext`] has been modified, such
+/// that the layers get processed again.
+pub(crate) fn mark_parallax_layers_on_context_change(
+    mut parallax_layers_query: Query<&mut ParallaxLayerData>,
+    parallax_context: Res<ParallaxContext>,
+) {
+    if !parallax_context.is_changed() || parallax_context.is_added() {
+        return;
+    }
+
+    for mut parallax in parallax_layers_query.iter_mut() {
+        parallax.set_changed();
+    }
+}
+
+/// Marks the parallax layers of a camera as [`ParallaxLayerResized`] when its projection has
+/// been modified, such as by zooming, such that the layers are laid out again for the new size
+/// of the camera view.
+///
+/// Repeating layers are also marked when their camera starts or stops rotating, since they
+/// cover the diagonal of the view of a rotated camera.
+#[allow(clippy::type_complexity)]
+pub(crate) fn mark_parallax_layers_on_camera_change(
+    mut commands: Commands,
+    parallax_layers_query: Query<
+        (Entity, &ParallaxLayerData, Option<&ParallaxTargetCamera>),
+        With<ParallaxLayerProcessed>,
+    >,
+    camera_query: Query<
         (
-            &mut Transform,
-            &mut ParallaxLayerData,
-            &Handle<ParallaxMaterial>,
+            Entity,
+            Ref<OrthographicProjection>,
+            &ParallaxCamera,
+            &Transform,
         ),
-        Added<ParallaxLayerData>,
+        Without<ParallaxLayerData>,
     >,
-    mut materials: ResMut<Assets<ParallaxMaterial>>,
-    mut images: ResMut<Assets<Image>>,
-    camera_query: Query<&OrthographicProjection, With<ParallaxCamera>>,
-    parallax_context: Res<ParallaxContext>,
 ) {
-    let camera_projection = camera_query
-        .get_single()
-        .expect("There should be exactly one parallax camera");
+    if camera_query.is_empty() {
+        return;
+    }
+
+    // Whether the projection of each camera changed, and whether it is rotated
+    let cameras: HashMap<_, _> = camera_query
+        .iter()
+        .map(|(entity, projection, _, transform)| {
+            let resized = projection.is_changed() && !projection.is_added();
+            (entity, (resized, camera_angle(transform) != 0.0))
+        })
+        .collect();
+    let primary = primary_camera(
+        camera_query
+            .iter()
+            .map(|(entity, _, camera, _)| (entity, camera)),
+    );
+    for (entity, parallax, target_camera) in parallax_layers_query.iter() {
+        let Some(&(resized, rotated)) = cameras.get(&layer_camera(target_camera, primary)) else {
+            continue;
+        };
+        let repeats = parallax
+            .flags
+            .intersects(ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS);
+        if resized || (repeats && parallax.view_rotated != rotated) {
+            commands.entity(entity).insert(ParallaxLayerResized);
+        }
+    }
+}
+
+/// Returns the angle of a camera around the z-axis, which layers turn with, see
+/// [`ParallaxFlags::LOCK_ROTATION`].
+#[inline]
+#[must_use]
+fn camera_angle(transform: &Transform) -> f32 {
+    transform.rotation.to_euler(EulerRot::ZYX).0
+}
+
+/// Components of a parallax layer used when processing it.
+pub(crate) type ParallaxLayerProcessingQuery = (
+    Entity,
+    Has<ParallaxLayerProcessed>,
+    Has<ParallaxLayerPending>,
+    Has<ParallaxLayerResized>,
+    &'static mut Transform,
+    &'static mut ParallaxLayerData,
+    &'static Handle<ParallaxMaterial>,
+    Option<&'static ParallaxPixelScroll>,
+    Option<&'static ParallaxLodImages>,
+    Option<&'static ParallaxTargetCamera>,
+    Option<&'static Mesh2dHandle>,
+);
+
+/// Resolves the depth, offset and dimensions of parallax layers, and sets up their material.
+#[derive(SystemParam)]
+pub(crate) struct ParallaxLayerProcessor<'w, 's> {
+    commands: Commands<'w, 's>,
+    materials: ResMut<'w, Assets<ParallaxMaterial>>,
+    images: ResMut<'w, Assets<Image>>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    parallax_mesh: Res<'w, ParallaxMesh>,
+    camera_query: Query<
+        'w,
+        's,
+        (
+            &'static OrthographicProjection,
+            &'static ParallaxCamera,
+            &'static Transform,
+        ),
+        Without<ParallaxLayerData>,
+    >,
+    parallax_context: Res<'w, ParallaxContext>,
+    asset_server: Option<Res<'w, AssetServer>>,
+    ready_events: EventWriter<'w, ParallaxLayerReady>,
+    load_failed_events: EventWriter<'w, ParallaxLayerLoadFailed>,
+}
+
+impl ParallaxLayerProcessor<'_, '_> {
+    /// Returns the size of the view of the parallax camera of a layer, see [`layer_camera`], the
+    /// scale of its projection, and whether the camera is rotated.
+    fn camera_view(
+        &self,
+        target_camera: Option<&ParallaxTargetCamera>,
+    ) -> Option<(Vec2, f32, bool)> {
+        let (camera_projection, camera_transform) = match target_camera {
+            Some(target_camera) => {
+                let (projection, _, transform) = self.camera_query.get(target_camera.0).ok()?;
+                (projection, transform)
+            }
+            None => primary_camera(
+                self.camera_query
+                    .iter()
+                    .map(|(projection, camera, transform)| ((projection, transform), camera)),
+            ),
+        };
+
+        Some((
+            camera_projection.area.half_size() * 2.0,
+            camera_projection.scale,
+            camera_angle(camera_transform) != 0.0,
+        ))
+    }
+
+    /// Returns whether the image of a layer is loaded.
+    ///
+    /// Otherwise the layer is marked as [`ParallaxLayerPending`] to be processed again once the
+    /// image is loaded, or a [`ParallaxLayerLoadFailed`] is sent if the image failed to load.
+    fn image_loaded(&mut self, entity: Entity, is_pending: bool, image: &Handle<Image>) -> bool {
+        if self.images.contains(image) {
+            return true;
+        }
+
+        let failed = self.asset_server.as_ref().is_some_and(|asset_server| {
+            matches!(asset_server.load_state(image.id()), LoadState::Failed)
+        });
+        if failed {
+            error!(
+                "Skipping parallax layer {entity:?}: its image {:?} failed to load",
+                image.path()
+            );
+            self.load_failed_events.send(ParallaxLayerLoadFailed {
+                entity,
+                image: image.clone(),
+            });
+            self.commands
+                .entity(entity)
+                .remove::<ParallaxLayerPending>();
+        } else if !is_pending {
+            self.commands.entity(entity).insert(ParallaxLayerPending);
+        }
+
+        false
+    }
+
+    /// Processes a single parallax layer, marking it as processed.
+    fn process(&mut self, parallax_layer: QueryItem<ParallaxLayerProcessingQuery>) {
+        let (
+            entity,
+            is_processed,
+            is_pending,
+            is_resized,
+            mut transform,
+            mut parallax,
+            material,
+            pixel_scroll,
+            lod_images,
+            target_camera,
+            mesh_handle,
+        ) = parallax_layer;
+        if is_resized {
+            self.commands
+                .entity(entity)
+                .remove::<ParallaxLayerResized>();
+        }
+
+        // Wait for the image, and the full resolution image of a layer with levels of detail
+        let image_handle = self
+            .materials
+            .get(material)
+            .expect("Parallax material should be loaded")
+            .image_handle()
+            .clone();
+        let images = [Some(image_handle), lod_images.map(|lod| lod.base.clone())];
+        for image in images.iter().flatten() {
+            if !self.image_loaded(entity, is_pending, image) {
+                return;
+            }
+        }
+        if is_pending {
+            self.commands
+                .entity(entity)
+                .remove::<ParallaxLayerPending>();
+        }
+
+        let Some((camera_size, camera_scale, view_rotated)) = self.camera_view(target_camera)
+        else {
+            error!("Skipping parallax layer {entity:?} bound to a camera which does not exist.");
+            return;
+        };
 
-    let camera_size = camera_projection.area.half_size() * 2.0;
+        // A resized layer is only laid out again, reusing its processed image and mesh
+        let relayout = is_resized && is_processed && !parallax.is_changed();
 
-    for (mut transform, mut parallax, material) in new_parallax_layers_query.iter_mut() {
-        let material = materials
+        // Processing writes the resolved values, which should not count as a change
+        let parallax = parallax.bypass_change_detection();
+
+        let material = self
+            .materials
             .get_mut(material)
             .expect("Parallax material should be loaded");
+        let relayout = relayout && material.image_handle() != *material.source_image();
 
-        let mut image = images
+        let image = self
+            .images
             .get(material.image_handle())
-            .expect("Image should be loaded")
-            .clone();
+            .expect("Image should be loaded, checked above");
+
+        // Only a single 2D texture can be repeated and sampled by the parallax material
+        let texture_descriptor = &image.texture_descriptor;
+        if texture_descriptor.dimension != TextureDimension::D2
+            || texture_descriptor.size.depth_or_array_layers != 1
+        {
+            error!(
+                "Skipping parallax layer {entity:?}: its image should be a single 2D texture, \
+                but is a {:?} texture with {} layers",
+                texture_descriptor.dimension, texture_descriptor.size.depth_or_array_layers
+            );
+            return;
+        }
 
-        let image_dimensions = image.size_f32();
+        // A lower resolution level of detail keeps the size of the full resolution image
+        let image_dimensions = match lod_images {
+            Some(lod_images) => self
+                .images
+                .get(&lod_images.base)
+                .expect("Image should be loaded, checked above")
+                .size_f32(),
+            None => image.size_f32(),
+        };
+        // The layer shows a single frame of a sprite sheet
+        let image_dimensions = match parallax.atlas {
+            Some(atlas) => image_dimensions / atlas.grid().as_vec2(),
+            None => image_dimensions,
+        };
 
         // Set and get the world depth, unwrap safe since we just before set the factor
-        parallax.depth = parallax.depth.to_world_with_factor(&parallax_context);
-        let depth_factor = parallax.depth.depth_factor().unwrap();
-        let mut depth_factor = Vec2::splat(depth_factor);
+        parallax.depth = match parallax.ignore_global_scale {
+            true => parallax
+                .authored_depth
+                .to_world_with_unscaled_factor(&self.parallax_context),
+            false => parallax
+                .authored_depth
+                .to_world_with_factor(&self.parallax_context),
+        };
+        parallax.pixel_scroll = pixel_scroll.map(|pixel_scroll| pixel_scroll.pixels_per_unit);
+        let (_, texture_factor) = parallax.split_factor();
 
-        // Configure texture repeat modes and dimensions
+        // Size of a single tile, either scaled from the image or stretched over the camera view
+        let repeats = BVec2::new(
+            parallax.flags.contains(ParallaxFlags::REPEAT_X_AXIS),
+            parallax.flags.contains(ParallaxFlags::REPEAT_Y_AXIS),
+        );
+        // Size of the layer on the axes which do not repeat, fitted inside the camera view
+        let layer_dimensions = match parallax.flags.contains(ParallaxFlags::CONTAIN) {
+            true => image_dimensions * (camera_size / image_dimensions).min_element(),
+            false => image_dimensions,
+        };
+        let tile_dimensions = match parallax.tile_scale {
+            Some(tile_scale) => Vec2::select(
+                repeats & tile_scale.cmpgt(Vec2::ZERO),
+                image_dimensions * tile_scale,
+                Vec2::select(repeats, camera_size, layer_dimensions),
+            ),
+            None => Vec2::select(repeats, image_dimensions, layer_dimensions),
+        };
+
+        // Configure texture repeat modes and dimensions, a repeating axis covers the camera view
+        // and the diagonal of a rotated one, leaving no gaps at any angle of the layer
+        parallax.view_rotated = view_rotated;
+        let repeat_view = match view_rotated {
+            true => Vec2::splat(camera_size.length()),
+            false => camera_size,
+        };
+        let repeat_extent = match parallax.flags.contains(ParallaxFlags::WHOLE_TILES) {
+            true => (repeat_view / tile_dimensions).ceil().max(Vec2::ONE) * tile_dimensions,
+            false => repeat_view,
+        };
         let (tile_mode_x, scaled_image_width) =
             match parallax.flags.contains(ParallaxFlags::REPEAT_X_AXIS) {
-                true => (ImageAddressMode::Repeat, camera_size.x),
-                false => {
-                    depth_factor.x = 0.0;
-                    (ImageAddressMode::ClampToEdge, image_dimensions.x)
-                }
+                true => (ImageAddressMode::Repeat, repeat_extent.x),
+                false => (ImageAddressMode::ClampToEdge, layer_dimensions.x),
             };
         let (tile_mode_y, scaled_image_height) =
             match parallax.flags.contains(ParallaxFlags::REPEAT_Y_AXIS) {
-                true => (ImageAddressMode::Repeat, camera_size.y),
-                false => {
-                    depth_factor.y = 0.0;
-                    (ImageAddressMode::ClampToEdge, image_dimensions.y)
-                }
+                true => (ImageAddressMode::Repeat, repeat_extent.y),
+                false => (ImageAddressMode::ClampToEdge, layer_dimensions.y),
             };
         let scaled_image_dimensions = Vec2::new(scaled_image_width, scaled_image_height);
 
-        image.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
-            address_mode_u: tile_mode_x,
-            address_mode_v: tile_mode_y,
-            ..default()
-        });
+        if !relayout {
+            // Anisotropic filtering requires linear filtering, as does a smooth distance field
+            let sampler_descriptor = match self.parallax_context.pixel_grid() {
+                Some(_) => ImageSamplerDescriptor::nearest(),
+                None if parallax.anisotropy > 1 => ImageSamplerDescriptor {
+                    anisotropy_clamp: parallax.anisotropy,
+                    ..ImageSamplerDescriptor::linear()
+                },
+                None if parallax.sdf => ImageSamplerDescriptor::linear(),
+                None => ImageSamplerDescriptor::default(),
+            };
+            let mut image = image.clone();
+            image.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+                address_mode_u: tile_mode_x,
+                address_mode_v: tile_mode_y,
+                ..sampler_descriptor
+            });
+            material.set_processed_image(self.images.add(image));
+        }
 
-        // Compute camera offset
-        let camera_translation =
-            translation_with_depth_and_flags(parallax.offset, parallax.depth, parallax.flags);
-        parallax.offset -= camera_translation;
+        // Compute camera offset, a camera-local offset is already relative to the camera, in
+        // screen pixels spanning the scale of the projection
+        let camera_translation = translation_with_depth_and_flags(
+            parallax.authored_offset,
+            parallax.depth,
+            parallax.flags,
+        );
+        parallax.offset = Vec2::select(
+            parallax.camera_local(),
+            parallax.authored_offset * camera_scale,
+            parallax.authored_offset - camera_translation,
+        );
 
         // Adjust offset relative to camera.
         if parallax.flags.contains(ParallaxFlags::OFFSET_TO_CAMERA) {
@@ -142,75 +813,1059 @@ pub(crate) fn process_new_parallax_layer_data(
             }
         }
 
-        let depth = parallax.depth.depth() + transform.translation.z;
+        // Shift the layer such that the pivot lies at the position of the layer
+        let pivot_offset = (Vec2::splat(0.5) - parallax.pivot) * scaled_image_dimensions;
+        parallax.offset += Vec2::select(parallax.scrolls_texture(), Vec2::ZERO, pivot_offset);
+
+        let depth = parallax.depth.depth()
+            + parallax.z_offset
+            + parallax.z_bias
+            + parallax.order_in_tier as f32 * self.parallax_context.tier_epsilon();
         transform.translation = parallax.offset.extend(depth);
-        transform.scale = scaled_image_dimensions.extend(1.0);
+        let resized = parallax.size != scaled_image_dimensions;
+        parallax.size = scaled_image_dimensions;
+
+        // A clamped axis keeps the edges of the layer outside of the camera view, or the layer
+        // inside of the camera view when it is the smaller of the two
+        let clamps = BVec2::new(
+            parallax.flags.contains(ParallaxFlags::CLAMP_X_AXIS),
+            parallax.flags.contains(ParallaxFlags::CLAMP_Y_AXIS),
+        );
+        parallax.clamp_extent = Vec2::select(
+            clamps,
+            ((scaled_image_dimensions - camera_size) / 2.0).abs(),
+            Vec2::INFINITY,
+        );
+        match parallax.flags.contains(ParallaxFlags::UNIT_SCALE) {
+            true => {
+                transform.scale = Vec3::ONE;
+                parallax.unit_mesh = true;
+                let mesh = Rectangle::from_size(scaled_image_dimensions);
+                match mesh_handle.filter(|_| relayout) {
+                    // The mesh of the layer is replaced in place when its size changed
+                    Some(mesh_handle) if resized => {
+                        self.meshes.insert(mesh_handle.0.id(), mesh.into())
+                    }
+                    Some(_) => {}
+                    None => {
+                        let mesh = self.meshes.add(mesh);
+                        self.commands.entity(entity).insert(Mesh2dHandle(mesh));
+                    }
+                }
+            }
+            false => {
+                transform.scale = scaled_image_dimensions.extend(1.0);
+                // The mesh of the size of the layer is no longer scaled by one
+                if parallax.unit_mesh {
+                    parallax.unit_mesh = false;
+                    self.commands
+                        .entity(entity)
+                        .insert(self.parallax_mesh.handle());
+                }
+            }
+        }
+
+        let mut repeat_scale = scaled_image_dimensions / tile_dimensions;
+        if let Some(limited_repeat_scale) = self.parallax_context.limit_repeat_scale(repeat_scale) {
+            if !relayout {
+                warn!(
+                    "Parallax layer {entity:?} repeats {repeat_scale} times, more than the maximum of {}.",
+                    self.parallax_context.max_repeat_scale().0
+                );
+            }
+            repeat_scale = limited_repeat_scale;
+        }
 
         material
-            .set_image_handle(images.add(image))
-            .set_repeat_scale(scaled_image_dimensions / image_dimensions)
-            .set_depth(depth_factor / scaled_image_dimensions)
-            .set_offset(parallax.offset);
-    }
-}
+            .set_repeat_scale(repeat_scale)
+            .set_depth(texture_factor / scaled_image_dimensions)
+            .set_offset(parallax.offset)
+            .set_tile_phase(Vec2::ZERO)
+            .set_shift(Vec2::ZERO)
+            .set_pixel_snap(match self.parallax_context.pixel_grid() {
+                Some(pixel_grid) => scaled_image_dimensions / pixel_grid,
+                None => Vec2::ZERO,
+            })
+            .set_detile_strength(Vec2::select(
+                repeats,
+                Vec2::splat(parallax.detile_strength.clamp(0.0, 1.0)),
+                Vec2::ZERO,
+            ))
+            .set_atlas_grid(parallax.atlas.map(|atlas| atlas.grid()));
 
-pub(crate) fn move_parallax_layers(
-    mut parallax_layer_query: Query<(&mut Transform, &ParallaxLayerData), Without<ParallaxCamera>>,
-    camera_query: Query<&Transform, With<ParallaxCamera>>,
-) {
-    let camera_transform = camera_query
-        .get_single()
-        .expect("There should be exactly one parallax camera");
+        // Tint the layer by its distance behind the neutral depth
+        let (fog_color, atmosphere) = match parallax.flags.contains(ParallaxFlags::NO_ATMOSPHERE) {
+            true => None,
+            false => self.parallax_context.atmosphere_at(parallax.depth.depth()),
+        }
+        .unwrap_or((Color::NONE, 0.0));
+        material.set_atmosphere(fog_color, atmosphere);
 
-    let camera_translation = camera_transform.translation.truncate();
-    for (mut transform, parallax) in parallax_layer_query.iter_mut() {
-        let translation =
-            translation_with_depth_and_flags(camera_translation, parallax.depth, parallax.flags);
+        // Shift the tiling such that a tile boundary lies at the tile origin, when the camera
+        // is centered at it
+        if let Some(tile_origin) = parallax.tile_origin {
+            let layer_translation =
+                translation_with_depth_and_flags(tile_origin, parallax.depth, parallax.flags)
+                    + parallax.offset;
+            let tile = material.tile_coordinate(
+                tile_origin,
+                layer_translation,
+                scaled_image_dimensions,
+                tile_origin,
+            );
+
+            let mut tile_phase = -tile.fract();
+            if !parallax.flags.contains(ParallaxFlags::REPEAT_X_AXIS) {
+                tile_phase.x = 0.0;
+            }
+            if !parallax.flags.contains(ParallaxFlags::REPEAT_Y_AXIS) {
+                tile_phase.y = 0.0;
+            }
+            material.set_tile_phase(tile_phase);
+        }
+
+        if !is_processed {
+            self.commands.entity(entity).insert(ParallaxLayerProcessed);
+
+            if let Some(fade_in) = parallax.fade_in {
+                let fade_in = ParallaxFadeIn::new(fade_in);
+                material.set_opacity(fade_in.opacity());
+                self.commands.entity(entity).insert(fade_in);
+            }
 
-        transform.translation = (translation + parallax.offset).extend(transform.translation.z);
+            self.ready_events.send(ParallaxLayerReady {
+                entity,
+                depth: parallax.depth,
+            });
+        }
     }
 }
 
-#[inline]
-#[must_use]
-fn translation_with_depth_and_flags(
-    mut translation: Vec2,
-    depth: Depth,
-    flags: ParallaxFlags,
-) -> Vec2 {
-    let Some(depth_factor) = depth.depth_factor() else {
-        // No depth factor is treated as 0.0
-        return Vec2::ZERO;
-    };
+#[allow(clippy::type_complexity)]
+pub(crate) fn process_new_parallax_layer_data(
+    mut processor: ParallaxLayerProcessor,
+    mut new_parallax_layers_query: Query<
+        ParallaxLayerProcessingQuery,
+        Or<(
+            Changed<ParallaxLayerData>,
+            Changed<ParallaxPixelScroll>,
+            With<ParallaxLayerPending>,
+            With<ParallaxLayerResized>,
+        )>,
+    >,
+    #[cfg(feature = "diagnostics")] mut diagnostics: Diagnostics,
+) {
+    #[cfg(feature = "diagnostics")]
+    let start = Instant::now();
 
-    if flags.contains(ParallaxFlags::LOCKED_X_AXIS) {
-        translation.x = 0.0;
-    } else if !flags.contains(ParallaxFlags::REPEAT_X_AXIS) {
-        translation.x -= translation.x * depth_factor;
+    for parallax_layer in new_parallax_layers_query.iter_mut() {
+        processor.process(parallax_layer);
     }
 
-    if flags.contains(ParallaxFlags::LOCKED_Y_AXIS) {
-        translation.y = 0.0;
-    } else if !flags.contains(ParallaxFlags::REPEAT_Y_AXIS) {
-        translation.y -= translation.y * depth_factor;
+    #[cfg(feature = "diagnostics")]
+    ParallaxDiagnostics::measure(
+        &mut diagnostics,
+        &ParallaxDiagnostics::PROCESS_LAYERS,
+        start,
+    );
+}
+
+/// Processes the parallax layers again once an image they show is modified, such as when it is
+/// hot reloaded.
+///
+/// Processing sets up a copy of the image, with the sampler and dimensions of the layer, so the
+/// texture of the layer is reset to the modified image to be copied again.
+#[allow(clippy::type_complexity)]
+pub(crate) fn reload_modified_parallax_images(
+    mut image_events: EventReader<AssetEvent<Image>>,
+    mut parallax_layer_query: Query<
+        (
+            &mut ParallaxLayerData,
+            &Handle<ParallaxMaterial>,
+            Option<&ParallaxLodImages>,
+        ),
+        With<ParallaxLayerProcessed>,
+    >,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+) {
+    let modified: HashSet<_> = image_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+    if modified.is_empty() {
+        return;
     }
 
-    translation
+    for (mut parallax, material, lod_images) in parallax_layer_query.iter_mut() {
+        let Some(source_image) = materials
+            .get(material)
+            .map(|material| material.source_image().clone())
+        else {
+            continue;
+        };
+
+        // A layer with levels of detail takes its dimensions from the full resolution image
+        let base_modified = lod_images.is_some_and(|lod| modified.contains(&lod.base.id()));
+        if !modified.contains(&source_image.id()) && !base_modified {
+            continue;
+        }
+
+        if let Some(material) = materials.get_mut(material) {
+            material.set_image_handle(source_image);
+        }
+        parallax.set_changed();
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::resources::ParallaxConfig;
+/// Processes every parallax layer, see [`ParallaxReprocessAll`](crate::commands::ParallaxReprocessAll).
+pub(crate) fn reprocess_all_parallax_layers(
+    mut processor: ParallaxLayerProcessor,
+    mut parallax_layers_query: Query<ParallaxLayerProcessingQuery>,
+) {
+    for parallax_layer in parallax_layers_query.iter_mut() {
+        processor.process(parallax_layer);
+    }
+}
 
-    #[test]
-    fn systems_translation() {
-        let context = ParallaxContext::new(ParallaxConfig::default());
-        let depth = Depth::from_parallax(10.0);
-        let translation_0 =
-            translation_with_depth_and_flags(Vec2::ZERO, depth, ParallaxFlags::NONE);
+/// Tracks the velocity of every parallax camera from its movement since the last frame, see
+/// [`ParallaxCameraVelocity`]. A [`ParallaxTeleport`] of the camera is not movement.
+pub(crate) fn track_parallax_camera_velocities(
+    camera_query: Query<(Entity, &Transform, &ParallaxCamera)>,
+    mut camera_velocity: ResMut<ParallaxCameraVelocity>,
+    time: Res<Time>,
+    mut teleport_events: EventReader<ParallaxTeleport>,
+    mut last_camera_translations: Local<HashMap<Entity, Vec2>>,
+) {
+    // Nothing follows a camera before one is spawned
+    if camera_query.is_empty() {
+        return;
+    }
 
-        let depth = depth.to_world_with_factor(&context);
+    let primary = primary_camera(
+        camera_query
+            .iter()
+            .map(|(entity, _, camera)| (entity, camera)),
+    );
+    let teleported = teleport_events.read().last().is_some();
+    let delta_seconds = time.delta_seconds();
+
+    let velocities = camera_query
+        .iter()
+        .map(|(entity, transform, _)| {
+            let camera_translation = transform.translation.truncate();
+            let velocity = match last_camera_translations.insert(entity, camera_translation) {
+                Some(last_translation) if delta_seconds > 0.0 && !teleported => {
+                    (camera_translation - last_translation) / delta_seconds
+                }
+                _ => Vec2::ZERO,
+            };
+            (entity, velocity)
+        })
+        .collect();
+    last_camera_translations.retain(|entity, _| camera_query.contains(*entity));
+
+    camera_velocity.set(primary, velocities);
+}
+
+/// Rate per second at which the speed boost follows the speed of the camera.
+const SPEED_BOOST_EASING: f32 = 8.0;
+
+/// Multiplies the depth factor of the layers by the speed boost and the [`ParallaxFactorBoost`]
+/// of each layer, which [`move_parallax_layers`] applies on top of the usual movement. A
+/// [`ParallaxTeleport`] of the camera does not raise the speed boost, as it has no velocity.
+///
+/// The speed boost eases in and out by the [`ParallaxClock`], so it wears off smoothly once the
+/// camera slows down, and stays as is while the clock is paused.
+#[allow(clippy::type_complexity)]
+pub(crate) fn boost_parallax_layers_with_camera_speed(
+    mut parallax_layer_query: Query<
+        (
+            &mut ParallaxLayerData,
+            Option<&ParallaxFactorBoost>,
+            Option<&ParallaxTargetCamera>,
+        ),
+        With<ParallaxLayerProcessed>,
+    >,
+    camera_query: Query<(Entity, &ParallaxCamera)>,
+    mut speed_multipliers: Local<HashMap<Entity, f32>>,
+    camera_velocity: Res<ParallaxCameraVelocity>,
+    parallax_context: Res<ParallaxContext>,
+    clock: Res<ParallaxClock>,
+) {
+    if camera_query.is_empty() {
+        return;
+    }
+
+    let primary = primary_camera(camera_query.iter());
+    let easing = (SPEED_BOOST_EASING * clock.delta()).min(1.0);
+
+    // Eased speed multiplier of each camera
+    for (entity, _) in camera_query.iter() {
+        let speed = camera_velocity
+            .camera_velocity(entity)
+            .map_or(0.0, Vec2::length);
+        let target = parallax_context.speed_boost_multiplier(speed);
+        let speed_multiplier = speed_multipliers.entry(entity).or_insert(1.0);
+        *speed_multiplier += (target - *speed_multiplier) * easing;
+    }
+    speed_multipliers.retain(|entity, _| camera_query.contains(*entity));
+
+    for (mut parallax, factor_boost, target_camera) in parallax_layer_query.iter_mut() {
+        let Some(&speed_multiplier) = speed_multipliers.get(&layer_camera(target_camera, primary))
+        else {
+            continue;
+        };
+
+        let multiplier = speed_multiplier * factor_boost.map_or(1.0, |boost| boost.multiplier);
+        if parallax.factor_boost != multiplier {
+            // The boost is a dynamic effect, which should not cause the layer to be processed
+            parallax.bypass_change_detection().factor_boost = multiplier;
+        }
+    }
+}
+
+/// Swaps the texture of the layers with levels of detail, when their depth factor crosses a
+/// threshold.
+pub(crate) fn select_parallax_layer_lod(
+    mut parallax_layer_query: Query<
+        (
+            &mut ParallaxLayerData,
+            &mut ParallaxLodImages,
+            &Handle<ParallaxMaterial>,
+        ),
+        With<ParallaxLayerProcessed>,
+    >,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+) {
+    for (mut parallax, mut lod_images, material) in parallax_layer_query.iter_mut() {
+        let Some(depth_factor) = parallax.depth.depth_factor() else {
+            continue;
+        };
+
+        let level = lod_images.level(depth_factor);
+        if level == lod_images.current {
+            continue;
+        }
+
+        lod_images.current = level;
+        if let Some(material) = materials.get_mut(material) {
+            material.set_image_handle(lod_images.image(level));
+        }
+
+        // Processing again sets up the sampler of the swapped texture
+        parallax.set_changed();
+    }
+}
+
+pub(crate) fn animate_parallax_dissolve(
+    mut parallax_layer_query: Query<(&mut ParallaxDissolve, &Handle<ParallaxMaterial>)>,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+    clock: Res<ParallaxClock>,
+) {
+    for (mut dissolve, material) in parallax_layer_query.iter_mut() {
+        if dissolve.is_finished() && !dissolve.is_changed() {
+            continue;
+        }
+
+        dissolve.advance(clock.delta());
+        if let Some(material) = materials.get_mut(material) {
+            material.set_dissolve_threshold(dissolve.threshold());
+        }
+    }
+}
+
+/// Advances the [`ParallaxFadeIn`] of the layers, removing it once finished.
+///
+/// The opacity of a layer faded by a [`ParallaxFocusDepth`] is set by
+/// [`fade_parallax_layers_by_focus`], which multiplies the two opacities.
+pub(crate) fn fade_in_parallax_layers(
+    mut commands: Commands,
+    mut parallax_layer_query: Query<(Entity, &mut ParallaxFadeIn, &Handle<ParallaxMaterial>)>,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+    clock: Res<ParallaxClock>,
+    focus_depth: Option<Res<ParallaxFocusDepth>>,
+) {
+    for (entity, mut fade_in, material) in parallax_layer_query.iter_mut() {
+        fade_in.advance(clock.delta());
+        if focus_depth.is_none() {
+            if let Some(material) = materials.get_mut(material) {
+                material.set_opacity(fade_in.opacity());
+            }
+        }
+
+        if fade_in.remaining() <= 0.0 {
+            commands.entity(entity).remove::<ParallaxFadeIn>();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn move_parallax_layers(
+    mut parallax_layer_query: Query<
+        (
+            &mut Transform,
+            &mut ParallaxLayerData,
+            &Handle<ParallaxMaterial>,
+            Option<&ParallaxTargetCamera>,
+        ),
+        (With<ParallaxLayerProcessed>, Without<ParallaxCamera>),
+    >,
+    camera_query: Query<(
+        Entity,
+        &Transform,
+        &ParallaxCamera,
+        &OrthographicProjection,
+        Option<&Camera>,
+    )>,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+    parallax_context: Res<ParallaxContext>,
+    camera_velocity: Res<ParallaxCameraVelocity>,
+    beat_pulse: Res<ParallaxBeatPulse>,
+    #[cfg(feature = "diagnostics")] mut diagnostics: Diagnostics,
+) {
+    if camera_query.is_empty() {
+        return;
+    }
+
+    #[cfg(feature = "diagnostics")]
+    let start = Instant::now();
+
+    let primary = primary_camera(
+        camera_query
+            .iter()
+            .map(|(entity, _, camera, _, _)| (entity, camera)),
+    );
+    let pulse_offset = beat_pulse.offset();
+
+    // Translation, rotation, velocity and screen pixel size of each camera
+    let cameras: HashMap<_, _> = camera_query
+        .iter()
+        .map(|(entity, transform, _, projection, camera)| {
+            let camera_translation = transform.translation.truncate();
+            let camera_angle = camera_angle(transform);
+            let camera_velocity = camera_velocity
+                .camera_velocity(entity)
+                .unwrap_or(Vec2::ZERO);
+            let pixel_size = camera_pixel_size(projection, camera);
+            (
+                entity,
+                (
+                    camera_translation,
+                    camera_angle,
+                    camera_velocity,
+                    pixel_size,
+                ),
+            )
+        })
+        .collect();
+
+    for (mut transform, mut parallax, material, target_camera) in parallax_layer_query.iter_mut() {
+        let Some(&(camera_translation, camera_angle, camera_velocity, pixel_size)) =
+            cameras.get(&layer_camera(target_camera, primary))
+        else {
+            continue;
+        };
+        let pixel_snap = parallax_context.pixel_grid().is_none()
+            && parallax.flags.contains(ParallaxFlags::PIXEL_SNAP);
+
+        let translation = Vec2::select(
+            parallax.camera_local(),
+            camera_translation,
+            translation_with_depth_and_flags(camera_translation, parallax.depth, parallax.flags),
+        );
+        let (layer_shift, mut texture_shift) = parallax.split_boost_shift(camera_translation);
+
+        // The beat pulse moves the layer, or scrolls its texture where the layer follows the camera
+        let mut layer_pulse = pulse_offset * parallax.depth.depth_factor_xy().unwrap_or(Vec2::ZERO);
+        if parallax.flags.contains(ParallaxFlags::LOCKED_X_AXIS) {
+            layer_pulse.x = 0.0;
+        }
+        if parallax.flags.contains(ParallaxFlags::LOCKED_Y_AXIS) {
+            layer_pulse.y = 0.0;
+        }
+        let layer_pulse = Vec2::select(parallax.camera_local(), Vec2::ZERO, layer_pulse);
+        let scrolls_texture = parallax.scrolls_texture();
+        texture_shift += Vec2::select(scrolls_texture, layer_pulse, Vec2::ZERO);
+
+        let mut translation = translation
+            + parallax.offset
+            + layer_shift
+            + Vec2::select(scrolls_texture, Vec2::ZERO, layer_pulse);
+        translation = clamp_to_extent(translation, camera_translation, parallax.clamp_extent);
+
+        // Turns with the camera like it follows its translation, by the mean factor of the axes
+        let turn = match parallax.flags.contains(ParallaxFlags::LOCK_ROTATION) {
+            true => 0.0,
+            false => {
+                let factor = parallax.effective_factor();
+                camera_angle * (1.0 - (factor.x + factor.y) / 2.0)
+            }
+        };
+        if turn != 0.0 {
+            // The layer turns around the camera, the same as its content
+            translation = camera_translation
+                + Vec2::from_angle(turn).rotate(translation - camera_translation);
+        }
+        let pixel_grid = match pixel_snap {
+            true => Some(pixel_size),
+            false => parallax_context.pixel_grid(),
+        };
+        if let Some(pixel_grid) = pixel_grid {
+            // Snap to the pixel grid relative to the camera
+            let relative_translation = translation - camera_translation;
+            translation =
+                camera_translation + (relative_translation / pixel_grid).round() * pixel_grid;
+        }
+
+        transform.translation = translation.extend(transform.translation.z);
+        if turn != parallax.camera_turn {
+            // Applied on top of the rotation of the layer itself
+            transform.rotation = (Quat::from_rotation_z(turn - parallax.camera_turn)
+                * transform.rotation)
+                .normalize();
+            parallax.bypass_change_detection().camera_turn = turn;
+        }
+
+        // Content stationary in the world moves against the camera on screen
+        let velocity = -camera_velocity * parallax.effective_factor();
+
+        let rendered_size = parallax.rendered_size(&transform);
+        let uv_shift = ParallaxMaterial::uv_shift(texture_shift, rendered_size);
+        set_material_if_changed(
+            &mut materials,
+            material,
+            |material| {
+                material.shift() != uv_shift
+                    || material.velocity() != velocity
+                    || pixel_snap && material.pixel_snap() != rendered_size / pixel_size
+            },
+            |material| {
+                material.set_shift(uv_shift).set_velocity(velocity);
+                if pixel_snap {
+                    // The pixel grid follows the zoom of the camera
+                    material.set_pixel_snap(rendered_size / pixel_size);
+                }
+            },
+        );
+    }
+
+    #[cfg(feature = "diagnostics")]
+    ParallaxDiagnostics::measure(&mut diagnostics, &ParallaxDiagnostics::MOVE_LAYERS, start);
+}
+
+/// Returns the size of a screen pixel of a camera in world units.
+///
+/// Without a viewport size, such as before the camera is first rendered, a pixel is assumed to
+/// be the scale of the projection, as with the default scaling mode.
+#[inline]
+fn camera_pixel_size(projection: &OrthographicProjection, camera: Option<&Camera>) -> f32 {
+    camera
+        .and_then(Camera::physical_viewport_size)
+        .filter(|viewport_size| viewport_size.x > 0)
+        .map_or(projection.scale, |viewport_size| {
+            projection.area.width() / viewport_size.x as f32
+        })
+}
+
+/// Applies the [`ParallaxAlpha`] of the layers to their material, resetting it once removed.
+#[allow(clippy::type_complexity)]
+pub(crate) fn apply_parallax_alpha(
+    parallax_layer_query: Query<
+        (&ParallaxAlpha, &Handle<ParallaxMaterial>),
+        Or<(Changed<ParallaxAlpha>, Changed<Handle<ParallaxMaterial>>)>,
+    >,
+    material_query: Query<&Handle<ParallaxMaterial>>,
+    mut removed_alphas: RemovedComponents<ParallaxAlpha>,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+) {
+    let removed = removed_alphas
+        .read()
+        .filter_map(|entity| material_query.get(entity).ok())
+        .map(|material| (1.0, material));
+    let changed = parallax_layer_query
+        .iter()
+        .map(|(alpha, material)| (alpha.0, material));
+
+    for (alpha, material) in removed.chain(changed) {
+        if let Some(material) = materials.get_mut(material) {
+            material.set_alpha(alpha);
+        }
+    }
+}
+
+/// Applies the LUT of the [`ParallaxColorGrading`] to the material of every parallax layer,
+/// removing it once the resource is removed.
+pub(crate) fn apply_parallax_color_grading(
+    material_query: Query<&Handle<ParallaxMaterial>>,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+    color_grading: Option<Res<ParallaxColorGrading>>,
+) {
+    let lut = color_grading.map(|color_grading| color_grading.lut.clone());
+
+    for material in material_query.iter() {
+        set_material_if_changed(
+            &mut materials,
+            material,
+            |material| material.color_lut() != lut.as_ref(),
+            |material| {
+                material.set_color_lut(lut.clone());
+            },
+        );
+    }
+}
+
+/// Moves the circle of every [`ParallaxCursorMask`] to the cursor over the primary window.
+///
+/// Holds the last position of the cursor while it is outside of the window, hiding the layers
+/// until the cursor first enters it.
+pub(crate) fn update_parallax_cursor_masks(
+    parallax_layer_query: Query<(&ParallaxCursorMask, &Handle<ParallaxMaterial>)>,
+    material_query: Query<&Handle<ParallaxMaterial>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut removed_cursor_masks: RemovedComponents<ParallaxCursorMask>,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+    mut last_cursor: Local<Option<Vec2>>,
+) {
+    for entity in removed_cursor_masks.read() {
+        if let Some(material) = material_query
+            .get(entity)
+            .ok()
+            .and_then(|material| materials.get_mut(material))
+        {
+            material.set_cursor_mask(None);
+        }
+    }
+
+    let window = window_query.get_single().ok();
+    if let Some(cursor) = window.and_then(Window::physical_cursor_position) {
+        *last_cursor = Some(cursor);
+    }
+    let scale_factor = window.map_or(1.0, Window::scale_factor);
+
+    for (cursor_mask, material) in parallax_layer_query.iter() {
+        let cursor_mask = match *last_cursor {
+            Some(cursor) => (cursor, cursor_mask.radius * scale_factor),
+            None => (Vec2::ZERO, 0.0),
+        };
+
+        set_material_if_changed(
+            &mut materials,
+            material,
+            |material| material.cursor_mask() != Some(cursor_mask),
+            |material| {
+                material.set_cursor_mask(Some(cursor_mask));
+            },
+        );
+    }
+}
+
+pub(crate) fn shimmer_parallax_layers(
+    parallax_layer_query: Query<
+        (
+            &Transform,
+            &ParallaxLayerData,
+            &ParallaxShimmer,
+            &Handle<ParallaxMaterial>,
+        ),
+        With<ParallaxLayerProcessed>,
+    >,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+    clock: Res<ParallaxClock>,
+) {
+    for (transform, parallax, shimmer, material) in parallax_layer_query.iter() {
+        let shimmer = ParallaxMaterial::uv_shift(
+            shimmer.displacement(clock.elapsed()),
+            parallax.rendered_size(transform),
+        );
+
+        set_material_if_changed(
+            &mut materials,
+            material,
+            |material| material.shimmer() != shimmer,
+            |material| {
+                material.set_shimmer(shimmer);
+            },
+        );
+    }
+}
+
+/// Rotates the textures of the parallax layers with a [`ParallaxUvRotation`].
+pub(crate) fn rotate_parallax_layer_uvs(
+    parallax_layer_query: Query<
+        (
+            &Transform,
+            &ParallaxLayerData,
+            &ParallaxUvRotation,
+            &Handle<ParallaxMaterial>,
+        ),
+        With<ParallaxLayerProcessed>,
+    >,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+    clock: Res<ParallaxClock>,
+) {
+    for (transform, parallax, uv_rotation, material) in parallax_layer_query.iter() {
+        let angle = uv_rotation.angle(clock.elapsed());
+
+        set_material_if_changed(
+            &mut materials,
+            material,
+            |material| material.uv_rotation() != angle,
+            |material| {
+                material.set_uv_rotation(angle, parallax.rendered_size(transform).abs());
+            },
+        );
+    }
+}
+
+/// Fades the layers by their distance from the [`ParallaxFocusDepth`], multiplied by the
+/// opacity of a [`ParallaxFadeIn`] while fading in.
+#[allow(clippy::type_complexity)]
+pub(crate) fn fade_parallax_layers_by_focus(
+    parallax_layer_query: Query<
+        (
+            &ParallaxLayerData,
+            &Handle<ParallaxMaterial>,
+            Option<&ParallaxFadeIn>,
+        ),
+        With<ParallaxLayerProcessed>,
+    >,
+    mut materials: ResMut<Assets<ParallaxMaterial>>,
+    focus_depth: Res<ParallaxFocusDepth>,
+    parallax_context: Res<ParallaxContext>,
+) {
+    for (parallax, material, fade_in) in parallax_layer_query.iter() {
+        let opacity = focus_depth.opacity(parallax.depth.depth(), &parallax_context)
+            * fade_in.map_or(1.0, ParallaxFadeIn::opacity);
+
+        set_material_if_changed(
+            &mut materials,
+            material,
+            |material| material.opacity() != opacity,
+            |material| {
+                material.set_opacity(opacity);
+            },
+        );
+    }
+}
+
+pub(crate) fn update_parallax_clear_color(
+    mut clear_color: ResMut<ClearColor>,
+    gradient: Res<ParallaxClearColorGradient>,
+    camera_query: Query<(&Transform, &ParallaxCamera)>,
+) {
+    if camera_query.is_empty() {
+        return;
+    }
+
+    let camera_transform = primary_camera(camera_query.iter());
+
+    let Some(color) = gradient.color_at(camera_transform.translation.truncate()) else {
+        return;
+    };
+
+    if clear_color.0 != color {
+        clear_color.0 = color;
+    }
+}
+
+/// Keeps the bounds of the parallax layers in sync with their transforms.
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_parallax_layer_bounds(
+    mut parallax_layer_query: Query<
+        (&Transform, &ParallaxLayerData, &mut ParallaxLayerBounds),
+        (With<ParallaxLayerProcessed>, Changed<Transform>),
+    >,
+) {
+    for (transform, parallax, mut bounds) in parallax_layer_query.iter_mut() {
+        let rect = Rect::from_center_size(
+            transform.translation.truncate(),
+            parallax.rendered_size(transform).abs(),
+        );
+        let repeats = BVec2::new(
+            parallax.flags.contains(ParallaxFlags::REPEAT_X_AXIS),
+            parallax.flags.contains(ParallaxFlags::REPEAT_Y_AXIS),
+        );
+        let rect = Rect {
+            min: Vec2::select(repeats, Vec2::NEG_INFINITY, rect.min),
+            max: Vec2::select(repeats, Vec2::INFINITY, rect.max),
+        };
+
+        if bounds.0 != rect {
+            bounds.0 = rect;
+        }
+    }
+}
+
+/// Spawns the layers of the stream regions which their camera entered, and despawns the layers
+/// of the regions which their camera left or which were removed.
+pub(crate) fn stream_parallax_regions(
+    mut commands: Commands,
+    mut stream_region_query: Query<(Entity, &mut ParallaxStreamRegion)>,
+    streamed_layer_query: Query<(Entity, &ParallaxStreamedLayer)>,
+    mut removed_regions: RemovedComponents<ParallaxStreamRegion>,
+    camera_query: Query<(Entity, &Transform, &ParallaxCamera)>,
+) {
+    // A removed region no longer despawns its layer, unless a new region took its place
+    let removed: HashSet<_> = removed_regions.read().collect();
+    if !removed.is_empty() {
+        for (layer, streamed) in streamed_layer_query.iter() {
+            let is_spawned = stream_region_query
+                .get(streamed.0)
+                .is_ok_and(|(_, region)| region.spawned == Some(layer));
+            if removed.contains(&streamed.0) && !is_spawned {
+                commands.entity(layer).despawn();
+            }
+        }
+    }
+
+    if stream_region_query.is_empty() {
+        return;
+    }
+
+    // Without a primary camera, only the regions of layers bound to a camera can be entered
+    let primary = camera_query
+        .iter()
+        .find_map(|(entity, _, camera)| camera.primary.then_some(entity));
+
+    for (entity, mut region) in stream_region_query.iter_mut() {
+        let camera = region.layer.target_camera.or(primary);
+        let is_inside = camera
+            .and_then(|camera| camera_query.get(camera).ok())
+            .is_some_and(|(_, transform, _)| {
+                region.bounds.contains(transform.translation.truncate())
+            });
+
+        match (is_inside, region.spawned) {
+            (true, None) => {
+                let layer = (region.layer.clone(), ParallaxStreamedLayer(entity));
+                region.spawned = Some(commands.spawn(layer).id());
+            }
+            (false, Some(layer)) => {
+                if let Some(mut layer_commands) = commands.get_entity(layer) {
+                    layer_commands.despawn();
+                }
+                region.spawned = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Draws the outline and the depth factor of every processed parallax layer.
+#[cfg(feature = "debug_gizmos")]
+pub(crate) fn draw_parallax_debug_gizmos(
+    mut gizmos: Gizmos,
+    parallax_layer_query: Query<(&Transform, &ParallaxLayerData), With<ParallaxLayerProcessed>>,
+) {
+    for (transform, parallax) in parallax_layer_query.iter() {
+        let center = transform.translation.truncate();
+        let size = parallax.rendered_size(transform).abs();
+        let color = depth_factor_color(parallax.depth.depth_factor());
+
+        gizmos.rect_2d(center, 0.0, size, color);
+        gizmos.circle_2d(center, 0.025 * size.min_element(), color);
+    }
+}
+
+/// Color of a depth factor, red at `1.0` moving with the world, blue at `0.0` moving with the
+/// camera, and white for a depth not yet resolved.
+#[cfg(feature = "debug_gizmos")]
+#[inline]
+fn depth_factor_color(depth_factor: Option<f32>) -> Color {
+    match depth_factor {
+        Some(depth_factor) => Color::hsl(240.0 * (1.0 - depth_factor.clamp(0.0, 1.0)), 1.0, 0.5),
+        None => Color::WHITE,
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_parallax_scene_depth(
+    mut scene_depth: ResMut<ParallaxSceneDepth>,
+    parallax_layer_query: Query<
+        (&Transform, &ParallaxLayerData, Option<&Visibility>),
+        (With<ParallaxLayerProcessed>, Without<ParallaxCamera>),
+    >,
+    camera_query: Query<((&Transform, &OrthographicProjection), &ParallaxCamera)>,
+) {
+    if camera_query.is_empty() {
+        return;
+    }
+
+    let (camera_transform, camera_projection) = primary_camera(camera_query.iter());
+
+    let camera_translation = camera_transform.translation.truncate();
+    let view = Rect {
+        min: camera_projection.area.min + camera_translation,
+        max: camera_projection.area.max + camera_translation,
+    };
+
+    let layers = parallax_layer_query
+        .iter()
+        .filter(|(_, _, visibility)| *visibility != Some(&Visibility::Hidden))
+        .filter_map(|(transform, parallax, _)| {
+            let depth_factor = parallax.depth.depth_factor()?;
+            let rect = Rect::from_center_size(
+                transform.translation.truncate(),
+                parallax.rendered_size(transform).abs(),
+            );
+            Some((rect, depth_factor))
+        });
+
+    let average_depth_factor = area_weighted_depth_factor(view, layers).unwrap_or_default();
+    if scene_depth.0 != average_depth_factor {
+        scene_depth.0 = average_depth_factor;
+    }
+}
+
+/// Computes the average depth factor of the given layers, weighted by the area of each layer
+/// inside the `view`. Returns `None` if no layer is inside the view.
+#[inline]
+#[must_use]
+fn area_weighted_depth_factor(
+    view: Rect,
+    layers: impl IntoIterator<Item = (Rect, f32)>,
+) -> Option<f32> {
+    let (weighted_sum, total_area) =
+        layers
+            .into_iter()
+            .fold((0.0, 0.0), |(weighted_sum, total_area), (rect, factor)| {
+                let visible = view.intersect(rect);
+                let area = match visible.is_empty() {
+                    true => 0.0,
+                    false => visible.width() * visible.height(),
+                };
+
+                (weighted_sum + factor * area, total_area + area)
+            });
+
+    match total_area > 0.0 {
+        true => Some(weighted_sum / total_area),
+        false => None,
+    }
+}
+
+#[inline]
+#[must_use]
+fn translation_with_depth_and_flags(translation: Vec2, depth: Depth, flags: ParallaxFlags) -> Vec2 {
+    let Some(depth_factor) = depth.depth_factor_xy() else {
+        // No depth factor is treated as 0.0
+        return Vec2::ZERO;
+    };
+
+    // Texture scrolling axes follow the camera, the texture offset handles the depth factor
+    let (layer_factor, _) = axis_factors(depth_factor, flags);
+    translation - translation * layer_factor
+}
+
+/// Limits the distance of a layer from its camera on the clamped axes, see
+/// [`ParallaxFlags::CLAMP_X_AXIS`]. The other axes have an infinite extent.
+#[inline]
+fn clamp_to_extent(translation: Vec2, camera_translation: Vec2, clamp_extent: Vec2) -> Vec2 {
+    camera_translation + (translation - camera_translation).clamp(-clamp_extent, clamp_extent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        commands::{ParallaxCameraCommands, ParallaxLayerCommands, ParallaxReprocessAll},
+        components::OffsetSpace,
+        resources::{GradientAxis, NeutralSemantics, ParallaxConfig, ParallaxProfile},
+    };
+    use bevy::{
+        ecs::system::{CommandQueue, RunSystemOnce},
+        prelude::{
+            resource_exists, Color, Events, IntoSystemConfigs, Schedule, UVec2, Vec4, World,
+        },
+        render::texture::{ImageFilterMode, ImageSampler},
+        render::{
+            render_asset::RenderAssetUsages,
+            render_resource::{Extent3d, TextureDimension, TextureFormat},
+        },
+        utils::Duration,
+    };
+
+    fn test_image(size: UVec2) -> Image {
+        Image::new_fill(
+            Extent3d {
+                width: size.x,
+                height: size.y,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[255; 4],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    fn test_world(camera_size: Vec2) -> World {
+        let mut world = World::default();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<Assets<ParallaxMaterial>>();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<ParallaxMesh>();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<ParallaxTeleport>>();
+        world.init_resource::<Events<ParallaxLayerReady>>();
+        world.init_resource::<Events<ParallaxLayerLoadFailed>>();
+        world.init_resource::<ParallaxBeatPulse>();
+        world.init_resource::<ParallaxCameraVelocity>();
+        #[cfg(feature = "diagnostics")]
+        world.init_resource::<bevy::diagnostic::DiagnosticsStore>();
+        world.insert_resource(ParallaxContext::new(ParallaxConfig::default()));
+        world.spawn((
+            ParallaxCamera::default(),
+            Transform::default(),
+            OrthographicProjection {
+                area: Rect::from_center_size(Vec2::ZERO, camera_size),
+                ..default()
+            },
+        ));
+
+        world
+    }
+
+    fn spawn_test_layer(
+        world: &mut World,
+        image_size: UVec2,
+        parallax: ParallaxLayerData,
+    ) -> Entity {
+        let image = world
+            .resource_mut::<Assets<Image>>()
+            .add(test_image(image_size));
+        let material = world
+            .resource_mut::<Assets<ParallaxMaterial>>()
+            .add(ParallaxMaterial::new(image, Color::WHITE));
+
+        world.spawn((Transform::default(), parallax, material)).id()
+    }
+
+    fn test_process_schedule() -> Schedule {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                mark_parallax_layers_on_context_change,
+                mark_parallax_layers_on_camera_change,
+                process_new_parallax_layer_data,
+            )
+                .chain(),
+        );
+
+        schedule
+    }
+
+    #[test]
+    fn systems_translation() {
+        let context = ParallaxContext::new(ParallaxConfig::default());
+        let depth = Depth::from_parallax(10.0);
+        let translation_0 =
+            translation_with_depth_and_flags(Vec2::ZERO, depth, ParallaxFlags::NONE);
+
+        let depth = depth.to_world_with_factor(&context);
 
         let translation_1 = translation_with_depth_and_flags(Vec2::ONE, depth, ParallaxFlags::NONE);
         let translation_2 =
@@ -220,14 +1875,3149 @@ mod tests {
             depth,
             ParallaxFlags::LOCKED_X_AXIS | ParallaxFlags::LOCKED_Y_AXIS,
         );
-        let translation_4 =
-            translation_with_depth_and_flags(Vec2::ONE, depth, ParallaxFlags::REPEAT_X_AXIS);
+        let translation_4 =
+            translation_with_depth_and_flags(Vec2::ONE, depth, ParallaxFlags::REPEAT_X_AXIS);
+
+        assert_eq!(depth.depth_factor(), Some(0.5));
+        assert_eq!(translation_0, Vec2::ZERO);
+        assert_eq!(translation_1, Vec2::splat(0.5));
+        assert_eq!(translation_2, Vec2::new(0.0, 0.5));
+        assert_eq!(translation_3, Vec2::ZERO);
+        assert_eq!(translation_4, Vec2::new(1.0, 0.5));
+    }
+
+    #[test]
+    fn systems_reprocess_changed_layers() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+
+        let mut schedule = test_process_schedule();
+
+        // Initial processing
+        schedule.run(&mut world);
+        let depth = world.get::<ParallaxLayerData>(layer).unwrap().depth();
+        assert_eq!(depth.depth(), -10.0);
+        assert_eq!(depth.depth_factor(), Some(0.5));
+
+        // Changing the context reprocesses the layer
+        world.resource_mut::<ParallaxContext>().set_scale(2.0);
+        schedule.run(&mut world);
+        let depth = world.get::<ParallaxLayerData>(layer).unwrap().depth();
+        assert_eq!(depth.depth(), -10.0);
+        assert_eq!(depth.depth_factor(), Some(1.0));
+
+        // Changing the layer reprocesses the layer
+        world
+            .get_mut::<ParallaxLayerData>(layer)
+            .unwrap()
+            .set_depth(30.0);
+        schedule.run(&mut world);
+        let depth = world.get::<ParallaxLayerData>(layer).unwrap().depth();
+        assert_eq!(depth.depth(), -30.0);
+        assert_eq!(depth.depth_factor(), Some(0.5));
+        assert_eq!(world.get::<Transform>(layer).unwrap().translation.z, -30.0);
+
+        // Nothing changed, the resolved values stay the same
+        schedule.run(&mut world);
+        let depth = world.get::<ParallaxLayerData>(layer).unwrap().depth();
+        assert_eq!(depth.depth(), -30.0);
+        assert_eq!(depth.depth_factor(), Some(0.5));
+    }
+
+    #[test]
+    fn systems_area_weighted_depth_factor() {
+        let view = Rect::from_center_size(Vec2::ZERO, Vec2::splat(100.0));
+
+        // Covers the whole view
+        let background = (Rect::from_center_size(Vec2::ZERO, Vec2::splat(200.0)), 0.5);
+        // A quarter of the view
+        let foreground = (Rect::from_center_size(Vec2::ZERO, Vec2::splat(50.0)), 2.0);
+        // Outside the view
+        let hidden = (
+            Rect::from_center_size(Vec2::X * 200.0, Vec2::splat(50.0)),
+            10.0,
+        );
+        // Half inside the view
+        let partial = (
+            Rect::from_center_size(Vec2::X * 50.0, Vec2::splat(50.0)),
+            4.0,
+        );
+
+        assert_eq!(area_weighted_depth_factor(view, []), None);
+        assert_eq!(area_weighted_depth_factor(view, [hidden]), None);
+        assert_eq!(area_weighted_depth_factor(view, [background]), Some(0.5));
+        assert_eq!(
+            area_weighted_depth_factor(view, [background, foreground, hidden]),
+            Some(0.8)
+        );
+        // (10000 * 0.5 + 1250 * 4.0) / 11250
+        assert_eq!(
+            area_weighted_depth_factor(view, [background, partial]),
+            Some(10000.0 / 11250.0)
+        );
+    }
+
+    #[test]
+    fn systems_pixel_scroll() {
+        let mut world = test_world(Vec2::splat(128.0));
+        let image_size = UVec2::splat(4);
+        let layer = spawn_test_layer(
+            &mut world,
+            image_size,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        );
+        world.entity_mut(layer).insert(ParallaxPixelScroll {
+            pixels_per_unit: Vec2::new(2.0, 3.0),
+        });
+
+        test_process_schedule().run(&mut world);
+
+        let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+        let material = world
+            .resource::<Assets<ParallaxMaterial>>()
+            .get(material)
+            .unwrap();
+
+        // Moving the camera 16 units scrolls the texture 2 * 16 pixels along the repeating axis
+        let start = material.tiling_phase(Vec2::ZERO);
+        let end = material.tiling_phase(Vec2::splat(16.0));
+        let scrolled_pixels = (end - start) * image_size.as_vec2();
+        assert_eq!(scrolled_pixels, Vec2::new(32.0, 0.0));
+    }
+
+    #[test]
+    fn systems_move_processed_layers() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+
+        let mut move_schedule = Schedule::default();
+        move_schedule.add_systems(move_parallax_layers);
+
+        let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+        camera_query.single_mut(&mut world).translation = Vec3::new(8.0, 8.0, 0.0);
+
+        // Unprocessed layers are not moved
+        move_schedule.run(&mut world);
+        assert!(world.get::<ParallaxLayerProcessed>(layer).is_none());
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().translation,
+            Vec3::ZERO
+        );
+
+        // Processed layers are moved with respect to their depth factor
+        test_process_schedule().run(&mut world);
+        move_schedule.run(&mut world);
+        assert!(world.get::<ParallaxLayerProcessed>(layer).is_some());
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().translation,
+            Vec3::new(4.0, 4.0, -10.0)
+        );
+    }
+
+    #[test]
+    fn systems_scroll_uv_clamped_layer() {
+        let mut world = test_world(Vec2::splat(128.0));
+        let image_size = UVec2::new(32, 16);
+        let layer = spawn_test_layer(
+            &mut world,
+            image_size,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::SCROLL_UV_X_AXIS),
+        );
+
+        test_process_schedule().run(&mut world);
+
+        // The layer keeps its native size and clamps its texture
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().scale,
+            image_size.as_vec2().extend(1.0)
+        );
+        let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+        let material = world
+            .resource::<Assets<ParallaxMaterial>>()
+            .get(material)
+            .unwrap();
+        let image = world
+            .resource::<Assets<Image>>()
+            .get(material.image_handle())
+            .unwrap();
+        assert!(matches!(
+            &image.sampler,
+            ImageSampler::Descriptor(ImageSamplerDescriptor {
+                address_mode_u: ImageAddressMode::ClampToEdge,
+                address_mode_v: ImageAddressMode::ClampToEdge,
+                ..
+            })
+        ));
+
+        // The texture pans by the depth factor along X, in UV space of the layer
+        let start = material.tiling_phase(Vec2::ZERO);
+        let end = material.tiling_phase(Vec2::splat(16.0));
+        assert_eq!(end - start, Vec2::new(16.0 * 0.5 / 32.0, 0.0));
+
+        // While the layer itself follows the camera along X
+        let mut move_schedule = Schedule::default();
+        move_schedule.add_systems(move_parallax_layers);
+        let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+        camera_query.single_mut(&mut world).translation = Vec3::new(16.0, 16.0, 0.0);
+        move_schedule.run(&mut world);
+
+        let translation = world.get::<Transform>(layer).unwrap().translation;
+        assert_eq!(translation.truncate(), Vec2::new(16.0, 8.0));
+    }
+
+    #[test]
+    fn systems_advance_parallax_clock() {
+        let mut world = World::default();
+        world.init_resource::<Time>();
+        world.init_resource::<ParallaxClock>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(advance_parallax_clock);
+
+        let mut advance = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.5));
+            schedule.run(world);
+        };
+
+        advance(&mut world);
+        assert_eq!(world.resource::<ParallaxClock>().elapsed(), 0.5);
+
+        // Paused clock does not advance while the real clock does
+        world.resource_mut::<ParallaxClock>().paused = true;
+        advance(&mut world);
+        assert_eq!(world.resource::<Time>().elapsed_seconds(), 1.0);
+        assert_eq!(world.resource::<ParallaxClock>().elapsed(), 0.5);
+        assert_eq!(world.resource::<ParallaxClock>().delta(), 0.0);
+
+        // Time-scaled clock
+        let mut clock = world.resource_mut::<ParallaxClock>();
+        clock.paused = false;
+        clock.speed = 0.5;
+        advance(&mut world);
+        assert_eq!(world.resource::<ParallaxClock>().elapsed(), 0.75);
+        assert_eq!(world.resource::<ParallaxClock>().delta(), 0.25);
+    }
+
+    #[test]
+    fn systems_pause_clock_when_unfocused() {
+        let mut world = test_world(Vec2::splat(128.0));
+        world.init_resource::<ParallaxClock>();
+        let window = world
+            .spawn((
+                Window {
+                    focused: false,
+                    ..default()
+                },
+                PrimaryWindow,
+            ))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(advance_parallax_clock);
+        let mut advance = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.5));
+            schedule.run(world);
+        };
+
+        // Unfocused window halts the clock
+        advance(&mut world);
+        assert_eq!(world.resource::<ParallaxClock>().elapsed(), 0.0);
+
+        world.get_mut::<Window>(window).unwrap().focused = true;
+        advance(&mut world);
+        assert_eq!(world.resource::<ParallaxClock>().elapsed(), 0.5);
+
+        // Opting out keeps the clock running
+        world.get_mut::<Window>(window).unwrap().focused = false;
+        world.resource_mut::<ParallaxClock>().pause_when_unfocused = false;
+        advance(&mut world);
+        assert_eq!(world.resource::<ParallaxClock>().elapsed(), 1.0);
+    }
+
+    #[test]
+    fn systems_tile_origin() {
+        let mut world = test_world(Vec2::splat(128.0));
+        let tile_origin = Vec2::new(40.0, 24.0);
+        let tile_size = UVec2::splat(16);
+        let flags = ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS;
+
+        let layers = [10.0_f32, 40.0].map(|depth| {
+            let mut parallax = ParallaxLayerData::new(depth.into(), Vec2::new(3.0, -5.0), flags);
+            parallax.set_tile_origin(Some(tile_origin));
+            spawn_test_layer(&mut world, tile_size, parallax)
+        });
+
+        let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+        camera_query.single_mut(&mut world).translation = tile_origin.extend(0.0);
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(move_parallax_layers.after(process_new_parallax_layer_data));
+        schedule.run(&mut world);
+
+        for layer in layers {
+            let transform = world.get::<Transform>(layer).unwrap();
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let material = world
+                .resource::<Assets<ParallaxMaterial>>()
+                .get(material)
+                .unwrap();
+
+            // Tile boundaries at the tile origin, and one tile further along each axis
+            for world_position in [tile_origin, tile_origin + tile_size.as_vec2()] {
+                let tile = material.tile_coordinate(
+                    tile_origin,
+                    transform.translation.truncate(),
+                    transform.scale.truncate(),
+                    world_position,
+                );
+                assert!((tile - tile.round()).abs().max_element() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn systems_speed_boost() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.init_resource::<ParallaxClock>();
+        world
+            .resource_mut::<ParallaxContext>()
+            .set_speed_boost(1.0, 10.0);
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(
+            (
+                track_parallax_camera_velocities,
+                boost_parallax_layers_with_camera_speed,
+                move_parallax_layers,
+            )
+                .chain()
+                .after(process_new_parallax_layer_data),
+        );
+        let mut run_at = |world: &mut World, camera_translation: Vec3| {
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(world).translation = camera_translation;
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.5));
+            world.resource_mut::<ParallaxClock>().advance(0.5);
+            schedule.run(world);
+        };
+        let boost_and_translation = |world: &World| {
+            (
+                world.get::<ParallaxLayerData>(layer).unwrap().factor_boost,
+                world.get::<Transform>(layer).unwrap().translation,
+            )
+        };
+
+        // At rest the movement is unchanged
+        run_at(&mut world, Vec3::ZERO);
+        run_at(&mut world, Vec3::ZERO);
+        assert_eq!(
+            boost_and_translation(&world),
+            (1.0, Vec3::new(0.0, 0.0, -10.0))
+        );
+
+        // Moving at the reference speed doubles the depth factor of 0.5
+        run_at(&mut world, Vec3::new(5.0, 0.0, 0.0));
+        assert_eq!(
+            boost_and_translation(&world),
+            (2.0, Vec3::new(0.0, 0.0, -10.0))
+        );
+
+        // The boost stays while the clock is paused, and wears off by the speed of the clock
+        world.resource_mut::<ParallaxClock>().paused = true;
+        run_at(&mut world, Vec3::new(5.0, 0.0, 0.0));
+        assert_eq!(
+            boost_and_translation(&world),
+            (2.0, Vec3::new(0.0, 0.0, -10.0))
+        );
+        let mut clock = world.resource_mut::<ParallaxClock>();
+        clock.paused = false;
+        clock.speed = 0.125;
+        run_at(&mut world, Vec3::new(5.0, 0.0, 0.0));
+        assert_eq!(
+            boost_and_translation(&world),
+            (1.5, Vec3::new(1.25, 0.0, -10.0))
+        );
+
+        // Back at rest, the boost wears off without leaving a displacement
+        world.resource_mut::<ParallaxClock>().speed = 1.0;
+        run_at(&mut world, Vec3::new(5.0, 0.0, 0.0));
+        assert_eq!(
+            boost_and_translation(&world),
+            (1.0, Vec3::new(2.5, 0.0, -10.0))
+        );
+    }
+
+    #[test]
+    fn systems_shared_depth() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.init_resource::<ParallaxDepthRegistry>();
+        let handle = world.resource_mut::<ParallaxDepthRegistry>().add(10.0);
+
+        let layers = [0.0_f32, 50.0].map(|depth| {
+            let parallax = ParallaxLayerData::new(depth.into(), Vec2::ZERO, ParallaxFlags::NONE);
+            let layer = spawn_test_layer(&mut world, UVec2::ONE, parallax);
+            world.entity_mut(layer).insert(handle);
+            layer
+        });
+        let other = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(50.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(sync_shared_parallax_depths.before(process_new_parallax_layer_data));
+
+        schedule.run(&mut world);
+        for layer in layers {
+            let depth = world.get::<ParallaxLayerData>(layer).unwrap().depth();
+            assert_eq!(depth.depth(), -10.0);
+            assert_eq!(depth.depth_factor(), Some(0.5));
+        }
+
+        // Changing the shared depth updates both layers
+        world
+            .resource_mut::<ParallaxDepthRegistry>()
+            .set(handle, 30.0);
+        schedule.run(&mut world);
+        for layer in layers {
+            let depth = world.get::<ParallaxLayerData>(layer).unwrap().depth();
+            assert_eq!(depth.depth(), -30.0);
+            assert_eq!(depth.depth_factor(), Some(0.25));
+        }
+
+        // Layers without the handle are unaffected
+        let depth = world.get::<ParallaxLayerData>(other).unwrap().depth();
+        assert_eq!(depth.depth(), -50.0);
+    }
+
+    #[test]
+    fn systems_pixel_perfect() {
+        let mut world = test_world(Vec2::new(64.0, 48.0));
+        world
+            .resource_mut::<ParallaxContext>()
+            .set_pixel_perfect(Some((1.0, 2)));
+        let pixel_grid = 0.5;
+
+        let layers = [
+            (5.0_f32, ParallaxFlags::NONE),
+            (13.0, ParallaxFlags::DEFAULT),
+            (
+                37.0,
+                ParallaxFlags::REPEAT_Y_AXIS | ParallaxFlags::OFFSET_CAMERA_RIGHT,
+            ),
+        ]
+        .map(|(depth, flags)| {
+            let parallax = ParallaxLayerData::new(depth.into(), Vec2::new(1.3, -2.1), flags);
+            spawn_test_layer(&mut world, UVec2::new(24, 10), parallax)
+        });
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(move_parallax_layers.after(process_new_parallax_layer_data));
+
+        let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+        for camera_translation in [Vec2::new(10.3, 7.7), Vec2::new(-3.1, 100.45)] {
+            camera_query.single_mut(&mut world).translation = camera_translation.extend(0.0);
+            schedule.run(&mut world);
+
+            for layer in layers {
+                let translation = world
+                    .get::<Transform>(layer)
+                    .unwrap()
+                    .translation
+                    .truncate();
+                let pixels = (translation - camera_translation) / pixel_grid;
+                assert!((pixels - pixels.round()).abs().max_element() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn systems_dissolve() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.init_resource::<ParallaxClock>();
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+        world
+            .entity_mut(layer)
+            .insert(ParallaxDissolve::reveal(2.0));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(animate_parallax_dissolve);
+
+        let mut advance = |world: &mut World, delta: f32| {
+            world.resource_mut::<ParallaxClock>().advance(delta);
+            schedule.run(world);
+
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let threshold = world
+                .resource::<Assets<ParallaxMaterial>>()
+                .get(material)
+                .unwrap()
+                .dissolve_threshold();
+            (
+                threshold,
+                world.get::<ParallaxDissolve>(layer).unwrap().is_finished(),
+            )
+        };
+
+        // The threshold is set when the dissolve gets added
+        assert_eq!(advance(&mut world, 0.0), (0.0, false));
+        assert_eq!(advance(&mut world, 1.0), (0.5, false));
+        assert_eq!(advance(&mut world, 1.5), (1.0, true));
+
+        // Replacing the dissolve starts a new transition
+        world.entity_mut(layer).insert(ParallaxDissolve::hide(1.0));
+        assert_eq!(advance(&mut world, 0.25), (0.75, false));
+        assert_eq!(advance(&mut world, 1.0), (0.0, true));
+    }
+
+    #[test]
+    fn systems_unshare_material() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.init_resource::<ParallaxClock>();
+        let image = world
+            .resource_mut::<Assets<Image>>()
+            .add(test_image(UVec2::ONE));
+        let shared = world
+            .resource_mut::<Assets<ParallaxMaterial>>()
+            .add(ParallaxMaterial::new(image, Color::WHITE));
+        let [layer, other] = [(); 2].map(|_| {
+            let parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE);
+            world
+                .spawn((parallax, shared.clone(), ParallaxSharedMaterial))
+                .id()
+        });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((unshare_parallax_materials, animate_parallax_dissolve).chain());
+
+        // Layers without state of their own keep sharing
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Handle<ParallaxMaterial>>(layer), Some(&shared));
+
+        // The dissolve of one layer leaves the other layer untouched
+        world
+            .entity_mut(layer)
+            .insert(ParallaxDissolve::reveal(1.0));
+        schedule.run(&mut world);
+
+        let own = world
+            .get::<Handle<ParallaxMaterial>>(layer)
+            .unwrap()
+            .clone();
+        assert_ne!(own, shared);
+        assert!(world.get::<ParallaxSharedMaterial>(layer).is_none());
+        assert_eq!(world.get::<Handle<ParallaxMaterial>>(other), Some(&shared));
+        let materials = world.resource::<Assets<ParallaxMaterial>>();
+        assert_eq!(materials.get(&own).unwrap().dissolve_threshold(), 0.0);
+        assert_eq!(materials.get(&shared).unwrap().dissolve_threshold(), 1.0);
+    }
+
+    #[test]
+    fn systems_camera_to_center() {
+        let mut world = test_world(Vec2::splat(64.0));
+        let image_size = UVec2::new(32, 16);
+        let texture_point = Vec2::new(6.0, -4.0);
+
+        let clamped = spawn_test_layer(
+            &mut world,
+            image_size,
+            ParallaxLayerData::new(10.0.into(), Vec2::new(3.0, -5.0), ParallaxFlags::NONE),
+        );
+        let locked = spawn_test_layer(
+            &mut world,
+            image_size,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::LOCKED_Y_AXIS),
+        );
+        let repeating = spawn_test_layer(
+            &mut world,
+            image_size,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        );
+        test_process_schedule().run(&mut world);
+
+        let mut move_schedule = Schedule::default();
+        move_schedule.add_systems(move_parallax_layers);
+        let mut move_camera = |world: &mut World, layer: Entity| {
+            let camera = world
+                .get::<ParallaxLayerData>(layer)
+                .unwrap()
+                .camera_to_center(texture_point);
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(world).translation = camera.extend(0.0);
+            move_schedule.run(world);
+            camera
+        };
+
+        // The point of a clamped layer lies at the camera position
+        for layer in [clamped, locked] {
+            let camera = move_camera(&mut world, layer);
+            let translation = world
+                .get::<Transform>(layer)
+                .unwrap()
+                .translation
+                .truncate();
+            assert_eq!(translation + texture_point, camera);
+        }
+        assert_eq!(move_camera(&mut world, clamped), Vec2::new(15.0, -13.0));
+        assert_eq!(move_camera(&mut world, locked).y, -4.0);
+
+        // The point of a repeating axis is sampled at the camera position, relative to the
+        // center of the layer spanning two tiles
+        let camera = move_camera(&mut world, repeating);
+        let transform = world.get::<Transform>(repeating).unwrap();
+        let material = world.get::<Handle<ParallaxMaterial>>(repeating).unwrap();
+        let tile = world
+            .resource::<Assets<ParallaxMaterial>>()
+            .get(material)
+            .unwrap()
+            .tile_coordinate(
+                camera,
+                transform.translation.truncate(),
+                transform.scale.truncate(),
+                camera,
+            );
+        assert_eq!(camera.x, 12.0);
+        assert_eq!(tile.x, 1.0 + texture_point.x / image_size.x as f32);
+    }
+
+    #[test]
+    fn systems_material_time() {
+        let mut world = World::default();
+        world.init_resource::<Time>();
+        world.init_resource::<ParallaxClock>();
+        world.init_resource::<Assets<ParallaxMaterial>>();
+        let [animated, still] = [(); 2].map(|_| {
+            world
+                .resource_mut::<Assets<ParallaxMaterial>>()
+                .add(ParallaxMaterial::new(Handle::default(), Color::WHITE))
+        });
+        world.spawn((animated.clone(), ParallaxMaterialTime));
+        world.spawn(still.clone());
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((advance_parallax_clock, update_parallax_material_time).chain());
+
+        let time = |world: &World, material: &Handle<ParallaxMaterial>| {
+            world
+                .resource::<Assets<ParallaxMaterial>>()
+                .get(material)
+                .unwrap()
+                .time()
+        };
+        let mut advance = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.5));
+            schedule.run(world);
+            time(world, &animated)
+        };
+
+        assert_eq!(advance(&mut world), 0.5);
+        assert_eq!(advance(&mut world), 1.0);
+
+        // Materials of layers without the component are left untouched
+        assert_eq!(time(&world, &still), 0.0);
+
+        // The time uniform follows the parallax clock
+        world.resource_mut::<ParallaxClock>().paused = true;
+        assert_eq!(advance(&mut world), 1.0);
+    }
+
+    #[test]
+    fn systems_pivot() {
+        let mut world = test_world(Vec2::splat(64.0));
+        let mut parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE);
+        parallax.set_pivot(Vec2::new(0.5, 0.0));
+        let layer = spawn_test_layer(&mut world, UVec2::new(32, 16), parallax);
+
+        let mut move_schedule = Schedule::default();
+        move_schedule.add_systems(move_parallax_layers);
+
+        test_process_schedule().run(&mut world);
+        move_schedule.run(&mut world);
+
+        // The bottom edge lies at the position of the layer
+        let bottom_edge = |world: &World| {
+            let transform = world.get::<Transform>(layer).unwrap();
+            transform.translation.truncate() - Vec2::Y * transform.scale.y / 2.0
+        };
+        assert_eq!(bottom_edge(&world), Vec2::ZERO);
+
+        let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+        camera_query.single_mut(&mut world).translation = Vec3::new(8.0, 8.0, 0.0);
+        move_schedule.run(&mut world);
+        assert_eq!(bottom_edge(&world), Vec2::new(4.0, 4.0));
+    }
+
+    #[test]
+    fn systems_neutral_semantics() {
+        for (neutral_semantics, expected) in [
+            (NeutralSemantics::StationaryInWorld, Vec2::ZERO),
+            (NeutralSemantics::StationaryOnScreen, Vec2::new(8.0, 8.0)),
+        ] {
+            let mut world = test_world(Vec2::splat(2.0));
+            world
+                .resource_mut::<ParallaxContext>()
+                .set_neutral_semantics(neutral_semantics);
+            let layer = spawn_test_layer(
+                &mut world,
+                UVec2::ONE,
+                ParallaxLayerData::new(0.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+            );
+
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(&mut world).translation = Vec3::new(8.0, 8.0, 0.0);
+
+            let mut move_schedule = Schedule::default();
+            move_schedule.add_systems(move_parallax_layers);
+            test_process_schedule().run(&mut world);
+            move_schedule.run(&mut world);
+
+            let translation = world.get::<Transform>(layer).unwrap().translation;
+            assert_eq!(translation.truncate(), expected);
+        }
+    }
+
+    #[test]
+    fn systems_shimmer() {
+        let mut world = test_world(Vec2::splat(64.0));
+        world.init_resource::<ParallaxClock>();
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::new(32, 16),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        );
+        world.entity_mut(layer).insert(ParallaxShimmer {
+            amplitude: Vec2::new(4.0, 2.0),
+            frequency: Vec2::new(0.25, 0.5),
+        });
+        test_process_schedule().run(&mut world);
+        let transform = *world.get::<Transform>(layer).unwrap();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(shimmer_parallax_layers);
+
+        let mut advance = |world: &mut World, delta: f32| {
+            world.resource_mut::<ParallaxClock>().advance(delta);
+            schedule.run(world);
+
+            assert_eq!(*world.get::<Transform>(layer).unwrap(), transform);
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let shimmer = world
+                .resource::<Assets<ParallaxMaterial>>()
+                .get(material)
+                .unwrap()
+                .shimmer();
+
+            // Back to texture pixels, where the UV x-axis is mirrored
+            shimmer * transform.scale.truncate() * Vec2::new(-1.0, 1.0)
+        };
+
+        // A quarter period of the x-axis and half a period of the y-axis
+        let shimmer = advance(&mut world, 1.0);
+        assert!((shimmer - Vec2::new(4.0, 0.0)).abs().max_element() < 1e-5);
+
+        let shimmer = advance(&mut world, 0.5);
+        assert!(
+            (shimmer - Vec2::new(4.0 * 0.5_f32.sqrt(), -2.0))
+                .abs()
+                .max_element()
+                < 1e-5
+        );
+
+        let shimmer = advance(&mut world, 1.5);
+        assert!((shimmer - Vec2::new(-4.0, 0.0)).abs().max_element() < 1e-5);
+    }
+
+    #[test]
+    fn systems_lod() {
+        let mut world = test_world(Vec2::splat(64.0));
+        let image_size = UVec2::new(32, 16);
+        let layer = spawn_test_layer(
+            &mut world,
+            image_size,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+
+        let material = world
+            .get::<Handle<ParallaxMaterial>>(layer)
+            .unwrap()
+            .clone();
+        let base = world
+            .resource::<Assets<ParallaxMaterial>>()
+            .get(&material)
+            .unwrap()
+            .image_handle();
+        let lod = world
+            .resource_mut::<Assets<Image>>()
+            .add(test_image(image_size / 4));
+        world.entity_mut(layer).insert(ParallaxLodImages {
+            base: base.clone(),
+            levels: vec![(0.6, lod.clone())],
+            current: None,
+        });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                mark_parallax_layers_on_context_change,
+                process_new_parallax_layer_data,
+                select_parallax_layer_lod,
+            )
+                .chain(),
+        );
+        let image_handle = |world: &World| {
+            world
+                .resource::<Assets<ParallaxMaterial>>()
+                .get(&material)
+                .unwrap()
+                .image_handle()
+        };
+
+        // A depth factor of 0.5 is below the threshold
+        schedule.run(&mut world);
+        assert_eq!(image_handle(&world), lod);
+
+        // The swapped texture keeps the size of the full resolution image
+        schedule.run(&mut world);
+        let image = world
+            .resource::<Assets<Image>>()
+            .get(image_handle(&world))
+            .unwrap();
+        assert_eq!(image.size(), image_size / 4);
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().scale,
+            image_size.as_vec2().extend(1.0)
+        );
+
+        // A depth factor of 2.0 crosses the threshold back
+        world
+            .get_mut::<ParallaxLayerData>(layer)
+            .unwrap()
+            .set_depth(-5.0);
+        schedule.run(&mut world);
+        assert_eq!(image_handle(&world), base);
+    }
+
+    #[test]
+    fn systems_reprocess_all() {
+        let mut world = test_world(Vec2::splat(64.0));
+        let image_size = UVec2::new(32, 16);
+        let layer = spawn_test_layer(
+            &mut world,
+            image_size,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        );
+        let mut schedule = test_process_schedule();
+        schedule.run(&mut world);
+
+        // Untracked changes are not picked up by the processing
+        let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+        let image = world
+            .resource::<Assets<ParallaxMaterial>>()
+            .get(material)
+            .unwrap()
+            .image_handle()
+            .clone();
+        world
+            .resource_mut::<Assets<Image>>()
+            .insert(&image, test_image(UVec2::new(32, 48)));
+        world.get_mut::<Transform>(layer).unwrap().scale = Vec3::ONE;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Transform>(layer).unwrap().scale, Vec3::ONE);
+
+        let mut command_queue = CommandQueue::default();
+        Commands::new(&mut command_queue, &world).add(ParallaxReprocessAll);
+        command_queue.apply(&mut world);
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().scale,
+            Vec3::new(64.0, 48.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn systems_clear_color_gradient() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.init_resource::<ClearColor>();
+        world.insert_resource(ParallaxClearColorGradient {
+            stops: vec![
+                (0.0, Color::rgba_linear(1.0, 0.0, 0.0, 1.0)),
+                (0.5, Color::rgba_linear(0.0, 1.0, 0.0, 1.0)),
+                (1.0, Color::rgba_linear(0.0, 0.0, 1.0, 1.0)),
+            ],
+            along: GradientAxis::Y,
+            range: (100.0, 300.0),
+        });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_parallax_clear_color);
+
+        let mut clear_color_at = |world: &mut World, y: f32| {
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(world).translation = Vec3::new(1000.0, y, 0.0);
+            schedule.run(world);
+            world.resource::<ClearColor>().0.as_linear_rgba_f32()
+        };
+
+        // The end stops are reached at the endpoints of the range, and kept beyond them
+        assert_eq!(clear_color_at(&mut world, 100.0), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(clear_color_at(&mut world, 300.0), [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(clear_color_at(&mut world, -50.0), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(clear_color_at(&mut world, 350.0), [0.0, 0.0, 1.0, 1.0]);
+
+        // Interpolated in between
+        assert_eq!(clear_color_at(&mut world, 200.0), [0.0, 1.0, 0.0, 1.0]);
+        assert_eq!(clear_color_at(&mut world, 150.0), [0.5, 0.5, 0.0, 1.0]);
+        assert_eq!(clear_color_at(&mut world, 275.0), [0.0, 0.25, 0.75, 1.0]);
+
+        // Without a camera the clear color is kept
+        let camera = world
+            .query_filtered::<Entity, With<ParallaxCamera>>()
+            .single(&world);
+        world.despawn(camera);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<ClearColor>().0.as_linear_rgba_f32(),
+            [0.0, 0.25, 0.75, 1.0]
+        );
+    }
+
+    #[test]
+    fn systems_camera_local_offset() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let mut parallax = ParallaxLayerData::new(
+            10.0.into(),
+            Vec2::new(30.0, -20.0),
+            ParallaxFlags::REPEAT_Y_AXIS,
+        );
+        parallax.set_offset_space(OffsetSpace::CameraLocal);
+        let layer = spawn_test_layer(&mut world, UVec2::ONE, parallax);
+
+        test_process_schedule().run(&mut world);
+        let mut move_schedule = Schedule::default();
+        move_schedule.add_systems(move_parallax_layers);
+
+        // The layer stays at the same screen position on the camera-local axis
+        for camera_translation in [Vec2::ZERO, Vec2::new(100.0, 50.0), Vec2::new(-35.0, 8.0)] {
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(&mut world).translation = camera_translation.extend(0.0);
+            move_schedule.run(&mut world);
+
+            let translation = world.get::<Transform>(layer).unwrap().translation;
+            assert_eq!(translation.x - camera_translation.x, 30.0);
+            // The repeating axis follows the camera
+            assert_eq!(translation.y, camera_translation.y);
+        }
+
+        // The offset is in screen pixels, which span more of the world once zoomed out
+        world
+            .query_filtered::<&mut OrthographicProjection, With<ParallaxCamera>>()
+            .single_mut(&mut world)
+            .scale = 2.0;
+        test_process_schedule().run(&mut world);
+        move_schedule.run(&mut world);
+        let translation = world.get::<Transform>(layer).unwrap().translation;
+        assert_eq!(translation.x + 35.0, 60.0);
+
+        // A world offset moves with the depth factor
+        world
+            .get_mut::<ParallaxLayerData>(layer)
+            .unwrap()
+            .set_offset_space(OffsetSpace::World);
+        test_process_schedule().run(&mut world);
+        move_schedule.run(&mut world);
+        let translation = world.get::<Transform>(layer).unwrap().translation;
+        assert_eq!(translation.x, -35.0 * 0.5 + 30.0 * 0.5);
+    }
+
+    #[test]
+    fn systems_effective_factor() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let mut spawn = |flags: ParallaxFlags| {
+            spawn_test_layer(
+                &mut world,
+                UVec2::ONE,
+                ParallaxLayerData::new(10.0.into(), Vec2::ZERO, flags),
+            )
+        };
+        let layers = [
+            (spawn(ParallaxFlags::NONE), Vec2::splat(0.5)),
+            (spawn(ParallaxFlags::LOCKED_X_AXIS), Vec2::new(1.0, 0.5)),
+            (
+                spawn(ParallaxFlags::LOCKED_X_AXIS | ParallaxFlags::LOCKED_Y_AXIS),
+                Vec2::ONE,
+            ),
+            (spawn(ParallaxFlags::REPEAT_X_AXIS), Vec2::splat(0.5)),
+            (
+                spawn(ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::LOCKED_Y_AXIS),
+                Vec2::new(0.5, 1.0),
+            ),
+            (spawn(ParallaxFlags::SCROLL_UV_Y_AXIS), Vec2::splat(0.5)),
+        ];
+        let pixel_scroll = spawn(ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::SCROLL_UV_Y_AXIS);
+        let camera_local = spawn(ParallaxFlags::REPEAT_Y_AXIS);
+        world.entity_mut(pixel_scroll).insert(ParallaxPixelScroll {
+            pixels_per_unit: Vec2::splat(4.0),
+        });
+        world
+            .get_mut::<ParallaxLayerData>(camera_local)
+            .unwrap()
+            .set_offset_space(OffsetSpace::CameraLocal);
+
+        test_process_schedule().run(&mut world);
+
+        let effective_factor = |entity: Entity| {
+            world
+                .get::<ParallaxLayerData>(entity)
+                .unwrap()
+                .effective_factor()
+        };
+        for (layer, expected) in layers {
+            assert_eq!(effective_factor(layer), expected);
+        }
+        // The pixel rate only replaces the factor of the repeating axis
+        assert_eq!(effective_factor(pixel_scroll), Vec2::new(4.0, 0.5));
+        assert_eq!(effective_factor(camera_local), Vec2::new(0.0, 0.5));
+    }
+
+    #[test]
+    fn systems_focus_depth() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.insert_resource(ParallaxFocusDepth {
+            depth: 10.0.into(),
+            falloff: 0.125,
+        });
+        let layers = [10.0, 14.0, 6.0, 100.0].map(|depth| {
+            spawn_test_layer(
+                &mut world,
+                UVec2::ONE,
+                ParallaxLayerData::new(depth.into(), Vec2::ZERO, ParallaxFlags::NONE),
+            )
+        });
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(fade_parallax_layers_by_focus.after(process_new_parallax_layer_data));
+        schedule.run(&mut world);
+
+        let opacities = layers.map(|layer| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            materials.get(material).unwrap().opacity()
+        });
+
+        // Opaque at the focus depth, fading by the falloff per unit of world depth
+        assert_eq!(opacities, [1.0, 0.5, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn systems_animate_neutral_depth() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.init_resource::<ParallaxClock>();
+        world.insert_resource(ParallaxNeutralDepthAnimation::new(20.0, 1.0));
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                animate_parallax_neutral_depth
+                    .run_if(resource_exists::<ParallaxNeutralDepthAnimation>),
+                mark_parallax_layers_on_context_change,
+                process_new_parallax_layer_data,
+            )
+                .chain(),
+        );
+
+        let mut advance = |world: &mut World, delta: f32| {
+            world.resource_mut::<ParallaxClock>().advance(delta);
+            schedule.run(world);
+            world.get::<Transform>(layer).unwrap().translation.z
+        };
+        let image = |world: &World| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            materials.get(material).unwrap().image_handle()
+        };
+
+        // The layer moves along with the neutral depth, relative to which its depth is defined
+        let depth = advance(&mut world, 0.0);
+        let processed_image = image(&world);
+        let depths = [0.25, 0.25, 0.25, 0.25].map(|delta| advance(&mut world, delta));
+        assert_eq!(depth, -10.0);
+        assert_eq!(depths, [-5.0, 0.0, 5.0, 10.0]);
+        // Only the layout follows the neutral depth, the processed image is reused
+        assert_eq!(image(&world), processed_image);
+        assert!(!world.contains_resource::<ParallaxNeutralDepthAnimation>());
+
+        let context = world.resource::<ParallaxContext>();
+        assert_eq!(context.neutral_depth(), 20.0);
+        assert_eq!(
+            world
+                .get::<ParallaxLayerData>(layer)
+                .unwrap()
+                .depth()
+                .depth(),
+            10.0
+        );
+
+        // The animation has finished
+        assert_eq!(advance(&mut world, 0.25), 10.0);
+    }
+
+    #[test]
+    fn systems_fade_in() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.init_resource::<ParallaxClock>();
+        let mut parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE);
+        parallax.fade_in = Some(2.0);
+        let layer = spawn_test_layer(&mut world, UVec2::ONE, parallax);
+        let instant = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(fade_in_parallax_layers.after(process_new_parallax_layer_data));
+
+        let mut advance = |world: &mut World, delta: f32| {
+            world.resource_mut::<ParallaxClock>().advance(delta);
+            schedule.run(world);
+
+            [layer, instant].map(|layer| {
+                let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+                let materials = world.resource::<Assets<ParallaxMaterial>>();
+                materials.get(material).unwrap().opacity()
+            })
+        };
+
+        // Transparent right after processing, while a layer without fade in appears at once
+        assert_eq!(advance(&mut world, 0.0), [0.0, 1.0]);
+        assert!(world.get::<ParallaxFadeIn>(instant).is_none());
+
+        assert_eq!(advance(&mut world, 0.5), [0.25, 1.0]);
+        assert_eq!(advance(&mut world, 1.5), [1.0, 1.0]);
+        assert!(world.get::<ParallaxFadeIn>(layer).is_none());
+
+        // Processing the layer again does not fade it in again
+        world
+            .get_mut::<ParallaxLayerData>(layer)
+            .unwrap()
+            .set_depth(20.0);
+        assert_eq!(advance(&mut world, 0.5), [1.0, 1.0]);
+    }
+
+    #[test]
+    fn systems_fade_in_with_focus_depth() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.init_resource::<ParallaxClock>();
+        world.insert_resource(ParallaxFocusDepth {
+            depth: 10.0.into(),
+            falloff: 0.125,
+        });
+        let mut parallax = ParallaxLayerData::new(14.0.into(), Vec2::ZERO, ParallaxFlags::NONE);
+        parallax.fade_in = Some(2.0);
+        let layer = spawn_test_layer(&mut world, UVec2::ONE, parallax);
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(
+            (fade_in_parallax_layers, fade_parallax_layers_by_focus)
+                .chain()
+                .after(process_new_parallax_layer_data),
+        );
+
+        let mut advance = |world: &mut World, delta: f32| {
+            world.resource_mut::<ParallaxClock>().advance(delta);
+            schedule.run(world);
+
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            materials.get(material).unwrap().opacity()
+        };
+
+        // The fade multiplies the opacity of the focus depth, 0.5 for the layer
+        assert_eq!(advance(&mut world, 0.0), 0.0);
+        assert_eq!(advance(&mut world, 1.0), 0.25);
+        assert_eq!(advance(&mut world, 1.0), 0.5);
+        assert!(world.get::<ParallaxFadeIn>(layer).is_none());
+        assert_eq!(advance(&mut world, 1.0), 0.5);
+    }
+
+    #[test]
+    fn systems_velocity() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layers = [0.5, 0.25].map(|factor| {
+            spawn_test_layer(
+                &mut world,
+                UVec2::ONE,
+                ParallaxLayerData::new(
+                    Depth::from_world(-10.0, factor),
+                    Vec2::ZERO,
+                    ParallaxFlags::NONE,
+                ),
+            )
+        });
+        let locked = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(
+                Depth::from_world(-10.0, 0.5),
+                Vec2::ZERO,
+                ParallaxFlags::LOCKED_Y_AXIS,
+            ),
+        );
+
+        test_process_schedule().run(&mut world);
+        let mut move_schedule = Schedule::default();
+        move_schedule.add_systems((track_parallax_camera_velocities, move_parallax_layers).chain());
+
+        let mut velocities = |world: &mut World, camera_translation: Vec3| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(500));
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(world).translation = camera_translation;
+            move_schedule.run(world);
+
+            [layers[0], layers[1], locked].map(|layer| {
+                let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+                let materials = world.resource::<Assets<ParallaxMaterial>>();
+                materials.get(material).unwrap().velocity()
+            })
+        };
+
+        // No velocity without a previous camera position
+        assert_eq!(velocities(&mut world, Vec3::ZERO), [Vec2::ZERO; 3]);
+
+        // Proportional to the effective factor, against the movement of the camera
+        assert_eq!(
+            velocities(&mut world, Vec3::new(4.0, 2.0, 0.0)),
+            [
+                Vec2::new(-4.0, -2.0),
+                Vec2::new(-2.0, -1.0),
+                Vec2::new(-4.0, -4.0),
+            ]
+        );
+
+        // A resting camera stops the layers
+        assert_eq!(
+            velocities(&mut world, Vec3::new(4.0, 2.0, 0.0)),
+            [Vec2::ZERO; 3]
+        );
+    }
+
+    #[test]
+    fn systems_whole_tiles() {
+        let mut world = test_world(Vec2::new(10.0, 6.0));
+        let flags = ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS;
+        let whole = spawn_test_layer(
+            &mut world,
+            UVec2::splat(4),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, flags | ParallaxFlags::WHOLE_TILES),
+        );
+        let partial = spawn_test_layer(
+            &mut world,
+            UVec2::splat(4),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, flags),
+        );
+        let larger = spawn_test_layer(
+            &mut world,
+            UVec2::new(16, 4),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, flags | ParallaxFlags::WHOLE_TILES),
+        );
+
+        test_process_schedule().run(&mut world);
+
+        let scales = |layer: Entity| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            (
+                world.get::<Transform>(layer).unwrap().scale.truncate(),
+                materials.get(material).unwrap().repeat_scale(),
+            )
+        };
+
+        // Whole tiles cover at least the camera view of 10 by 6
+        assert_eq!(scales(whole), (Vec2::new(12.0, 8.0), Vec2::new(3.0, 2.0)));
+        assert_eq!(scales(larger), (Vec2::new(16.0, 8.0), Vec2::new(1.0, 2.0)));
+        // Otherwise the tiles are cut off at the camera view
+        assert_eq!(scales(partial), (Vec2::new(10.0, 6.0), Vec2::new(2.5, 1.5)));
+    }
+
+    #[test]
+    fn systems_rebind_layers_to_camera() {
+        let mut world = test_world(Vec2::splat(64.0));
+        let old_camera = world
+            .query_filtered::<Entity, With<ParallaxCamera>>()
+            .single(&world);
+        let flags = ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS;
+        let repeating = spawn_test_layer(
+            &mut world,
+            UVec2::new(32, 16),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, flags),
+        );
+        let single = spawn_test_layer(
+            &mut world,
+            UVec2::new(32, 16),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+        world
+            .entity_mut(repeating)
+            .insert(ParallaxTargetCamera(old_camera));
+
+        // A second camera with a layer of its own
+        let minimap_camera = world
+            .spawn((
+                ParallaxCamera { primary: false },
+                Transform::default(),
+                OrthographicProjection {
+                    area: Rect::from_center_size(Vec2::ZERO, Vec2::splat(32.0)),
+                    ..default()
+                },
+            ))
+            .id();
+        let minimap = spawn_test_layer(
+            &mut world,
+            UVec2::new(32, 16),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, flags),
+        );
+        world
+            .entity_mut(minimap)
+            .insert(ParallaxTargetCamera(minimap_camera));
+        test_process_schedule().run(&mut world);
+        assert_eq!(
+            world.get::<Transform>(repeating).unwrap().scale,
+            Vec3::new(64.0, 64.0, 1.0)
+        );
+
+        // Swap to a camera with a wider view
+        world.entity_mut(old_camera).remove::<ParallaxCamera>();
+        let new_camera = world
+            .spawn((
+                ParallaxCamera::default(),
+                Transform::default(),
+                OrthographicProjection {
+                    area: Rect::from_center_size(Vec2::ZERO, Vec2::new(160.0, 90.0)),
+                    ..default()
+                },
+            ))
+            .id();
+
+        let mut command_queue = CommandQueue::default();
+        Commands::new(&mut command_queue, &world).rebind_layers_to_camera();
+        command_queue.apply(&mut world);
+
+        // Repeating layers fill the new view, while other layers keep the size of their image
+        assert_eq!(
+            world.get::<Transform>(repeating).unwrap().scale,
+            Vec3::new(160.0, 90.0, 1.0)
+        );
+        assert_eq!(
+            world.get::<Transform>(single).unwrap().scale,
+            Vec3::new(32.0, 16.0, 1.0)
+        );
+        assert_eq!(
+            world.get::<ParallaxTargetCamera>(repeating),
+            Some(&ParallaxTargetCamera(new_camera))
+        );
+
+        // The layer of the second camera keeps its camera and view
+        assert_eq!(
+            world.get::<ParallaxTargetCamera>(minimap),
+            Some(&ParallaxTargetCamera(minimap_camera))
+        );
+        assert_eq!(
+            world.get::<Transform>(minimap).unwrap().scale,
+            Vec3::new(32.0, 32.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn systems_detile_strength() {
+        let mut world = test_world(Vec2::splat(64.0));
+        let mut parallax =
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS);
+        parallax.set_detile_strength(2.0);
+        let layer = spawn_test_layer(&mut world, UVec2::splat(16), parallax);
+
+        test_process_schedule().run(&mut world);
+
+        // Only the repeating axis varies, with the strength clamped
+        let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+        let materials = world.resource::<Assets<ParallaxMaterial>>();
+        assert_eq!(
+            materials.get(material).unwrap().detile_strength(),
+            Vec2::new(1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn systems_tile_scale() {
+        let mut world = test_world(Vec2::new(100.0, 60.0));
+        let image_size = UVec2::new(20, 10);
+        let spawn_layer = |world: &mut World, flags: ParallaxFlags, tile_scale: Option<Vec2>| {
+            let mut parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, flags);
+            parallax.set_tile_scale(tile_scale);
+            spawn_test_layer(world, image_size, parallax)
+        };
+        // X tiled at native size, Y clamped at native size
+        let native_x = spawn_layer(&mut world, ParallaxFlags::REPEAT_X_AXIS, Some(Vec2::ONE));
+        // X tiled at half size, Y stretched over the camera view
+        let mixed = spawn_layer(
+            &mut world,
+            ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS,
+            Some(Vec2::new(0.5, 0.0)),
+        );
+        let whole_tiles = spawn_layer(
+            &mut world,
+            ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::WHOLE_TILES,
+            Some(Vec2::splat(2.0)),
+        );
+
+        test_process_schedule().run(&mut world);
+
+        let scales = |layer: Entity| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            (
+                world.get::<Transform>(layer).unwrap().scale.truncate(),
+                materials.get(material).unwrap().repeat_scale(),
+            )
+        };
+
+        assert_eq!(
+            scales(native_x),
+            (Vec2::new(100.0, 10.0), Vec2::new(5.0, 1.0))
+        );
+        assert_eq!(
+            scales(mixed),
+            (Vec2::new(100.0, 60.0), Vec2::new(10.0, 1.0))
+        );
+        // Tiles of 40 units cover the camera view of 100 units with 3 whole tiles
+        assert_eq!(
+            scales(whole_tiles),
+            (Vec2::new(120.0, 10.0), Vec2::new(3.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn systems_solid_layer() {
+        let mut world = test_world(Vec2::new(160.0, 90.0));
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<ParallaxMesh>();
+        world.init_resource::<ParallaxSolidImage>();
+        let layer = world
+            .spawn(ParallaxSolidLayer {
+                color: Color::MIDNIGHT_BLUE,
+                depth: Depth::from_world(-20.0, 0.75),
+            })
+            .id();
+
+        world.run_system_once(initial_load_parallax_solid_layers);
+        test_process_schedule().run(&mut world);
+
+        // The plane fills the camera view at its depth, tinted by its color
+        assert!(world.get::<ParallaxSolidLayer>(layer).is_none());
+        let transform = world.get::<Transform>(layer).unwrap();
+        assert_eq!(transform.scale, Vec3::new(160.0, 90.0, 1.0));
+        assert_eq!(transform.translation.z, -20.0);
+        let parallax = world.get::<ParallaxLayerData>(layer).unwrap();
+        assert_eq!(parallax.depth().depth_factor(), Some(0.75));
+        let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+        let materials = world.resource::<Assets<ParallaxMaterial>>();
+        assert_eq!(
+            materials.get(material).unwrap().color(),
+            Color::MIDNIGHT_BLUE
+        );
+    }
+
+    #[test]
+    fn systems_primary_camera() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+        let repeating = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        );
+        let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+        camera_query.single_mut(&mut world).translation = Vec3::new(8.0, 8.0, 0.0);
+
+        // A camera which is not primary is ignored
+        world.spawn((
+            ParallaxCamera { primary: false },
+            Transform::from_xyz(-50.0, 20.0, 0.0),
+            OrthographicProjection {
+                area: Rect::from_center_size(Vec2::ZERO, Vec2::splat(100.0)),
+                ..default()
+            },
+        ));
+
+        let mut move_schedule = Schedule::default();
+        move_schedule.add_systems(move_parallax_layers);
+        test_process_schedule().run(&mut world);
+        move_schedule.run(&mut world);
+
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().translation,
+            Vec3::new(4.0, 4.0, -10.0)
+        );
+        assert_eq!(world.get::<Transform>(repeating).unwrap().scale.x, 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "There should be exactly one primary parallax camera")]
+    fn systems_multiple_primary_cameras_panic() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.spawn((
+            ParallaxCamera::default(),
+            Transform::default(),
+            OrthographicProjection::default(),
+        ));
+        spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+
+        test_process_schedule().run(&mut world);
+    }
+
+    #[test]
+    fn systems_lock_tile_phase() {
+        let mut world = test_world(Vec2::splat(4.0));
+        let locked = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(
+                10.0.into(),
+                Vec2::ZERO,
+                ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::LOCK_TILE_PHASE,
+            ),
+        );
+        let scrolling = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        );
+        test_process_schedule().run(&mut world);
+
+        let mut move_schedule = Schedule::default();
+        move_schedule.add_systems(move_parallax_layers);
+        let mut state = |world: &mut World, layer: Entity, camera_translation: Vec2| {
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(world).translation = camera_translation.extend(0.0);
+            move_schedule.run(world);
+
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            (
+                world.get::<Transform>(layer).unwrap().translation.x,
+                materials
+                    .get(material)
+                    .unwrap()
+                    .tiling_phase(camera_translation)
+                    .x,
+            )
+        };
+
+        // The locked layer moves with its depth factor, while its texture phase stays constant
+        assert_eq!(state(&mut world, locked, Vec2::ZERO), (0.0, 0.0));
+        assert_eq!(state(&mut world, locked, Vec2::new(8.0, 0.0)), (4.0, 0.0));
+        assert_eq!(state(&mut world, locked, Vec2::new(-6.0, 0.0)), (-3.0, 0.0));
+
+        // Otherwise the layer follows the camera, while its texture scrolls
+        assert_eq!(state(&mut world, scrolling, Vec2::ZERO), (0.0, 0.0));
+        assert_eq!(
+            state(&mut world, scrolling, Vec2::new(8.0, 0.0)),
+            (8.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn systems_cover_and_contain() {
+        let mut world = test_world(Vec2::new(100.0, 60.0));
+        let cover = spawn_test_layer(
+            &mut world,
+            UVec2::new(30, 20),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::COVER),
+        );
+        let contain_wide = spawn_test_layer(
+            &mut world,
+            UVec2::new(50, 10),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::CONTAIN),
+        );
+        let contain_tall = spawn_test_layer(
+            &mut world,
+            UVec2::new(20, 30),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::CONTAIN),
+        );
+
+        test_process_schedule().run(&mut world);
+
+        let scales = |layer: Entity| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            (
+                world.get::<Transform>(layer).unwrap().scale.truncate(),
+                materials.get(material).unwrap().repeat_scale(),
+            )
+        };
+
+        // Cover fills the camera view by tiling, cropping the last tiles
+        assert_eq!(
+            scales(cover),
+            (Vec2::new(100.0, 60.0), Vec2::new(100.0 / 30.0, 3.0))
+        );
+        // Contain fits the image inside the camera view, keeping its aspect ratio
+        assert_eq!(scales(contain_wide), (Vec2::new(100.0, 20.0), Vec2::ONE));
+        assert_eq!(scales(contain_tall), (Vec2::new(40.0, 60.0), Vec2::ONE));
+    }
+
+    #[test]
+    fn systems_layer_bounds() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::new(4, 2),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+        let repeating = spawn_test_layer(
+            &mut world,
+            UVec2::new(4, 2),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        );
+        world
+            .entity_mut(layer)
+            .insert(ParallaxLayerBounds::default());
+        world
+            .entity_mut(repeating)
+            .insert(ParallaxLayerBounds::default());
+
+        let mut move_schedule = Schedule::default();
+        move_schedule.add_systems((move_parallax_layers, update_parallax_layer_bounds).chain());
+
+        // Unprocessed layers have no bounds yet
+        move_schedule.run(&mut world);
+        assert_eq!(
+            world.get::<ParallaxLayerBounds>(layer).unwrap().rect(),
+            Rect::default()
+        );
+
+        test_process_schedule().run(&mut world);
+        let mut bounds_at = |world: &mut World, camera_translation: Vec2| {
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(world).translation = camera_translation.extend(0.0);
+            move_schedule.run(world);
+            [layer, repeating]
+                .map(|entity| world.get::<ParallaxLayerBounds>(entity).unwrap().rect())
+        };
+
+        // The bounds follow the layer as it moves with half the camera movement
+        let [bounds, _] = bounds_at(&mut world, Vec2::ZERO);
+        assert_eq!(bounds, Rect::new(-2.0, -1.0, 2.0, 1.0));
+        let [bounds, repeating_bounds] = bounds_at(&mut world, Vec2::new(8.0, -4.0));
+        assert_eq!(bounds, Rect::new(2.0, -3.0, 6.0, -1.0));
+        assert_eq!(
+            repeating_bounds,
+            Rect {
+                min: Vec2::new(f32::NEG_INFINITY, -3.0),
+                max: Vec2::new(f32::INFINITY, -1.0),
+            }
+        );
+    }
+
+    #[test]
+    fn systems_skip_non_2d_image() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        );
+
+        // Swap the image for a 3D texture and an array texture
+        for (dimension, layers) in [(TextureDimension::D3, 2), (TextureDimension::D2, 4)] {
+            let image = world.resource_mut::<Assets<Image>>().add(Image::new_fill(
+                Extent3d {
+                    width: 1,
+                    height: 1,
+                    depth_or_array_layers: layers,
+                },
+                dimension,
+                &[255; 4],
+                TextureFormat::Rgba8UnormSrgb,
+                RenderAssetUsages::default(),
+            ));
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap().id();
+            world
+                .resource_mut::<Assets<ParallaxMaterial>>()
+                .get_mut(material)
+                .unwrap()
+                .set_image_handle(image.clone());
+            world
+                .get_mut::<ParallaxLayerData>(layer)
+                .unwrap()
+                .set_changed();
+
+            test_process_schedule().run(&mut world);
+
+            // The layer is left untouched
+            assert!(world.get::<ParallaxLayerProcessed>(layer).is_none());
+            assert_eq!(world.get::<Transform>(layer).unwrap().scale, Vec3::ONE);
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            assert_eq!(materials.get(material).unwrap().image_handle(), image);
+        }
+    }
+
+    #[test]
+    fn systems_factor_boost() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.init_resource::<ParallaxClock>();
+        let [boosted, unboosted] = [(); 2].map(|_| {
+            spawn_test_layer(
+                &mut world,
+                UVec2::ONE,
+                ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+            )
+        });
+        world
+            .entity_mut(boosted)
+            .insert(ParallaxFactorBoost { multiplier: 2.0 });
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(
+            (
+                track_parallax_camera_velocities,
+                boost_parallax_layers_with_camera_speed,
+                move_parallax_layers,
+            )
+                .chain()
+                .after(process_new_parallax_layer_data),
+        );
+        let mut run_at = |world: &mut World, camera_translation: Vec3| {
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(world).translation = camera_translation;
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.5));
+            world.resource_mut::<ParallaxClock>().advance(0.5);
+            schedule.run(world);
+        };
+        let translation = |world: &World, layer: Entity| {
+            world
+                .get::<Transform>(layer)
+                .unwrap()
+                .translation
+                .truncate()
+        };
+
+        // The boosted layer moves with twice its depth factor of 0.5, the other is unaffected
+        run_at(&mut world, Vec3::ZERO);
+        run_at(&mut world, Vec3::new(8.0, -4.0, 0.0));
+        assert_eq!(translation(&world, boosted), Vec2::ZERO);
+        assert_eq!(translation(&world, unboosted), Vec2::new(4.0, -2.0));
+
+        // Relaxing the boost returns the layer to its usual position, without drifting
+        world
+            .get_mut::<ParallaxFactorBoost>(boosted)
+            .unwrap()
+            .multiplier = 1.0;
+        run_at(&mut world, Vec3::new(8.0, -4.0, 0.0));
+        assert_eq!(translation(&world, boosted), Vec2::new(4.0, -2.0));
+        run_at(&mut world, Vec3::new(12.0, -4.0, 0.0));
+        assert_eq!(translation(&world, boosted), Vec2::new(6.0, -2.0));
+        assert_eq!(translation(&world, unboosted), Vec2::new(6.0, -2.0));
+    }
+
+    #[test]
+    fn systems_teleport() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.init_resource::<ParallaxClock>();
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+        world
+            .resource_mut::<ParallaxContext>()
+            .set_speed_boost(1.0, 8.0);
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(
+            (
+                track_parallax_camera_velocities,
+                boost_parallax_layers_with_camera_speed,
+                move_parallax_layers,
+            )
+                .chain()
+                .after(process_new_parallax_layer_data),
+        );
+        let mut run_at = |world: &mut World, camera_translation: Vec3| {
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(world).translation = camera_translation;
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.5));
+            world.resource_mut::<ParallaxClock>().advance(0.5);
+            schedule.run(world);
+        };
+
+        // Moving at the reference speed doubles the depth factor of 0.5
+        run_at(&mut world, Vec3::ZERO);
+        run_at(&mut world, Vec3::new(4.0, 0.0, 0.0));
+        assert_eq!(
+            world.get::<ParallaxLayerData>(layer).unwrap().factor_boost,
+            2.0
+        );
+
+        // The layer tracks the teleported camera, while the jump does not raise the boost
+        world.send_event(ParallaxTeleport);
+        run_at(&mut world, Vec3::new(100.0, -20.0, 0.0));
+        assert_eq!(
+            world.get::<ParallaxLayerData>(layer).unwrap().factor_boost,
+            1.0
+        );
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().translation,
+            Vec3::new(50.0, -10.0, -10.0)
+        );
+        let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+        let materials = world.resource::<Assets<ParallaxMaterial>>();
+        assert_eq!(materials.get(material).unwrap().velocity(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn systems_context_profiles() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+        world.resource_mut::<ParallaxContext>().add_profile(
+            "forest",
+            ParallaxProfile {
+                near_depth: -10.0,
+                neutral_depth: 0.0,
+                far_depth: 100.0,
+                scale: 2.0,
+            },
+        );
+        // Names may also be built at runtime, such as from a level file
+        world.resource_mut::<ParallaxContext>().add_profile(
+            String::from("cave"),
+            ParallaxProfile {
+                near_depth: -5.0,
+                neutral_depth: 5.0,
+                far_depth: 20.0,
+                scale: 1.0,
+            },
+        );
+
+        let mut schedule = test_process_schedule();
+        let mut factor_with_profile = |world: &mut World, profile: &str| {
+            assert!(world
+                .resource_mut::<ParallaxContext>()
+                .select_profile(profile));
+            schedule.run(world);
+            world
+                .get::<ParallaxLayerData>(layer)
+                .unwrap()
+                .depth()
+                .depth_factor()
+        };
+
+        // Depth factor 0.5 at the default depths
+        assert_eq!(factor_with_profile(&mut world, "forest"), Some(1.0));
+        assert_eq!(
+            world.resource::<ParallaxContext>().active_profile(),
+            Some("forest")
+        );
+        // Neutral depth 5.0 puts the layer at world depth -5.0, with near depth 10.0 in world
+        assert_eq!(factor_with_profile(&mut world, "cave"), Some(10.0 / 15.0));
+        assert_eq!(factor_with_profile(&mut world, "forest"), Some(1.0));
+
+        // Unknown profiles leave the context as is
+        let mut context = world.resource_mut::<ParallaxContext>();
+        assert!(!context.select_profile("desert"));
+        assert_eq!(context.active_profile(), Some("forest"));
+        assert_eq!(context.scale(), 2.0);
+    }
+
+    #[test]
+    fn systems_max_repeat_scale() {
+        let mut world = test_world(Vec2::new(4000.0, 50.0));
+        world
+            .resource_mut::<ParallaxContext>()
+            .set_max_repeat_scale(1000.0, false);
+        let flags = ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::REPEAT_Y_AXIS;
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::splat(2),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, flags),
+        );
+        let repeat_scale = |world: &World| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            materials.get(material).unwrap().repeat_scale()
+        };
+
+        // Past the maximum, the repeat scale is only reported without clamping
+        let mut schedule = test_process_schedule();
+        schedule.run(&mut world);
+        assert_eq!(repeat_scale(&world), Vec2::new(2000.0, 25.0));
+
+        // Clamping only limits the axes past the maximum, stretching the tiles
+        world
+            .resource_mut::<ParallaxContext>()
+            .set_max_repeat_scale(1000.0, true);
+        schedule.run(&mut world);
+        assert_eq!(repeat_scale(&world), Vec2::new(1000.0, 25.0));
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().scale.truncate(),
+            Vec2::new(4000.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn systems_target_camera() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let second_camera = world
+            .spawn((
+                ParallaxCamera { primary: false },
+                Transform::default(),
+                OrthographicProjection {
+                    area: Rect::from_center_size(Vec2::ZERO, Vec2::splat(4.0)),
+                    ..default()
+                },
+            ))
+            .id();
+        let flags = ParallaxFlags::REPEAT_X_AXIS;
+        let [primary_layer, second_layer] = [(); 2].map(|_| {
+            spawn_test_layer(
+                &mut world,
+                UVec2::ONE,
+                ParallaxLayerData::new(10.0.into(), Vec2::ZERO, flags),
+            )
+        });
+        world
+            .entity_mut(second_layer)
+            .insert(ParallaxTargetCamera(second_camera));
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(move_parallax_layers.after(process_new_parallax_layer_data));
+        schedule.run(&mut world);
+
+        // Each layer covers the view of its camera
+        let transform = |world: &World, layer: Entity| *world.get::<Transform>(layer).unwrap();
+        assert_eq!(transform(&world, primary_layer).scale.x, 2.0);
+        assert_eq!(transform(&world, second_layer).scale.x, 4.0);
+
+        // Each layer tracks its own camera, following it on the repeating axis and with the
+        // depth factor of 0.5 on the other
+        let mut camera_query = world.query::<(&mut Transform, &ParallaxCamera)>();
+        for (mut transform, camera) in camera_query.iter_mut(&mut world) {
+            transform.translation = match camera.primary {
+                true => Vec3::new(8.0, 0.0, 0.0),
+                false => Vec3::new(0.0, 16.0, 0.0),
+            };
+        }
+        schedule.run(&mut world);
+        assert_eq!(
+            transform(&world, primary_layer).translation.truncate(),
+            Vec2::new(8.0, 0.0)
+        );
+        assert_eq!(
+            transform(&world, second_layer).translation.truncate(),
+            Vec2::new(0.0, 8.0)
+        );
+    }
+
+    #[test]
+    fn systems_unit_scale() {
+        let mut world = test_world(Vec2::new(100.0, 60.0));
+        let flags = ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::UNIT_SCALE;
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::new(20, 10),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, flags),
+        );
+        let scaled = spawn_test_layer(
+            &mut world,
+            UVec2::new(20, 10),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        );
+        world
+            .entity_mut(layer)
+            .insert(ParallaxLayerBounds::default());
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(
+            (move_parallax_layers, update_parallax_layer_bounds)
+                .chain()
+                .after(process_new_parallax_layer_data),
+        );
+        schedule.run(&mut world);
+
+        // The transform keeps a unit scale, while the mesh has the size of the layer
+        assert_eq!(world.get::<Transform>(layer).unwrap().scale, Vec3::ONE);
+        let mesh = &world.get::<Mesh2dHandle>(layer).unwrap().0;
+        let aabb = world
+            .resource::<Assets<Mesh>>()
+            .get(mesh)
+            .unwrap()
+            .compute_aabb()
+            .unwrap();
+        assert_eq!(Vec3::from(aabb.half_extents), Vec3::new(50.0, 5.0, 0.0));
+
+        // The layer is as large as a layer sized by its transform
+        let parallax = world.get::<ParallaxLayerData>(layer).unwrap();
+        assert_eq!(parallax.size(), Vec2::new(100.0, 10.0));
+        assert_eq!(
+            world.get::<Transform>(scaled).unwrap().scale,
+            Vec3::new(100.0, 10.0, 1.0)
+        );
+        let bounds = world.get::<ParallaxLayerBounds>(layer).unwrap().rect();
+        assert_eq!((bounds.min.y, bounds.max.y), (-5.0, 5.0));
+
+        // Clearing the flag puts back the shared unit mesh, scaled by the transform
+        world
+            .get_mut::<ParallaxLayerData>(layer)
+            .unwrap()
+            .set_flags(ParallaxFlags::REPEAT_X_AXIS);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Mesh2dHandle>(layer).unwrap().0,
+            world.resource::<ParallaxMesh>().handle().0
+        );
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().scale,
+            Vec3::new(100.0, 10.0, 1.0)
+        );
+        let bounds = world.get::<ParallaxLayerBounds>(layer).unwrap().rect();
+        assert_eq!((bounds.min.y, bounds.max.y), (-5.0, 5.0));
+    }
+
+    #[test]
+    fn systems_set_parallax_depth() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let flags = ParallaxFlags::REPEAT_X_AXIS;
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(Depth::from_world(-4.0, 0.5), Vec2::ZERO, flags),
+        );
+        let mut schedule = test_process_schedule();
+        schedule.run(&mut world);
+
+        // Moving a layer with a world depth back to a parallax depth resolves it again
+        world
+            .get_mut::<ParallaxLayerData>(layer)
+            .unwrap()
+            .set_parallax_depth(30.0);
+        schedule.run(&mut world);
+        let depth = world.get::<ParallaxLayerData>(layer).unwrap().depth();
+        assert_eq!(depth.depth(), -30.0);
+        assert_eq!(depth.depth_factor(), Some(0.25));
+
+        let transform = *world.get::<Transform>(layer).unwrap();
+        assert_eq!(transform.translation.z, -30.0);
+        assert_eq!(transform.scale, Vec3::new(2.0, 1.0, 1.0));
+        let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+        let materials = world.resource::<Assets<ParallaxMaterial>>();
+        assert_eq!(
+            materials.get(material).unwrap().depth(),
+            Vec2::new(0.25 / 2.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn systems_alpha() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+        world.entity_mut(layer).insert(ParallaxAlpha(0.5));
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(apply_parallax_alpha.after(process_new_parallax_layer_data));
+
+        let mut run = |world: &mut World| {
+            schedule.run(world);
+
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            materials.get(material).unwrap().alpha()
+        };
+
+        assert_eq!(run(&mut world), 0.5);
+
+        world.get_mut::<ParallaxAlpha>(layer).unwrap().0 = 0.25;
+        assert_eq!(run(&mut world), 0.25);
+
+        // Removing the alpha makes the layer opaque again
+        world.entity_mut(layer).remove::<ParallaxAlpha>();
+        assert_eq!(run(&mut world), 1.0);
+    }
+
+    #[test]
+    fn systems_swap_layer_depths() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let [front, back] = [10.0, 30.0].map(|depth| {
+            spawn_test_layer(
+                &mut world,
+                UVec2::ONE,
+                ParallaxLayerData::new(depth.into(), Vec2::ZERO, ParallaxFlags::NONE),
+            )
+        });
+        let not_a_layer = world.spawn_empty().id();
+        let mut schedule = test_process_schedule();
+        schedule.run(&mut world);
+
+        let resolved = |world: &World, layer: Entity| {
+            let depth = world.get::<ParallaxLayerData>(layer).unwrap().depth();
+            let z = world.get::<Transform>(layer).unwrap().translation.z;
+            (z, depth.depth_factor())
+        };
+        let (front_before, back_before) = (resolved(&world, front), resolved(&world, back));
+        assert_eq!(front_before, (-10.0, Some(0.5)));
+        assert_eq!(back_before, (-30.0, Some(0.25)));
+
+        let mut command_queue = CommandQueue::default();
+        Commands::new(&mut command_queue, &world).swap_layer_depths(front, back);
+        command_queue.apply(&mut world);
+        schedule.run(&mut world);
+
+        assert_eq!(resolved(&world, front), back_before);
+        assert_eq!(resolved(&world, back), front_before);
+
+        // Swapping with an entity that is not a layer does nothing
+        Commands::new(&mut command_queue, &world).swap_layer_depths(front, not_a_layer);
+        command_queue.apply(&mut world);
+        schedule.run(&mut world);
+
+        assert_eq!(resolved(&world, front), back_before);
+        assert_eq!(resolved(&world, back), front_before);
+    }
+
+    #[test]
+    fn systems_cursor_mask() {
+        use bevy::window::WindowResolution;
+
+        let mut world = test_world(Vec2::splat(2.0));
+        let window = world
+            .spawn((
+                Window {
+                    resolution: WindowResolution::new(800.0, 400.0).with_scale_factor_override(2.0),
+                    ..default()
+                },
+                PrimaryWindow,
+            ))
+            .id();
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+        world
+            .entity_mut(layer)
+            .insert(ParallaxCursorMask { radius: 16.0 });
 
-        assert_eq!(depth.depth_factor(), Some(0.5));
-        assert_eq!(translation_0, Vec2::ZERO);
-        assert_eq!(translation_1, Vec2::splat(0.5));
-        assert_eq!(translation_2, Vec2::new(0.0, 0.5));
-        assert_eq!(translation_3, Vec2::ZERO);
-        assert_eq!(translation_4, Vec2::new(1.0, 0.5));
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(update_parallax_cursor_masks.after(process_new_parallax_layer_data));
+
+        let mut run = |world: &mut World, cursor: Option<Vec2>| {
+            world
+                .get_mut::<Window>(window)
+                .unwrap()
+                .set_cursor_position(cursor);
+            schedule.run(world);
+
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            materials.get(material).unwrap().cursor_mask()
+        };
+
+        // Hidden until the cursor enters the window
+        assert_eq!(run(&mut world, None), Some((Vec2::ZERO, 0.0)));
+
+        // Follows the cursor in physical pixels
+        let expected = Some((Vec2::new(200.0, 100.0), 32.0));
+        assert_eq!(run(&mut world, Some(Vec2::new(100.0, 50.0))), expected);
+
+        // Holds the last position while the cursor is outside of the window
+        assert_eq!(run(&mut world, None), expected);
+
+        // Removing the component reveals the whole layer
+        world.entity_mut(layer).remove::<ParallaxCursorMask>();
+        assert_eq!(run(&mut world, None), None);
+    }
+
+    #[test]
+    fn systems_relative_depth() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let [wall, poster] = [20.0, 0.0].map(|depth| {
+            spawn_test_layer(
+                &mut world,
+                UVec2::ONE,
+                ParallaxLayerData::new(depth.into(), Vec2::ZERO, ParallaxFlags::NONE),
+            )
+        });
+        world.entity_mut(poster).insert(ParallaxRelativeDepth {
+            reference: wall,
+            delta: -2.0,
+        });
+
+        let mut schedule = test_process_schedule();
+        schedule
+            .add_systems(apply_parallax_relative_depths.before(process_new_parallax_layer_data));
+
+        let mut run = |world: &mut World| {
+            schedule.run(world);
+            let parallax = world.get::<ParallaxLayerData>(poster).unwrap();
+            let z = world.get::<Transform>(poster).unwrap().translation.z;
+            (parallax.authored_depth.depth(), z)
+        };
+
+        assert_eq!(run(&mut world), (18.0, -18.0));
+
+        // Follows the depth of the reference
+        world
+            .get_mut::<ParallaxLayerData>(wall)
+            .unwrap()
+            .set_parallax_depth(40.0);
+        assert_eq!(run(&mut world), (38.0, -38.0));
+
+        // Keeps the current depth once the reference is gone
+        world.despawn(wall);
+        assert_eq!(run(&mut world), (38.0, -38.0));
+        assert!(world.get::<ParallaxRelativeDepth>(poster).is_none());
+    }
+
+    #[cfg(feature = "debug_gizmos")]
+    #[test]
+    fn systems_depth_factor_color() {
+        assert_eq!(depth_factor_color(Some(1.0)), Color::hsl(0.0, 1.0, 0.5));
+        assert_eq!(depth_factor_color(Some(0.0)), Color::hsl(240.0, 1.0, 0.5));
+        assert_eq!(depth_factor_color(Some(4.0)), depth_factor_color(Some(1.0)));
+        assert_eq!(depth_factor_color(None), Color::WHITE);
+    }
+
+    #[test]
+    fn systems_layer_ready() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layers = [10.0, 30.0].map(|depth| {
+            spawn_test_layer(
+                &mut world,
+                UVec2::ONE,
+                ParallaxLayerData::new(depth.into(), Vec2::ZERO, ParallaxFlags::NONE),
+            )
+        });
+        let mut schedule = test_process_schedule();
+        let mut reader = world.resource::<Events<ParallaxLayerReady>>().get_reader();
+
+        let mut read = |world: &mut World| {
+            let mut events = reader
+                .read(world.resource::<Events<ParallaxLayerReady>>())
+                .map(|event| (event.entity, event.depth.depth_factor()))
+                .collect::<Vec<_>>();
+            events.sort_by_key(|(entity, _)| *entity);
+            events
+        };
+
+        schedule.run(&mut world);
+        assert_eq!(
+            read(&mut world),
+            vec![(layers[0], Some(0.5)), (layers[1], Some(0.25))]
+        );
+
+        // Processing a layer again sends no event
+        world
+            .get_mut::<ParallaxLayerData>(layers[0])
+            .unwrap()
+            .set_parallax_depth(20.0);
+        schedule.run(&mut world);
+        assert_eq!(read(&mut world), vec![]);
+    }
+
+    #[test]
+    fn systems_order_in_tier() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world
+            .resource_mut::<ParallaxContext>()
+            .set_tier_epsilon(0.5);
+        let layers = [2, -1, 0].map(|order_in_tier| {
+            let mut parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE);
+            parallax.set_order_in_tier(order_in_tier);
+            spawn_test_layer(&mut world, UVec2::ONE, parallax)
+        });
+        let mut schedule = test_process_schedule();
+        schedule.run(&mut world);
+
+        let z = |world: &World| {
+            layers.map(|layer| world.get::<Transform>(layer).unwrap().translation.z)
+        };
+
+        // Ordered within the tier, without changing the depth factor
+        assert_eq!(z(&world), [-9.0, -10.5, -10.0]);
+        for layer in layers {
+            let depth = world.get::<ParallaxLayerData>(layer).unwrap().depth();
+            assert_eq!(depth.depth_factor(), Some(0.5));
+        }
+
+        // Reordering a layer moves it within the tier
+        world
+            .get_mut::<ParallaxLayerData>(layers[1])
+            .unwrap()
+            .set_order_in_tier(3);
+        schedule.run(&mut world);
+        assert_eq!(z(&world), [-9.0, -8.5, -10.0]);
+    }
+
+    #[test]
+    fn systems_pending_image() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let image = Handle::<Image>::weak_from_u128(42);
+        let material = world
+            .resource_mut::<Assets<ParallaxMaterial>>()
+            .add(ParallaxMaterial::new(image.clone(), Color::WHITE));
+        let parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE);
+        let layer = world.spawn((Transform::default(), parallax, material)).id();
+        let mut schedule = test_process_schedule();
+
+        // Waits for the image without panicking
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert!(world.get::<ParallaxLayerProcessed>(layer).is_none());
+        assert!(world.get::<ParallaxLayerPending>(layer).is_some());
+
+        // Processed once the image is loaded
+        world
+            .resource_mut::<Assets<Image>>()
+            .insert(image.id(), test_image(UVec2::new(4, 2)));
+        schedule.run(&mut world);
+        assert!(world.get::<ParallaxLayerProcessed>(layer).is_some());
+        assert!(world.get::<ParallaxLayerPending>(layer).is_none());
+    }
+
+    #[test]
+    fn systems_pixel_snap_flag() {
+        let mut world = test_world(Vec2::new(64.0, 48.0));
+        let pixel_size = 0.25;
+        let mut projection_query = world.query::<&mut OrthographicProjection>();
+        projection_query.single_mut(&mut world).scale = pixel_size;
+
+        let flags = ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::PIXEL_SNAP;
+        let layers = [(13.0, flags), (37.0, ParallaxFlags::PIXEL_SNAP)].map(|(depth, flags)| {
+            let parallax = ParallaxLayerData::new(depth.into(), Vec2::new(1.3, -2.1), flags);
+            spawn_test_layer(&mut world, UVec2::new(24, 10), parallax)
+        });
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(move_parallax_layers.after(process_new_parallax_layer_data));
+
+        let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+        for camera_translation in [Vec2::new(10.3, 7.7), Vec2::new(-3.1, 100.45)] {
+            camera_query.single_mut(&mut world).translation = camera_translation.extend(0.0);
+            schedule.run(&mut world);
+
+            for layer in layers {
+                let transform = *world.get::<Transform>(layer).unwrap();
+                let pixels = (transform.translation.truncate() - camera_translation) / pixel_size;
+                assert!((pixels - pixels.round()).abs().max_element() < 1e-3);
+
+                // The texture scrolling snaps to the same grid
+                let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+                let materials = world.resource::<Assets<ParallaxMaterial>>();
+                assert_eq!(
+                    materials.get(material).unwrap().pixel_snap(),
+                    transform.scale.truncate() / pixel_size
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn systems_anisotropy() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let mut parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE);
+        parallax.set_anisotropy(16);
+        let layer = spawn_test_layer(&mut world, UVec2::ONE, parallax);
+        let mut schedule = test_process_schedule();
+
+        let anisotropy_clamp = |world: &World| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let material = world
+                .resource::<Assets<ParallaxMaterial>>()
+                .get(material)
+                .unwrap();
+            let image = world
+                .resource::<Assets<Image>>()
+                .get(material.image_handle())
+                .unwrap();
+            match &image.sampler {
+                ImageSampler::Descriptor(descriptor) => descriptor.anisotropy_clamp,
+                ImageSampler::Default => panic!("The sampler should be set up"),
+            }
+        };
+
+        schedule.run(&mut world);
+        assert_eq!(anisotropy_clamp(&world), 16);
+
+        // Disabled while rendering pixel-perfect with nearest filtering
+        world
+            .resource_mut::<ParallaxContext>()
+            .set_pixel_perfect(Some((1.0, 1)));
+        schedule.run(&mut world);
+        assert_eq!(anisotropy_clamp(&world), 1);
+    }
+
+    #[test]
+    fn systems_depth_factor_per_axis() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::ONE,
+            ParallaxLayerData::new(
+                Depth::from_world_xy(-10.0, 0.5, 0.25),
+                Vec2::ZERO,
+                ParallaxFlags::NONE,
+            ),
+        );
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(move_parallax_layers.after(process_new_parallax_layer_data));
+
+        let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+        camera_query.single_mut(&mut world).translation = Vec3::new(4.0, 4.0, 0.0);
+        schedule.run(&mut world);
+
+        // The layer follows the camera by one minus the factor of each axis
+        let translation = world.get::<Transform>(layer).unwrap().translation;
+        assert_eq!(translation, Vec3::new(2.0, 3.0, -10.0));
+
+        let parallax = world.get::<ParallaxLayerData>(layer).unwrap();
+        assert_eq!(parallax.effective_factor(), Vec2::new(0.5, 0.25));
+    }
+
+    #[test]
+    fn systems_camera_rotation() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layers = [
+            (10.0, Vec2::ZERO, ParallaxFlags::NONE),
+            (30.0, Vec2::ZERO, ParallaxFlags::NONE),
+            (10.0, Vec2::ZERO, ParallaxFlags::LOCK_ROTATION),
+            (10.0, Vec2::X * 4.0, ParallaxFlags::NONE),
+            (10.0, Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        ]
+        .map(|(depth, offset, flags)| {
+            let parallax = ParallaxLayerData::new(depth.into(), offset, flags);
+            spawn_test_layer(&mut world, UVec2::ONE, parallax)
+        });
+
+        // The authored rotation of a layer is kept
+        let authored_angle = 0.3;
+        world.get_mut::<Transform>(layers[1]).unwrap().rotation =
+            Quat::from_rotation_z(authored_angle);
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(move_parallax_layers.after(process_new_parallax_layer_data));
+        schedule.run(&mut world);
+
+        let layer_angle = |world: &World, layer: Entity| {
+            let rotation = world.get::<Transform>(layer).unwrap().rotation;
+            rotation.to_euler(EulerRot::ZYX).0
+        };
+        assert_eq!(layer_angle(&world, layers[0]), 0.0);
+        assert!((layer_angle(&world, layers[1]) - authored_angle).abs() < 1e-5);
+        assert_eq!(
+            world.get::<ParallaxLayerData>(layers[4]).unwrap().size.x,
+            2.0
+        );
+        let translation = world
+            .get::<Transform>(layers[3])
+            .unwrap()
+            .translation
+            .truncate();
+
+        let camera_angle = 0.4;
+        let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+        camera_query.single_mut(&mut world).rotation = Quat::from_rotation_z(camera_angle);
+        schedule.run(&mut world);
+
+        // Factor 0.5 at depth 10 and 0.25 at depth 30, the distant layer turns more with the camera
+        assert!((layer_angle(&world, layers[0]) - camera_angle * 0.5).abs() < 1e-5);
+        assert!(
+            (layer_angle(&world, layers[1]) - authored_angle - camera_angle * 0.75).abs() < 1e-5
+        );
+        assert_eq!(layer_angle(&world, layers[2]), 0.0);
+
+        // An off-center layer turns around the camera
+        let expected = Vec2::from_angle(camera_angle * 0.5).rotate(translation);
+        let rotated_translation = world.get::<Transform>(layers[3]).unwrap().translation;
+        assert!(rotated_translation.truncate().abs_diff_eq(expected, 1e-5));
+
+        // A repeating layer covers the diagonal of the rotated camera view
+        let size = world.get::<ParallaxLayerData>(layers[4]).unwrap().size;
+        assert!((size.x - 8.0_f32.sqrt()).abs() < 1e-5);
+
+        camera_query.single_mut(&mut world).rotation = Quat::IDENTITY;
+        schedule.run(&mut world);
+
+        // Turning back restores the rotation and position of the layers
+        assert!(layer_angle(&world, layers[0]).abs() < 1e-5);
+        assert!((layer_angle(&world, layers[1]) - authored_angle).abs() < 1e-5);
+        let reset_translation = world.get::<Transform>(layers[3]).unwrap().translation;
+        assert!(reset_translation.truncate().abs_diff_eq(translation, 1e-5));
+        assert_eq!(
+            world.get::<ParallaxLayerData>(layers[4]).unwrap().size.x,
+            2.0
+        );
+    }
+
+    #[test]
+    fn systems_beat_pulse() {
+        let mut world = test_world(Vec2::splat(2.0));
+        world.init_resource::<ParallaxClock>();
+        let layers = [
+            (10.0, ParallaxFlags::NONE),
+            (30.0, ParallaxFlags::LOCKED_Y_AXIS),
+        ]
+        .map(|(depth, flags)| {
+            let parallax = ParallaxLayerData::new(depth.into(), Vec2::ZERO, flags);
+            spawn_test_layer(&mut world, UVec2::ONE, parallax)
+        });
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(
+            (
+                advance_parallax_clock,
+                advance_parallax_beat_pulse,
+                move_parallax_layers,
+            )
+                .chain()
+                .after(process_new_parallax_layer_data),
+        );
+        let mut advance = |world: &mut World, seconds: f32| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(seconds));
+            schedule.run(world);
+        };
+        let translation = |world: &World, layer: Entity| {
+            world
+                .get::<Transform>(layer)
+                .unwrap()
+                .translation
+                .truncate()
+        };
+
+        advance(&mut world, 0.0);
+        assert_eq!(translation(&world, layers[0]), Vec2::ZERO);
+
+        let mut beat_pulse = world.resource_mut::<ParallaxBeatPulse>();
+        beat_pulse.decay_time = 1.0;
+        beat_pulse.fire(Vec2::new(8.0, 4.0));
+
+        // Immediate offset scaled by the depth factor of 0.5 and 0.25, without the locked axis
+        advance(&mut world, 0.0);
+        assert_eq!(translation(&world, layers[0]), Vec2::new(4.0, 2.0));
+        assert_eq!(translation(&world, layers[1]), Vec2::new(2.0, 0.0));
+
+        // Quadratic decay, a quarter is left halfway
+        advance(&mut world, 0.5);
+        assert_eq!(translation(&world, layers[0]), Vec2::new(1.0, 0.5));
+        assert_eq!(translation(&world, layers[1]), Vec2::new(0.5, 0.0));
+
+        advance(&mut world, 0.5);
+        assert_eq!(translation(&world, layers[0]), Vec2::ZERO);
+        assert_eq!(translation(&world, layers[1]), Vec2::ZERO);
+    }
+
+    #[test]
+    fn systems_camera_zoom() {
+        let mut world = test_world(Vec2::splat(64.0));
+        let image_size = UVec2::new(32, 16);
+        let repeating = spawn_test_layer(
+            &mut world,
+            image_size,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        );
+        let single = spawn_test_layer(
+            &mut world,
+            image_size,
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+        let unit_scale = spawn_test_layer(
+            &mut world,
+            image_size,
+            ParallaxLayerData::new(
+                10.0.into(),
+                Vec2::ZERO,
+                ParallaxFlags::REPEAT_X_AXIS | ParallaxFlags::UNIT_SCALE,
+            ),
+        );
+
+        let mut schedule = test_process_schedule();
+        schedule.run(&mut world);
+
+        let assets = |world: &World, layer: Entity| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            (
+                materials.get(material).unwrap().image_handle(),
+                world.get::<Mesh2dHandle>(layer).unwrap().0.clone(),
+                world.resource::<Assets<Image>>().len(),
+                world.resource::<Assets<Mesh>>().len(),
+            )
+        };
+        let unit_scale_assets = assets(&world, unit_scale);
+
+        let scales = |world: &World, layer: Entity| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let materials = world.resource::<Assets<ParallaxMaterial>>();
+            (
+                world.get::<Transform>(layer).unwrap().scale.truncate(),
+                materials.get(material).unwrap().repeat_scale(),
+            )
+        };
+        assert_eq!(
+            scales(&world, repeating),
+            (Vec2::new(64.0, 16.0), Vec2::new(2.0, 1.0))
+        );
+
+        // Zooming out doubles the camera view
+        let mut camera_query =
+            world.query_filtered::<&mut OrthographicProjection, With<ParallaxCamera>>();
+        let mut projection = camera_query.single_mut(&mut world);
+        projection.scale = 2.0;
+        projection.area = Rect::from_center_size(Vec2::ZERO, Vec2::splat(128.0));
+        schedule.run(&mut world);
+
+        assert_eq!(
+            scales(&world, repeating),
+            (Vec2::new(128.0, 16.0), Vec2::new(4.0, 1.0))
+        );
+        // Non-repeating layers keep the dimensions of their image
+        assert_eq!(scales(&world, single), (Vec2::new(32.0, 16.0), Vec2::ONE));
+
+        // The processed image and the mesh are reused, the mesh is resized in place
+        assert_eq!(assets(&world, unit_scale), unit_scale_assets);
+        let meshes = world.resource::<Assets<Mesh>>();
+        let mesh = meshes.get(&unit_scale_assets.1).unwrap();
+        assert_eq!(
+            mesh.compute_aabb().unwrap().half_extents,
+            Vec3::new(64.0, 8.0, 0.0).into()
+        );
+    }
+
+    #[test]
+    fn systems_ignore_global_scale() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layers = [false, true].map(|ignore_global_scale| {
+            let mut parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE);
+            parallax.set_ignore_global_scale(ignore_global_scale);
+            spawn_test_layer(&mut world, UVec2::ONE, parallax)
+        });
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(move_parallax_layers.after(process_new_parallax_layer_data));
+        let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+        camera_query.single_mut(&mut world).translation = Vec3::new(100.0, 0.0, 0.0);
+
+        let translations = |world: &World| {
+            layers.map(|layer| world.get::<Transform>(layer).unwrap().translation.x)
+        };
+
+        // Factor 0.5 at depth 10
+        schedule.run(&mut world);
+        assert_eq!(translations(&world), [50.0, 50.0]);
+
+        // Doubling the scale makes the normal layer stationary in the world
+        world.resource_mut::<ParallaxContext>().set_scale(2.0);
+        schedule.run(&mut world);
+        assert_eq!(translations(&world), [0.0, 50.0]);
+    }
+
+    #[test]
+    fn systems_sdf_linear_filtering() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layers = [false, true].map(|sdf| {
+            let mut parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE);
+            parallax.sdf = sdf;
+            spawn_test_layer(&mut world, UVec2::ONE, parallax)
+        });
+        test_process_schedule().run(&mut world);
+
+        let mag_filter = |layer: Entity| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let material = world
+                .resource::<Assets<ParallaxMaterial>>()
+                .get(material)
+                .unwrap();
+            let image = world
+                .resource::<Assets<Image>>()
+                .get(material.image_handle())
+                .unwrap();
+            match &image.sampler {
+                ImageSampler::Descriptor(descriptor) => descriptor.mag_filter,
+                ImageSampler::Default => panic!("The sampler should be set up"),
+            }
+        };
+
+        // The distance field is interpolated between the texels for a smooth edge
+        assert!(matches!(mag_filter(layers[0]), ImageFilterMode::Nearest));
+        assert!(matches!(mag_filter(layers[1]), ImageFilterMode::Linear));
+    }
+
+    #[test]
+    fn systems_camera_velocity() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(track_parallax_camera_velocities);
+
+        let mut velocity_at = |world: &mut World, camera_translation: Vec3, seconds: f32| {
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(world).translation = camera_translation;
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(seconds));
+            schedule.run(world);
+            world.resource::<ParallaxCameraVelocity>().velocity()
+        };
+
+        // No velocity without a previous camera position
+        assert_eq!(velocity_at(&mut world, Vec3::ZERO, 0.25), Vec2::ZERO);
+        // Moved 3 by -1 over a quarter of a second
+        assert_eq!(
+            velocity_at(&mut world, Vec3::new(3.0, -1.0, 0.0), 0.25),
+            Vec2::new(12.0, -4.0)
+        );
+        assert_eq!(
+            velocity_at(&mut world, Vec3::new(3.0, -1.0, 0.0), 0.25),
+            Vec2::ZERO
+        );
+
+        // A teleport is not movement
+        world.send_event(ParallaxTeleport);
+        assert_eq!(
+            velocity_at(&mut world, Vec3::new(100.0, 0.0, 0.0), 0.25),
+            Vec2::ZERO
+        );
+
+        let mut camera_query = world.query_filtered::<Entity, With<ParallaxCamera>>();
+        let camera = camera_query.single(&world);
+        let camera_velocity = world.resource::<ParallaxCameraVelocity>();
+        assert_eq!(camera_velocity.camera_velocity(camera), Some(Vec2::ZERO));
+        assert_eq!(camera_velocity.camera_velocity(Entity::PLACEHOLDER), None);
+    }
+
+    #[test]
+    fn systems_stream_region() {
+        let mut world = test_world(Vec2::new(100.0, 100.0));
+        let region = world
+            .spawn(ParallaxStreamRegion::new(
+                Rect::new(50.0, -10.0, 150.0, 10.0),
+                ParallaxLayer {
+                    name: Some("cave".into()),
+                    ..default()
+                },
+            ))
+            .id();
+        let move_camera = |world: &mut World, x: f32| {
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(world).translation.x = x;
+            world.run_system_once(stream_parallax_regions);
+            world
+                .get::<ParallaxStreamRegion>(region)
+                .unwrap()
+                .spawned_layer()
+        };
+        let layer_names = |world: &mut World| {
+            let mut layer_query = world.query::<&ParallaxLayer>();
+            layer_query
+                .iter(world)
+                .map(|parallax| parallax.name.clone().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        // Outside
+        assert_eq!(move_camera(&mut world, 0.0), None);
+        assert!(layer_names(&mut world).is_empty());
+
+        // Entering spawns the layer once
+        let layer = move_camera(&mut world, 100.0).unwrap();
+        assert_eq!(move_camera(&mut world, 120.0), Some(layer));
+        assert_eq!(layer_names(&mut world), vec!["cave"]);
+
+        // Leaving despawns the layer but keeps the region
+        assert_eq!(move_camera(&mut world, 200.0), None);
+        assert!(world.get_entity(layer).is_none());
+        assert!(layer_names(&mut world).is_empty());
+
+        // Entering again spawns a new layer
+        assert!(move_camera(&mut world, 60.0).is_some());
+        assert_eq!(layer_names(&mut world), vec!["cave"]);
+    }
+
+    #[test]
+    fn systems_stream_region_removed() {
+        let mut world = test_world(Vec2::new(100.0, 100.0));
+        let spawn_region = |world: &mut World| {
+            world
+                .spawn(ParallaxStreamRegion::new(
+                    Rect::new(-50.0, -10.0, 50.0, 10.0),
+                    ParallaxLayer::default(),
+                ))
+                .id()
+        };
+        let spawned_layer = |world: &World, region: Entity| {
+            world
+                .get::<ParallaxStreamRegion>(region)
+                .and_then(ParallaxStreamRegion::spawned_layer)
+        };
+
+        // Despawning the region while the camera is inside of it despawns its layer
+        let region = spawn_region(&mut world);
+        world.run_system_once(stream_parallax_regions);
+        let layer = spawned_layer(&world, region).unwrap();
+        world.despawn(region);
+        world.run_system_once(stream_parallax_regions);
+        assert!(world.get_entity(layer).is_none());
+
+        // As does removing the component, while a region inserted in its place keeps its layer
+        let region = spawn_region(&mut world);
+        world.run_system_once(stream_parallax_regions);
+        let layer = spawned_layer(&world, region).unwrap();
+        world.entity_mut(region).remove::<ParallaxStreamRegion>();
+        world.run_system_once(stream_parallax_regions);
+        assert!(world.get_entity(layer).is_none());
+
+        world.entity_mut(region).insert(ParallaxStreamRegion::new(
+            Rect::new(-50.0, -10.0, 50.0, 10.0),
+            ParallaxLayer::default(),
+        ));
+        world.run_system_once(stream_parallax_regions);
+        let layer = spawned_layer(&world, region).unwrap();
+        world.run_system_once(stream_parallax_regions);
+        assert!(world.get_entity(layer).is_some());
+    }
+
+    #[test]
+    fn systems_stream_region_without_camera() {
+        let mut world = World::default();
+
+        // Neither without regions nor with a region is a camera required
+        world.run_system_once(stream_parallax_regions);
+        let region = world
+            .spawn(ParallaxStreamRegion::new(
+                Rect::from_corners(Vec2::splat(-1.0), Vec2::ONE),
+                ParallaxLayer::default(),
+            ))
+            .id();
+        world.run_system_once(stream_parallax_regions);
+
+        let region = world.get::<ParallaxStreamRegion>(region).unwrap();
+        assert_eq!(region.spawned_layer(), None);
+    }
+
+    #[test]
+    fn systems_color_grading() {
+        let mut world = test_world(Vec2::new(100.0, 100.0));
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::new(10, 10),
+            ParallaxLayerData::new(Depth::from_parallax(10.0), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+        let lut = |world: &World| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let material = world.resource::<Assets<ParallaxMaterial>>().get(material);
+            material.unwrap().color_lut().cloned()
+        };
+
+        // Off by default
+        world.run_system_once(apply_parallax_color_grading);
+        assert_eq!(lut(&world), None);
+
+        let warm_lut = world
+            .resource_mut::<Assets<Image>>()
+            .add(test_image(UVec2::new(256, 16)));
+        world.insert_resource(ParallaxColorGrading {
+            lut: warm_lut.clone(),
+        });
+        world.run_system_once(apply_parallax_color_grading);
+        assert_eq!(lut(&world), Some(warm_lut));
+
+        world.remove_resource::<ParallaxColorGrading>();
+        world.run_system_once(apply_parallax_color_grading);
+        assert_eq!(lut(&world), None);
+    }
+
+    #[test]
+    fn systems_atlas_animation() {
+        let mut world = test_world(Vec2::new(100.0, 100.0));
+        world.init_resource::<ParallaxClock>();
+        let mut parallax =
+            ParallaxLayerData::new(Depth::from_parallax(10.0), Vec2::ZERO, ParallaxFlags::NONE);
+        parallax.atlas = Some(ParallaxAtlas {
+            frame_count: 6,
+            ..ParallaxAtlas::new(4, 2, 4.0)
+        });
+        let layer = spawn_test_layer(&mut world, UVec2::new(40, 20), parallax);
+        let material = |world: &World| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            world
+                .resource::<Assets<ParallaxMaterial>>()
+                .get(material)
+                .unwrap()
+                .clone()
+        };
+
+        // The layer is sized for a single frame
+        test_process_schedule().run(&mut world);
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().scale,
+            Vec3::new(10.0, 10.0, 1.0)
+        );
+        assert_eq!(material(&world).atlas_grid(), Some(UVec2::new(4, 2)));
+
+        world.run_system_once(animate_parallax_atlases);
+        assert_eq!(material(&world).current_frame(), 0);
+
+        world.resource_mut::<ParallaxClock>().advance(0.5);
+        world.run_system_once(animate_parallax_atlases);
+        assert_eq!(material(&world).current_frame(), 2);
+
+        // Loops after the last played frame
+        world.resource_mut::<ParallaxClock>().advance(1.0);
+        world.run_system_once(animate_parallax_atlases);
+        assert_eq!(material(&world).current_frame(), 0);
+    }
+
+    #[test]
+    fn systems_uv_rotation() {
+        let mut world = test_world(Vec2::new(100.0, 100.0));
+        world.init_resource::<ParallaxClock>();
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::new(20, 10),
+            ParallaxLayerData::new(Depth::from_parallax(10.0), Vec2::ZERO, ParallaxFlags::NONE),
+        );
+        world.entity_mut(layer).insert(ParallaxUvRotation {
+            radians_per_sec: 0.5,
+        });
+        test_process_schedule().run(&mut world);
+        let uv_rotation = |world: &World| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let material = world.resource::<Assets<ParallaxMaterial>>().get(material);
+            material.unwrap().uv_rotation()
+        };
+
+        world.run_system_once(rotate_parallax_layer_uvs);
+        assert_eq!(uv_rotation(&world), 0.0);
+
+        // Advances with the clock
+        world.resource_mut::<ParallaxClock>().advance(1.0);
+        world.run_system_once(rotate_parallax_layer_uvs);
+        assert_eq!(uv_rotation(&world), 0.5);
+
+        world.resource_mut::<ParallaxClock>().advance(2.0);
+        world.run_system_once(rotate_parallax_layer_uvs);
+        assert_eq!(uv_rotation(&world), 1.5);
+
+        // Wraps around after a full turn
+        world.resource_mut::<ParallaxClock>().advance(12.0);
+        world.run_system_once(rotate_parallax_layer_uvs);
+        assert!((uv_rotation(&world) - (7.5 - std::f32::consts::TAU)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn systems_atmosphere() {
+        let mut world = test_world(Vec2::new(100.0, 100.0));
+        world
+            .resource_mut::<ParallaxContext>()
+            .set_atmosphere(Some((Color::rgb_linear(0.0, 0.0, 1.0), 1.0)));
+        let [far, opted_out, near] = [
+            (10.0, ParallaxFlags::NONE),
+            (10.0, ParallaxFlags::NO_ATMOSPHERE),
+            (-5.0, ParallaxFlags::NONE),
+        ]
+        .map(|(depth, flags)| {
+            spawn_test_layer(
+                &mut world,
+                UVec2::ONE,
+                ParallaxLayerData::new(Depth::from_parallax(depth), Vec2::ZERO, flags),
+            )
+        });
+        let color = |world: &World, layer: Entity| {
+            let material = world.get::<Handle<ParallaxMaterial>>(layer).unwrap();
+            let material = world.resource::<Assets<ParallaxMaterial>>().get(material);
+            material.unwrap().color()
+        };
+
+        // A tenth of the way to the far depth
+        test_process_schedule().run(&mut world);
+        let tinted = Vec4::from(color(&world, far).as_linear_rgba_f32());
+        assert!(tinted.abs_diff_eq(Vec4::new(0.9, 0.9, 1.0, 1.0), 1e-5));
+        assert_eq!(color(&world, opted_out), Color::WHITE);
+        assert_eq!(color(&world, near), Color::WHITE);
+
+        // Removing the atmosphere restores the color
+        world.resource_mut::<ParallaxContext>().set_atmosphere(None);
+        test_process_schedule().run(&mut world);
+        assert_eq!(color(&world, far), Color::WHITE);
+    }
+
+    #[test]
+    fn systems_clamped_axis() {
+        let mut world = test_world(Vec2::new(4.0, 2.0));
+        let layers = [
+            (UVec2::new(10, 2), ParallaxFlags::NONE),
+            (UVec2::new(10, 2), ParallaxFlags::CLAMP_X_AXIS),
+            (UVec2::new(2, 2), ParallaxFlags::CLAMP_X_AXIS),
+        ]
+        .map(|(image_size, flags)| {
+            let parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, flags);
+            spawn_test_layer(&mut world, image_size, parallax)
+        });
+
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(move_parallax_layers.after(process_new_parallax_layer_data));
+        let mut move_camera = |world: &mut World, x: f32| {
+            let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+            camera_query.single_mut(world).translation = Vec3::new(x, 0.0, 0.0);
+            schedule.run(world);
+            layers.map(|layer| world.get::<Transform>(layer).unwrap().translation.x)
+        };
+
+        // Keeps the native width and scrolls with the factor of 0.5 within its extent
+        assert_eq!(move_camera(&mut world, 4.0), [2.0, 2.0, 3.0]);
+        let parallax = world.get::<ParallaxLayerData>(layers[1]).unwrap();
+        assert_eq!(parallax.size, Vec2::new(10.0, 2.0));
+
+        // The wide layer keeps covering the camera view, the narrow one stays inside of it
+        assert_eq!(move_camera(&mut world, 10.0), [5.0, 7.0, 9.0]);
+        assert_eq!(move_camera(&mut world, -10.0), [-5.0, -7.0, -9.0]);
+    }
+
+    #[test]
+    fn systems_z_bias() {
+        let mut world = test_world(Vec2::splat(2.0));
+        let layers = [0.25, -0.5].map(|z_bias| {
+            let mut parallax = ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::NONE);
+            parallax.set_z_bias(z_bias);
+            spawn_test_layer(&mut world, UVec2::ONE, parallax)
+        });
+        let mut schedule = test_process_schedule();
+        schedule.add_systems(move_parallax_layers.after(process_new_parallax_layer_data));
+
+        let mut camera_query = world.query_filtered::<&mut Transform, With<ParallaxCamera>>();
+        camera_query.single_mut(&mut world).translation = Vec3::new(4.0, 0.0, 0.0);
+        schedule.run(&mut world);
+
+        // Drawn in order of the bias, while both scroll with the factor of their depth
+        let translations = layers.map(|layer| world.get::<Transform>(layer).unwrap().translation);
+        assert_eq!(
+            translations,
+            [Vec3::new(2.0, 0.0, -9.75), Vec3::new(2.0, 0.0, -10.5)]
+        );
+    }
+
+    #[test]
+    fn systems_reload_modified_image() {
+        let mut world = test_world(Vec2::splat(64.0));
+        world.init_resource::<Events<AssetEvent<Image>>>();
+        let layer = spawn_test_layer(
+            &mut world,
+            UVec2::new(32, 16),
+            ParallaxLayerData::new(10.0.into(), Vec2::ZERO, ParallaxFlags::REPEAT_X_AXIS),
+        );
+        let mut schedule = test_process_schedule();
+        schedule
+            .add_systems(reload_modified_parallax_images.before(process_new_parallax_layer_data));
+        schedule.run(&mut world);
+
+        let material = world
+            .get::<Handle<ParallaxMaterial>>(layer)
+            .unwrap()
+            .clone();
+        let material_images = |world: &World| {
+            let material = world
+                .resource::<Assets<ParallaxMaterial>>()
+                .get(&material)
+                .unwrap();
+            (material.source_image().clone(), material.image_handle())
+        };
+        let (source, processed) = material_images(&world);
+        assert_ne!(source, processed);
+
+        // Unrelated images are ignored
+        let unrelated = world
+            .resource_mut::<Assets<Image>>()
+            .add(test_image(UVec2::ONE));
+        world.send_event(AssetEvent::Modified { id: unrelated.id() });
+        schedule.run(&mut world);
+        assert_eq!(material_images(&world).1, processed);
+
+        // The modified image is copied again, with the sampler and dimensions of the layer
+        world
+            .resource_mut::<Assets<Image>>()
+            .insert(&source, test_image(UVec2::new(32, 48)));
+        world.send_event(AssetEvent::Modified { id: source.id() });
+        schedule.run(&mut world);
+
+        let (reloaded_source, reloaded) = material_images(&world);
+        assert_eq!(reloaded_source, source);
+        assert_ne!(reloaded, source);
+        assert_ne!(reloaded, processed);
+        let image = world.resource::<Assets<Image>>().get(&reloaded).unwrap();
+        assert_eq!(image.size(), UVec2::new(32, 48));
+        let ImageSampler::Descriptor(sampler) = &image.sampler else {
+            panic!("The sampler of the layer should be set up");
+        };
+        assert!(matches!(sampler.address_mode_u, ImageAddressMode::Repeat));
+        assert_eq!(
+            world.get::<Transform>(layer).unwrap().scale,
+            Vec3::new(64.0, 48.0, 1.0)
+        );
+        let repeat_scale = world
+            .resource::<Assets<ParallaxMaterial>>()
+            .get(&material)
+            .unwrap()
+            .repeat_scale();
+        assert_eq!(repeat_scale, Vec2::new(2.0, 1.0));
     }
 }